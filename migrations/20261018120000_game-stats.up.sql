@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS game_stats (
+    -- user_id is a Discord snowflake embedded into a UUID, the same as
+    -- spoiler_subscription's ids (see cards_sdk::ids).
+    user_id UUID NOT NULL,
+    -- cards_sdk::stats::Game::as_str — one row per player per game.
+    game TEXT NOT NULL,
+    played INT NOT NULL DEFAULT 0,
+    won INT NOT NULL DEFAULT 0,
+    streak INT NOT NULL DEFAULT 0,
+    best_streak INT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (user_id, game)
+);
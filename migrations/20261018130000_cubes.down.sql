@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS cube_card;
+DROP TABLE IF EXISTS cube;
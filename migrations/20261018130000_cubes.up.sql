@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS cube (
+    id BIGSERIAL PRIMARY KEY,
+    -- guild_id is a Discord snowflake embedded into a UUID, the same as
+    -- spoiler_subscription's ids (see cards_sdk::ids).
+    guild_id UUID NOT NULL,
+    -- Stored lowercased so `/cube pack name:Vintage` finds "vintage".
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (guild_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS cube_card (
+    cube_id BIGINT NOT NULL REFERENCES cube (id) ON DELETE CASCADE,
+    card_id UUID NOT NULL REFERENCES card (id),
+    added_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    -- Cubes are singleton: adding a card that's already in is a no-op.
+    PRIMARY KEY (cube_id, card_id)
+);
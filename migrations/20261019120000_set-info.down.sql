@@ -0,0 +1,7 @@
+ALTER TABLE set
+    DROP COLUMN IF EXISTS set_type,
+    DROP COLUMN IF EXISTS release_date,
+    DROP COLUMN IF EXISTS card_count,
+    DROP COLUMN IF EXISTS block,
+    DROP COLUMN IF EXISTS parent_abbreviation,
+    DROP COLUMN IF EXISTS icon_svg_uri;
@@ -0,0 +1,11 @@
+-- Set-level details from Scryfall's set list. Left null for sets only ever
+-- seen through their cards, until the next full sync fills them in.
+ALTER TABLE set
+    ADD COLUMN IF NOT EXISTS set_type TEXT,
+    ADD COLUMN IF NOT EXISTS release_date DATE,
+    ADD COLUMN IF NOT EXISTS card_count INTEGER,
+    ADD COLUMN IF NOT EXISTS block TEXT,
+    -- The abbreviation of the set this one belongs to, e.g. a commander
+    -- deck's main set.
+    ADD COLUMN IF NOT EXISTS parent_abbreviation TEXT,
+    ADD COLUMN IF NOT EXISTS icon_svg_uri TEXT;
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_gin_rule_oracle_text;
+DROP INDEX IF EXISTS idx_gin_rule_oracle_tsv;
+ALTER TABLE rule DROP COLUMN IF EXISTS oracle_tsv;
@@ -0,0 +1,8 @@
+-- Full-text search over rules text: a stemmed tsvector for word matches,
+-- and a trigram index so a quoted phrase can be matched as a substring.
+ALTER TABLE rule
+    ADD COLUMN IF NOT EXISTS oracle_tsv TSVECTOR
+        GENERATED ALWAYS AS (to_tsvector('english', coalesce(oracle_text, ''))) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_gin_rule_oracle_tsv ON rule USING gin (oracle_tsv);
+CREATE INDEX IF NOT EXISTS idx_gin_rule_oracle_text ON rule USING gin (lower(oracle_text) gin_trgm_ops);
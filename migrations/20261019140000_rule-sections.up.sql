@@ -0,0 +1,16 @@
+-- The Comprehensive Rules, one row per numbered rule: `7` (a section),
+-- `702` (a chapter), `702.19` (a rule) or `702.19b` (a subrule), each
+-- pointing at the number it sits under.
+CREATE TABLE IF NOT EXISTS rule_section (
+    number TEXT PRIMARY KEY,
+    parent TEXT,
+    -- Order in the document, which doesn't sort as text (702.9 < 702.19).
+    position INTEGER NOT NULL,
+    -- Headings and named rules like `702.19. Trample`, for keyword lookup.
+    title TEXT,
+    normalised_title TEXT,
+    text TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS ix_rule_section_parent ON rule_section (parent);
+CREATE INDEX IF NOT EXISTS idx_gin_rule_section_normalised_title ON rule_section USING gin (normalised_title gin_trgm_ops);
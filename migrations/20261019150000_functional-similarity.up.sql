@@ -0,0 +1,8 @@
+-- Cards that play alike, by the similarity of their rules text, worked out
+-- by `sync bulk`. Keyed by `rule.id` (a card's oracle id) on both sides.
+CREATE TABLE IF NOT EXISTS functional_similarity (
+    oracle_id UUID NOT NULL,
+    similar_oracle_id UUID NOT NULL,
+    score REAL NOT NULL,
+    PRIMARY KEY (oracle_id, similar_oracle_id)
+);
@@ -0,0 +1,12 @@
+-- Names cards are printed with in languages other than English, loaded by
+-- `sync localised`. Keyed by `rule.id` (a card's oracle id); a name shared
+-- by many printings is stored once.
+CREATE TABLE IF NOT EXISTS card_localised_name (
+    oracle_id UUID NOT NULL,
+    lang TEXT NOT NULL,
+    name TEXT NOT NULL,
+    normalised_name TEXT NOT NULL,
+    PRIMARY KEY (oracle_id, lang, normalised_name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_gin_card_localised_name_normalised_name ON card_localised_name USING gin (normalised_name gin_trgm_ops);
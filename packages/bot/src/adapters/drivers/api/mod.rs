@@ -0,0 +1,126 @@
+mod proxies;
+mod responses;
+mod routes;
+
+use crate::adapters::drivers::api::proxies::TrustedProxies;
+use crate::domain::app::App;
+use crate::ports::drivers::client::Client;
+use crate::ports::services::cache::Cache;
+use crate::ports::services::card_store::CardStore;
+use crate::ports::services::image_store::ImageStore;
+use crate::ports::services::spoiler_subscription::SpoilerSubscription;
+use async_trait::async_trait;
+use cards_sdk::{CubeRepository, SpoilerQueue, StatsRepository};
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::Request;
+use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
+use std::convert::Infallible;
+use std::env;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+const DEFAULT_PORT: u16 = 8080;
+
+/// How long to wait for requests already being answered once asked to stop.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Serves card search over HTTP as JSON, for anything that wants the bot's
+/// fuzzy matching without going through Discord.
+pub struct Api<IS, CS, C, Sub> {
+    app: Arc<App<IS, CS, C, Sub>>,
+    address: SocketAddr,
+    proxies: Arc<TrustedProxies>,
+}
+
+impl<IS, CS, C, Sub> Api<IS, CS, C, Sub> {
+    /// Listens on `API_PORT`, 8080 by default, trusting the proxies in
+    /// `TRUSTED_PROXIES` to say who each request is from.
+    pub fn new(app: App<IS, CS, C, Sub>) -> Self {
+        let port = env::var("API_PORT")
+            .ok()
+            .and_then(|port| port.parse().ok())
+            .unwrap_or(DEFAULT_PORT);
+        Self {
+            app: Arc::new(app),
+            address: SocketAddr::from(([0, 0, 0, 0], port)),
+            proxies: Arc::new(TrustedProxies::from_env()),
+        }
+    }
+}
+
+#[async_trait]
+impl<IS, CS, C, Sub> Client for Api<IS, CS, C, Sub>
+where
+    IS: ImageStore + Send + Sync + 'static,
+    CS: CardStore + SpoilerQueue + StatsRepository + CubeRepository + Send + Sync + 'static,
+    C: Cache + Send + Sync + 'static,
+    Sub: SpoilerSubscription + Send + Sync + 'static,
+{
+    /// Answers requests until asked to stop, then finishes the ones it's
+    /// already answering.
+    async fn run(&mut self) {
+        let listener = match TcpListener::bind(self.address).await {
+            Ok(listener) => listener,
+            Err(why) => {
+                log::error!("Couldn't serve the API on {} - {why}", self.address);
+                return;
+            }
+        };
+        log::info!("Serving the API on {}", self.address);
+
+        let graceful = GracefulShutdown::new();
+        let shutdown = telemetry::shutdown_signal();
+        tokio::pin!(shutdown);
+
+        loop {
+            let (stream, peer) = tokio::select! {
+                accepted = listener.accept() => match accepted {
+                    Ok(accepted) => accepted,
+                    Err(why) => {
+                        log::warn!("Couldn't accept API connection: {why}");
+                        continue;
+                    }
+                },
+                () = &mut shutdown => break,
+            };
+
+            let app = self.app.clone();
+            let proxies = self.proxies.clone();
+            let service = service_fn(move |request: Request<Incoming>| {
+                let app = app.clone();
+                let client = proxies.client(peer.ip(), request.headers());
+                async move {
+                    let response = app
+                        .api_route(
+                            request.method(),
+                            request.uri().path(),
+                            request.uri().query(),
+                            client,
+                        )
+                        .await;
+                    Ok::<_, Infallible>(response)
+                }
+            });
+            let connection = graceful
+                .watch(http1::Builder::new().serve_connection(TokioIo::new(stream), service));
+            tokio::spawn(async move {
+                if let Err(why) = connection.await {
+                    log::warn!("Error serving API request: {why}");
+                }
+            });
+        }
+
+        if tokio::time::timeout(DRAIN_TIMEOUT, graceful.shutdown())
+            .await
+            .is_err()
+        {
+            log::warn!("Gave up waiting for in-flight API requests");
+        }
+        log::info!("Shut down");
+    }
+}
@@ -0,0 +1,126 @@
+use hyper::HeaderMap;
+use ipnet::IpNet;
+use std::env;
+use std::net::IpAddr;
+
+const FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// The proxies whose `X-Forwarded-For` is believed, so clients behind an
+/// ingress are told apart rather than all sharing the ingress's address.
+#[derive(Default)]
+pub struct TrustedProxies(Vec<IpNet>);
+
+impl TrustedProxies {
+    /// Reads `TRUSTED_PROXIES`, a comma separated list of addresses or CIDR
+    /// ranges. Without it no proxy is trusted and every request is from the
+    /// address that connected.
+    pub fn from_env() -> Self {
+        env::var("TRUSTED_PROXIES")
+            .map(|list| Self::parse(&list))
+            .unwrap_or_default()
+    }
+
+    fn parse(list: &str) -> Self {
+        let proxies = list
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| {
+                let proxy = entry
+                    .parse::<IpNet>()
+                    .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from));
+                if proxy.is_err() {
+                    log::warn!("Ignoring trusted proxy {entry:?}, it isn't an address or range");
+                }
+                proxy.ok()
+            })
+            .collect();
+        Self(proxies)
+    }
+
+    fn trusts(&self, address: IpAddr) -> bool {
+        self.0.iter().any(|proxy| proxy.contains(&address))
+    }
+
+    /// Who a request is from. Walks `X-Forwarded-For` from the right while
+    /// each hop is a trusted proxy, and stops at the first one that isn't:
+    /// anything further left was written by the client and can't be
+    /// believed.
+    pub fn client(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        let forwarded: Vec<&str> = headers
+            .get_all(FORWARDED_FOR)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect();
+
+        let mut client = peer;
+        for hop in forwarded.into_iter().rev() {
+            if !self.trusts(client) {
+                break;
+            }
+            let Ok(hop) = hop.parse() else {
+                break;
+            };
+            client = hop;
+        }
+        client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+
+    const PROXY: &str = "10.1.2.3";
+
+    fn forwarded_for(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(FORWARDED_FOR, HeaderValue::from_static(value));
+        headers
+    }
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn test_untrusted_peer_is_the_client() {
+        let proxies = TrustedProxies::parse("10.0.0.0/8");
+        let client = proxies.client(ip("203.0.113.9"), &forwarded_for("198.51.100.1"));
+        assert_eq!(client, ip("203.0.113.9"));
+    }
+
+    #[test]
+    fn test_trusted_proxy_forwards_the_client() {
+        let proxies = TrustedProxies::parse("10.0.0.0/8");
+        let client = proxies.client(ip(PROXY), &forwarded_for("198.51.100.1"));
+        assert_eq!(client, ip("198.51.100.1"));
+    }
+
+    #[test]
+    fn test_spoofed_hops_are_ignored() {
+        let proxies = TrustedProxies::parse("10.0.0.0/8, 192.0.2.7");
+        let client = proxies.client(
+            ip(PROXY),
+            &forwarded_for("1.1.1.1, 198.51.100.1, 192.0.2.7"),
+        );
+        assert_eq!(client, ip("198.51.100.1"));
+    }
+
+    #[test]
+    fn test_unreadable_hop_stops_at_the_proxy() {
+        let proxies = TrustedProxies::parse("10.0.0.0/8");
+        let client = proxies.client(ip(PROXY), &forwarded_for("unknown"));
+        assert_eq!(client, ip(PROXY));
+    }
+
+    #[test]
+    fn test_nothing_trusted_by_default() {
+        let proxies = TrustedProxies::default();
+        let client = proxies.client(ip(PROXY), &forwarded_for("198.51.100.1"));
+        assert_eq!(client, ip(PROXY));
+    }
+}
@@ -0,0 +1,134 @@
+use contracts::card::Card;
+use contracts::card_set::CardSet;
+use contracts::search_result::SearchResultDto;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A card as the API shows it: what's printed on it, without the ids and
+/// normalised names only the bot needs.
+#[derive(Serialize)]
+pub struct CardJson {
+    id: Uuid,
+    oracle_id: Uuid,
+    name: String,
+    mana_cost: String,
+    type_line: String,
+    oracle_text: String,
+    power: Option<String>,
+    toughness: Option<String>,
+    loyalty: Option<String>,
+    defence: Option<String>,
+    colour_identity: Vec<String>,
+    artist: String,
+    set_name: String,
+    set_code: String,
+    release_date: String,
+    scryfall_url: String,
+    image_url: String,
+}
+
+impl From<&Card> for CardJson {
+    fn from(card: &Card) -> Self {
+        Self {
+            id: *card.id(),
+            oracle_id: *card.oracle_id(),
+            name: card.name().to_string(),
+            mana_cost: card.mana_cost().to_string(),
+            type_line: card.type_line().to_string(),
+            oracle_text: card.oracle_text().to_string(),
+            power: card.power().map(ToString::to_string),
+            toughness: card.toughness().map(ToString::to_string),
+            loyalty: card.loyalty().map(ToString::to_string),
+            defence: card.defence().map(ToString::to_string),
+            colour_identity: card.colour_identity().to_vec(),
+            artist: card.artist().to_string(),
+            set_name: card.set_name().to_string(),
+            set_code: card.set_abbreviation().to_string(),
+            release_date: card.release_date().to_string(),
+            scryfall_url: card.url().to_string(),
+            image_url: format!("/cards/{}/image", card.id()),
+        }
+    }
+}
+
+/// Just enough of a card to link to it.
+#[derive(Serialize)]
+pub struct CardLinkJson {
+    id: Uuid,
+    name: String,
+    set_code: String,
+}
+
+impl From<&Card> for CardLinkJson {
+    fn from(card: &Card) -> Self {
+        Self {
+            id: *card.id(),
+            name: card.name().to_string(),
+            set_code: card.set_abbreviation().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PrintingJson {
+    id: Uuid,
+    set_name: String,
+    set_code: String,
+    release_date: String,
+}
+
+impl From<&CardSet> for PrintingJson {
+    fn from(printing: &CardSet) -> Self {
+        Self {
+            id: *printing.card_id(),
+            set_name: printing.name().to_string(),
+            set_code: printing.abbreviation().to_string(),
+            release_date: printing.release_date().to_string(),
+        }
+    }
+}
+
+/// A card with everything the bot would offer alongside it.
+#[derive(Serialize)]
+pub struct SearchResultJson {
+    card: CardJson,
+    printings: Vec<PrintingJson>,
+    similar_cards: Vec<CardLinkJson>,
+    functionally_similar: Vec<CardLinkJson>,
+}
+
+impl From<&SearchResultDto> for SearchResultJson {
+    fn from(result: &SearchResultDto) -> Self {
+        let links = |cards: Option<&Vec<Card>>| {
+            cards
+                .into_iter()
+                .flatten()
+                .map(CardLinkJson::from)
+                .collect()
+        };
+        Self {
+            card: CardJson::from(result.card()),
+            printings: result
+                .printings()
+                .into_iter()
+                .flatten()
+                .map(PrintingJson::from)
+                .collect(),
+            similar_cards: links(result.similar_cards()),
+            functionally_similar: links(result.functionally_similar()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ErrorJson {
+    error: String,
+}
+
+impl ErrorJson {
+    pub fn new(error: &str) -> Self {
+        Self {
+            error: error.to_string(),
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use crate::adapters::drivers::api::responses::{ErrorJson, PrintingJson, SearchResultJson};
+use crate::domain::query::QueryParams;
+use crate::impl_app;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::{
+    HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+    ACCESS_CONTROL_ALLOW_ORIGIN, ACCESS_CONTROL_MAX_AGE, CONTENT_TYPE,
+};
+use hyper::{Method, Response, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use uuid::Uuid;
+
+pub type ApiResponse = Response<Full<Bytes>>;
+
+/// The API is read-only and public, so any page may call it.
+const ALLOWED_ORIGIN: &str = "*";
+const ALLOWED_METHODS: &str = "GET, OPTIONS";
+/// How long, in seconds, a browser may reuse a preflight's answer.
+const PREFLIGHT_MAX_AGE: &str = "86400";
+
+fn respond(status: StatusCode, content_type: &'static str, body: Vec<u8>) -> ApiResponse {
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
+
+fn json(status: StatusCode, body: &impl Serialize) -> ApiResponse {
+    match serde_json::to_vec(body) {
+        Ok(body) => respond(status, "application/json", body),
+        Err(why) => {
+            log::warn!("Couldn't serialise API response: {why}");
+            respond(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "application/json",
+                Vec::new(),
+            )
+        }
+    }
+}
+
+fn error(status: StatusCode, message: &str) -> ApiResponse {
+    json(status, &ErrorJson::new(message))
+}
+
+fn not_found() -> ApiResponse {
+    error(StatusCode::NOT_FOUND, "Not found")
+}
+
+/// `200` with `value` as JSON, or a `404` if there isn't one.
+fn found(value: Option<impl Serialize>) -> ApiResponse {
+    match value {
+        Some(value) => json(StatusCode::OK, &value),
+        None => not_found(),
+    }
+}
+
+/// Answers a browser's CORS preflight, which doesn't count against the
+/// rate limit since the request it's asking about will.
+fn preflight() -> ApiResponse {
+    let mut response = respond(StatusCode::NO_CONTENT, "text/plain", Vec::new());
+    let headers = response.headers_mut();
+    headers.insert(
+        ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static(ALLOWED_METHODS),
+    );
+    headers.insert(
+        ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::from_static("Content-Type"),
+    );
+    headers.insert(
+        ACCESS_CONTROL_MAX_AGE,
+        HeaderValue::from_static(PREFLIGHT_MAX_AGE),
+    );
+    response
+}
+
+fn parameters(query: Option<&str>) -> HashMap<String, String> {
+    query
+        .map(|query| {
+            form_urlencoded::parse(query.as_bytes())
+                .into_owned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl_app! {
+    /// Answers one API request from `client`, with CORS headers so pages on
+    /// other origins can call it. `OPTIONS` answers a preflight; anything
+    /// else is charged against the client's rate limit before it's routed.
+    pub async fn api_route(
+        &self,
+        method: &Method,
+        path: &str,
+        query: Option<&str>,
+        client: IpAddr,
+    ) -> ApiResponse {
+        let mut response = if method == Method::OPTIONS {
+            preflight()
+        } else if method != Method::GET {
+            error(StatusCode::METHOD_NOT_ALLOWED, "Only GET is supported")
+        } else if !self.rate_limit_client(client).await {
+            error(StatusCode::TOO_MANY_REQUESTS, "Too many requests, slow down")
+        } else {
+            self.api_get(path, query).await
+        };
+        response.headers_mut().insert(
+            ACCESS_CONTROL_ALLOW_ORIGIN,
+            HeaderValue::from_static(ALLOWED_ORIGIN),
+        );
+        response
+    }
+
+    /// Routes a `GET`:
+    ///
+    /// - `GET /cards/search?q=<name>[&set=<set>][&artist=<artist>]` — the
+    ///   best match, fuzzy matched like `[[name | set=... | artist=...]]`
+    /// - `GET /cards/random[?set=<set>]`
+    /// - `GET /cards/<id>`
+    /// - `GET /cards/<id>/printings`
+    /// - `GET /cards/<id>/image` — the card's PNG
+    async fn api_get(&self, path: &str, query: Option<&str>) -> ApiResponse {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        let parameters = parameters(query);
+
+        match segments.as_slice() {
+            ["cards", "search"] => {
+                let Some(name) = parameters.get("q").filter(|name| !name.trim().is_empty()) else {
+                    return error(StatusCode::BAD_REQUEST, "Missing query parameter `q`");
+                };
+                let query = QueryParams::parse(
+                    name,
+                    parameters.get("set").map(String::as_str),
+                    parameters.get("artist").map(String::as_str),
+                );
+                found(self.find_card(query).await.as_ref().map(SearchResultJson::from))
+            }
+            ["cards", "random"] => {
+                let set = parameters.get("set").map(String::as_str);
+                found(self.random_card(set).await.as_ref().map(SearchResultJson::from))
+            }
+            ["cards", id, rest @ ..] => {
+                let Ok(id) = Uuid::parse_str(id) else {
+                    return error(StatusCode::BAD_REQUEST, "Card ids are UUIDs");
+                };
+                match rest {
+                    [] => found(self.fetch_from_id(&id).await.as_ref().map(SearchResultJson::from)),
+                    ["printings"] => found(self.printings(&id).await.map(|printings| {
+                        printings.iter().map(PrintingJson::from).collect::<Vec<_>>()
+                    })),
+                    ["image"] => match self.card_image(&id).await {
+                        Some(image) => respond(StatusCode::OK, "image/png", image.bytes().to_vec()),
+                        None => not_found(),
+                    },
+                    _ => not_found(),
+                }
+            }
+            _ => not_found(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::rate_limit::expect_unlimited;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::card::Card;
+    use contracts::card_set::CardSet;
+    use contracts::image::Image;
+    use http_body_util::BodyExt;
+    use std::net::Ipv4Addr;
+
+    const CLIENT: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    fn card() -> Card {
+        Card::new(
+            Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+            String::from("Lightning Bolt"),
+            String::from("lightning bolt"),
+            Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap(),
+            String::from("https://scryfall.com/card/m11/146/lightning-bolt"),
+            Uuid::new_v4(),
+            None,
+            String::from("{R}"),
+            vec![String::from("R")],
+            None,
+            None,
+            None,
+            None,
+            String::from("Instant"),
+            String::from("Lightning Bolt deals 3 damage to any target."),
+            None,
+            String::from("Christopher Moeller"),
+            String::from("magic 2011"),
+            String::from("m11"),
+            time::Date::from_calendar_date(2010, time::Month::July, 16).unwrap(),
+        )
+    }
+
+    fn app(
+        card_store: MockCardStore,
+        image_store: MockImageStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
+        App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    async fn body(response: ApiResponse) -> serde_json::Value {
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_by_id() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_fetch_card_by_id()
+            .returning(|_| Some(card()));
+        card_store.expect_all_prints().returning(|_| {
+            Some(vec![CardSet::new(
+                *card().id(),
+                String::from("Magic 2011"),
+                "m11",
+                time::Date::from_calendar_date(2010, time::Month::July, 16).unwrap(),
+            )])
+        });
+        card_store
+            .expect_similar_cards()
+            .returning(|_| Some(Vec::new()));
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+
+        let response = app(card_store, image_store)
+            .api_route(
+                &Method::GET,
+                &format!("/cards/{}", card().id()),
+                None,
+                CLIENT,
+            )
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body(response).await;
+        assert_eq!(body["card"]["name"], "Lightning Bolt");
+        assert_eq!(body["card"]["set_code"], "m11");
+        assert_eq!(body["card"]["release_date"], "2010-07-16");
+        assert_eq!(body["printings"][0]["set_name"], "Magic 2011");
+    }
+
+    #[tokio::test]
+    async fn test_printings_of_unknown_card() {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_fetch_card_by_id().returning(|_| None);
+
+        let response = app(card_store, MockImageStore::new())
+            .api_route(
+                &Method::GET,
+                &format!("/cards/{}/printings", Uuid::new_v4()),
+                None,
+                CLIENT,
+            )
+            .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(body(response).await["error"], "Not found");
+    }
+
+    #[tokio::test]
+    async fn test_image() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_fetch_card_by_id()
+            .returning(|_| Some(card()));
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+
+        let response = app(card_store, image_store)
+            .api_route(
+                &Method::GET,
+                &format!("/cards/{}/image", card().id()),
+                None,
+                CLIENT,
+            )
+            .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "image/png");
+    }
+
+    #[tokio::test]
+    async fn test_bad_requests() {
+        let app = app(MockCardStore::new(), MockImageStore::new());
+
+        let search = app
+            .api_route(&Method::GET, "/cards/search", Some("q=+"), CLIENT)
+            .await;
+        assert_eq!(search.status(), StatusCode::BAD_REQUEST);
+
+        let bad_id = app
+            .api_route(&Method::GET, "/cards/bolt", None, CLIENT)
+            .await;
+        assert_eq!(bad_id.status(), StatusCode::BAD_REQUEST);
+
+        let post = app
+            .api_route(&Method::POST, "/cards/random", None, CLIENT)
+            .await;
+        assert_eq!(post.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let unknown = app.api_route(&Method::GET, "/decks", None, CLIENT).await;
+        assert_eq!(unknown.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_cors() {
+        let app = app(MockCardStore::new(), MockImageStore::new());
+
+        let preflight = app
+            .api_route(&Method::OPTIONS, "/cards/random", None, CLIENT)
+            .await;
+        assert_eq!(preflight.status(), StatusCode::NO_CONTENT);
+        assert_eq!(preflight.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert_eq!(
+            preflight.headers()[ACCESS_CONTROL_ALLOW_METHODS],
+            "GET, OPTIONS"
+        );
+
+        let unknown = app.api_route(&Method::GET, "/decks", None, CLIENT).await;
+        assert_eq!(unknown.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited() {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_fetch_card_by_id().times(0);
+
+        // An empty bucket, last touched as far in the future as can be, so
+        // it won't have refilled by the time it's read.
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .with(mockall::predicate::eq(String::from(
+                "rate-limit:client:127.0.0.1",
+            )))
+            .return_const(Some(String::from(
+                "(tokens:0.0,updated:18446744073709551615,warned:false)",
+            )));
+        cache.expect_set().returning(|_, _| Ok(()));
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        let response = app
+            .api_route(
+                &Method::GET,
+                &format!("/cards/{}", card().id()),
+                None,
+                CLIENT,
+            )
+            .await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    }
+}
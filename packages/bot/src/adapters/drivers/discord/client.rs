@@ -1,3 +1,4 @@
+use crate::adapters::drivers::discord::in_flight::IN_FLIGHT;
 use crate::domain::app::App;
 use crate::ports::drivers::client::Client;
 use crate::ports::services::cache::Cache;
@@ -5,10 +6,16 @@ use crate::ports::services::card_store::CardStore;
 use crate::ports::services::image_store::ImageStore;
 use crate::ports::services::spoiler_subscription::SpoilerSubscription;
 use async_trait::async_trait;
-use cards_sdk::SpoilerQueue;
+use cards_sdk::{CubeRepository, SpoilerQueue, StatsRepository};
 use serenity::all::GatewayIntents;
 use serenity::Client as DiscordClient;
 use std::env;
+use std::time::Duration;
+
+/// How long to wait for in-flight interactions once the shards are closed.
+/// Kept under Kubernetes' 30 second grace period, after which it kills the
+/// pod anyway.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(20);
 
 pub struct Discord(DiscordClient);
 
@@ -17,7 +24,7 @@ impl Discord {
     pub async fn new<IS, CS, C, Sub>(app: App<IS, CS, C, Sub>) -> Self
     where
         IS: ImageStore + Send + Sync + 'static,
-        CS: CardStore + SpoilerQueue + Send + Sync + 'static,
+        CS: CardStore + SpoilerQueue + StatsRepository + CubeRepository + Send + Sync + 'static,
         C: Cache + Send + Sync + 'static,
         Sub: SpoilerSubscription + Send + Sync + 'static,
     {
@@ -36,9 +43,26 @@ impl Discord {
 
 #[async_trait]
 impl Client for Discord {
+    /// Runs until asked to stop, then closes the shards so no new events
+    /// arrive and gives the ones already being handled time to finish.
     async fn run(&mut self) {
+        let shard_manager = self.0.shard_manager.clone();
+        let shutdown = telemetry::shutdown_signal();
+        tokio::spawn(async move {
+            shutdown.await;
+            shard_manager.shutdown_all().await;
+        });
+
         if let Err(why) = self.0.start().await {
             log::error!("Error starting client - {why:?}");
         }
+
+        if tokio::time::timeout(DRAIN_TIMEOUT, IN_FLIGHT.drained())
+            .await
+            .is_err()
+        {
+            log::warn!("Gave up waiting for in-flight interactions");
+        }
+        log::info!("Shut down");
     }
 }
@@ -0,0 +1,163 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::components::interaction::{ARTIST_PAGE, GALLERY_ID};
+use crate::adapters::drivers::discord::utils::description::create_card_description;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::adapters::drivers::discord::utils::message::page_window;
+use crate::adapters::drivers::discord::utils::reply::Reply;
+use crate::domain::functions::artist::{ArtistGallery, GALLERY_PAGE_SIZE};
+use crate::domain::i18n::{Locale, Message};
+use crate::ports::drivers::client::{ArtistInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use contracts::card::Card;
+use contracts::image::Image;
+use discord_embeds::colour_id_emoji;
+use serenity::all::{
+    CreateActionRow, CreateAttachment, CreateEmbed, CreateEmbedFooter, CreateSelectMenu,
+    CreateSelectMenuKind, CreateSelectMenuOption, EditInteractionResponse,
+};
+
+const GALLERY_FILE: &str = "gallery.png";
+
+fn work_line(card: &Card) -> String {
+    format!(
+        "[{}]({}) — {} ({})",
+        card.name(),
+        card.url(),
+        card.set_name(),
+        card.release_date().year()
+    )
+}
+
+async fn build_illustration_dropdown(gallery: &ArtistGallery, locale: Locale) -> CreateActionRow {
+    let mut options = Vec::with_capacity(gallery.illustrations().len());
+    for card in gallery.illustrations() {
+        options.push(
+            CreateSelectMenuOption::new(card.name(), card.id().to_string())
+                .emoji(colour_id_emoji(card).await)
+                .description(create_card_description(card)),
+        );
+    }
+    let menu = CreateSelectMenu::new(GALLERY_ID, CreateSelectMenuKind::String { options })
+        .placeholder(Message::OpenCard.text(locale));
+    CreateActionRow::SelectMenu(menu)
+}
+
+/// Each page is described by the years its illustrations span.
+fn build_page_dropdown(gallery: &ArtistGallery, locale: Locale) -> Option<CreateActionRow> {
+    if gallery.pages() < 2 {
+        return None;
+    }
+    let options = page_window(gallery.page(), gallery.pages())
+        .filter_map(|number| {
+            let cards = gallery.illustrations_on(number);
+            let (start, end) = (
+                cards.first()?.release_date().year(),
+                cards.last()?.release_date().year(),
+            );
+            let years = if start == end {
+                start.to_string()
+            } else {
+                format!("{start}–{end}")
+            };
+            let from = number * GALLERY_PAGE_SIZE + 1;
+            Some(
+                CreateSelectMenuOption::new(
+                    Message::PageRange {
+                        page: number + 1,
+                        from,
+                        to: from + cards.len() - 1,
+                    }
+                    .text(locale),
+                    number.to_string(),
+                )
+                .description(years)
+                .default_selection(number == gallery.page()),
+            )
+        })
+        .collect();
+    let menu = CreateSelectMenu::new(
+        format!("{ARTIST_PAGE}{}", gallery.artist_name()),
+        CreateSelectMenuKind::String { options },
+    )
+    .placeholder(Message::TurnToPage.text(locale));
+    Some(CreateActionRow::SelectMenu(menu))
+}
+
+pub async fn build_gallery_message<R: Reply>(
+    gallery: &ArtistGallery,
+    image: Option<Image>,
+    locale: Locale,
+) -> R {
+    let first = gallery.page() * GALLERY_PAGE_SIZE + 1;
+    let lines: Vec<String> = gallery
+        .illustrations()
+        .iter()
+        .enumerate()
+        .map(|(index, card)| format!("`{}.` {}", first + index, work_line(card)))
+        .collect();
+
+    let mut embed = CreateEmbed::default()
+        .title(gallery.artist())
+        .description(format!(
+            "{}\n**{}:** {}\n**{}:** {}\n\n{}",
+            Message::ArtistSummary {
+                cards: gallery.card_count() as usize,
+                illustrations: gallery.total(),
+            }
+            .text(locale),
+            Message::FirstWork.text(locale),
+            work_line(gallery.first_work()),
+            Message::LatestWork.text(locale),
+            work_line(gallery.latest_work()),
+            lines.join("\n")
+        ))
+        .footer(CreateEmbedFooter::new(
+            Message::PageOf {
+                page: gallery.page() + 1,
+                pages: gallery.pages(),
+            }
+            .text(locale),
+        ));
+
+    let mut message = R::default();
+    if let Some(image) = image {
+        embed = embed.attachment(GALLERY_FILE);
+        message = message.with_file(CreateAttachment::bytes(image.bytes(), GALLERY_FILE));
+    }
+
+    let components: Vec<CreateActionRow> = [
+        Some(build_illustration_dropdown(gallery, locale).await),
+        build_page_dropdown(gallery, locale),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    message.with_embed(embed).with_components(components)
+}
+
+/// `/artist` is deferred by the dispatcher while the gallery is drawn, so
+/// these edit that response rather than creating one.
+#[async_trait]
+impl ArtistInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_gallery(
+        &self,
+        gallery: ArtistGallery,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError> {
+        self.edit(build_gallery_message(&gallery, image, self.locale()).await)
+            .await
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().content(message))
+            .await
+    }
+}
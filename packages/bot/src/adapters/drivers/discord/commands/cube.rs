@@ -0,0 +1,159 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::modals::interaction::{
+    DiscordModalInteraction, CUBE_IMPORT, LIST_INPUT,
+};
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::i18n::{Locale, Message};
+use crate::domain::rate_limit::Requester;
+use crate::ports::drivers::client::{CubeInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use contracts::card::Card;
+use discord_embeds::add_emoji;
+use serenity::all::{
+    CreateActionRow, CreateEmbed, CreateInputText, CreateModal, EditInteractionResponse, GuildId,
+    InputTextStyle, Member, Permissions,
+};
+
+/// Discord caps modal titles at 45 characters.
+const TITLE_LIMIT: usize = 45;
+/// ...and text inputs at 4000.
+const LIST_LIMIT: u16 = 4000;
+
+/// The paste box `/cube import` opens, with the cube's name carried in the
+/// custom id for when it's submitted.
+pub fn build_import_modal(name: &str, locale: Locale) -> CreateModal {
+    let title: String = Message::ImportIntoCube { cube: name }
+        .text(locale)
+        .chars()
+        .take(TITLE_LIMIT)
+        .collect();
+    CreateModal::new(format!("{CUBE_IMPORT}{name}"), title).components(vec![
+        CreateActionRow::InputText(
+            CreateInputText::new(
+                InputTextStyle::Paragraph,
+                Message::CubeListLabel.text(locale),
+                LIST_INPUT,
+            )
+            .placeholder("1 Lightning Bolt\n1 Counterspell")
+            .max_length(LIST_LIMIT),
+        ),
+    ])
+}
+
+/// Whether a member has Manage Server. Interactions from a server always
+/// carry the member with their resolved permissions.
+pub fn can_manage_guild(member: Option<&Member>) -> bool {
+    member
+        .and_then(|member| member.permissions)
+        .is_some_and(Permissions::manage_guild)
+}
+
+async fn build_pack_embeds(name: &str, packs: &[Vec<Card>], locale: Locale) -> Vec<CreateEmbed> {
+    let mut embeds = Vec::with_capacity(packs.len());
+    for (index, pack) in packs.iter().enumerate() {
+        let mut lines = Vec::with_capacity(pack.len());
+        for card in pack {
+            lines.push(format!(
+                "[{}]({}) {}",
+                card.name(),
+                card.url(),
+                add_emoji(card.mana_cost()).await
+            ));
+        }
+        embeds.push(
+            CreateEmbed::default()
+                .title(
+                    Message::CubePack {
+                        cube: name,
+                        pack: index + 1,
+                    }
+                    .text(locale),
+                )
+                .description(lines.join("\n")),
+        );
+    }
+    embeds
+}
+
+/// Every `/cube` subcommand but `import` is deferred by the dispatcher, so
+/// these edit that response rather than creating one.
+#[async_trait]
+impl CubeInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.command().user.id.get(),
+            guild_id: self.command().guild_id.map(GuildId::get),
+        }
+    }
+
+    fn can_manage_guild(&self) -> bool {
+        can_manage_guild(self.command().member.as_deref())
+    }
+
+    async fn send_packs(
+        &self,
+        name: String,
+        packs: Vec<Vec<Card>>,
+    ) -> Result<(), MessageInteractionError> {
+        let embeds = build_pack_embeds(&name, &packs, self.locale()).await;
+        self.edit(EditInteractionResponse::new().embeds(embeds))
+            .await
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().content(message))
+            .await
+    }
+}
+
+#[async_trait]
+impl CubeInteraction for DiscordModalInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(self.modal().guild_locale.as_deref(), &self.modal().locale)
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.modal().user.id.get(),
+            guild_id: self.modal().guild_id.map(GuildId::get),
+        }
+    }
+
+    fn can_manage_guild(&self) -> bool {
+        can_manage_guild(self.modal().member.as_ref())
+    }
+
+    async fn send_packs(
+        &self,
+        name: String,
+        packs: Vec<Vec<Card>>,
+    ) -> Result<(), MessageInteractionError> {
+        let embeds = build_pack_embeds(&name, &packs, self.locale()).await;
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().embeds(embeds),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().content(message),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
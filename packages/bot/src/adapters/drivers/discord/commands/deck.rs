@@ -0,0 +1,448 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::modals::interaction::{
+    DiscordModalInteraction, COMMANDER, DECK, LIST_INPUT,
+};
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::deck::commander::{CommanderReport, DECK_SIZE};
+use crate::domain::functions::deck::report::{DeckReport, FormatCheck, CURVE_SIZE};
+use crate::domain::i18n::{Locale, Message};
+use crate::domain::rate_limit::Requester;
+use crate::ports::drivers::client::{DeckInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use contracts::priced_card::Currency;
+use discord_embeds::add_emoji;
+use serenity::all::{
+    CreateActionRow, CreateEmbed, CreateInputText, CreateModal, EditInteractionResponse, GuildId,
+    InputTextStyle,
+};
+
+/// Discord caps text inputs at 4000 characters.
+const LIST_LIMIT: u16 = 4000;
+/// How many offending cards are named per format before the rest are
+/// summed up, to keep the embed under Discord's length limit.
+const VIOLATIONS_SHOWN: usize = 3;
+const UNRESOLVED_SHOWN: usize = 10;
+
+/// The paste box `/deck` opens, with the chosen currency carried in the
+/// custom id for when it's submitted.
+pub fn build_deck_modal(currency: Currency, locale: Locale) -> CreateModal {
+    CreateModal::new(
+        format!("{DECK}{currency}"),
+        Message::DeckModalTitle.text(locale),
+    )
+    .components(vec![CreateActionRow::InputText(
+        CreateInputText::new(
+            InputTextStyle::Paragraph,
+            Message::DeckListLabel.text(locale),
+            LIST_INPUT,
+        )
+        .placeholder("4 Lightning Bolt\n4 Monastery Swiftspear\n\nSideboard\n2 Pyroblast")
+        .max_length(LIST_LIMIT),
+    )])
+}
+
+/// The paste box `/commander` opens.
+pub fn build_commander_modal(locale: Locale) -> CreateModal {
+    CreateModal::new(COMMANDER, Message::CommanderModalTitle.text(locale)).components(vec![
+        CreateActionRow::InputText(
+            CreateInputText::new(
+                InputTextStyle::Paragraph,
+                Message::DeckListLabel.text(locale),
+                LIST_INPUT,
+            )
+            .placeholder("Commander\n1 Krenko, Mob Boss\n\nDeck\n1 Sol Ring\n30 Mountain")
+            .max_length(LIST_LIMIT),
+        ),
+    ])
+}
+
+fn format_line(check: &FormatCheck, locale: Locale) -> String {
+    if check.is_legal() {
+        return format!("✅ {}", check.format().name());
+    }
+
+    let mut offenders: Vec<String> = check
+        .violations()
+        .iter()
+        .take(VIOLATIONS_SHOWN)
+        .map(|violation| {
+            let reason = Message::Violation {
+                legality: violation.legality(),
+                copies: violation.copies(),
+            };
+            format!("{} ({})", violation.name(), reason.text(locale))
+        })
+        .collect();
+    if check.violations().len() > VIOLATIONS_SHOWN {
+        offenders.push(
+            Message::More {
+                count: check.violations().len() - VIOLATIONS_SHOWN,
+            }
+            .text(locale),
+        );
+    }
+
+    format!("❌ {} — {}", check.format().name(), offenders.join(", "))
+}
+
+fn curve_lines(report: &DeckReport) -> String {
+    let lines: Vec<String> = report
+        .curve()
+        .iter()
+        .enumerate()
+        .map(|(mana_value, count)| {
+            let label = if mana_value + 1 == CURVE_SIZE {
+                format!("{mana_value}+")
+            } else {
+                format!("{mana_value} ")
+            };
+            let bar = "■".repeat(usize::try_from(*count).unwrap_or_default());
+            format!("{label} {bar} {count}")
+        })
+        .collect();
+    format!("```\n{}\n```", lines.join("\n"))
+}
+
+async fn build_report_embed(report: &DeckReport, locale: Locale) -> CreateEmbed {
+    let mut counts = vec![Message::MainCount {
+        count: report.main(),
+    }
+    .text(locale)];
+    if report.sideboard() > 0 {
+        counts.push(
+            Message::SideboardCount {
+                count: report.sideboard(),
+            }
+            .text(locale),
+        );
+    }
+    if report.commander() > 0 {
+        counts.push(
+            Message::CommanderCount {
+                count: report.commander(),
+            }
+            .text(locale),
+        );
+    }
+
+    let mut description = vec![
+        counts.join(" · "),
+        String::new(),
+        format!("**{}**", Message::LegalityHeading.text(locale)),
+    ];
+    description.extend(
+        report
+            .formats()
+            .iter()
+            .map(|check| format_line(check, locale)),
+    );
+
+    let mut colours = Vec::with_capacity(report.colours().len());
+    for (symbol, count) in report.colours() {
+        colours.push(format!(
+            "{} {count}",
+            add_emoji(&format!("{{{symbol}}}")).await
+        ));
+    }
+
+    let types: Vec<String> = report
+        .types()
+        .iter()
+        .map(|(card_type, count)| format!("{card_type} {count}"))
+        .collect();
+
+    let mut price = vec![report.formatted_price()];
+    if !report.unpriced().is_empty() {
+        price.push(
+            Message::Unpriced {
+                count: report.unpriced().len(),
+            }
+            .text(locale),
+        );
+    }
+
+    let mut embed = CreateEmbed::default()
+        .title(Message::DeckAnalysis.text(locale))
+        .description(description.join("\n"))
+        .field(Message::ManaCurve.text(locale), curve_lines(report), false)
+        .field(
+            Message::Colours.text(locale),
+            non_empty(colours.join("  ")),
+            true,
+        )
+        .field(
+            Message::Types.text(locale),
+            non_empty(types.join("\n")),
+            true,
+        )
+        .field(Message::Price.text(locale), price.join("\n"), true);
+
+    let unresolved = report.unresolved();
+    if !unresolved.is_empty() {
+        let mut lines: Vec<String> = unresolved
+            .iter()
+            .take(UNRESOLVED_SHOWN)
+            .map(|line| format!("- `{line}`"))
+            .collect();
+        if unresolved.len() > UNRESOLVED_SHOWN {
+            lines.push(
+                Message::AndMore {
+                    count: unresolved.len() - UNRESOLVED_SHOWN,
+                }
+                .text(locale),
+            );
+        }
+        embed = embed.field(Message::CouldNotFind.text(locale), lines.join("\n"), false);
+    }
+
+    embed
+}
+
+/// Names the first few cards in `names`, summing up the rest.
+fn name_list(names: &[String], locale: Locale) -> String {
+    let mut shown: Vec<String> = names.iter().take(VIOLATIONS_SHOWN * 2).cloned().collect();
+    if names.len() > shown.len() {
+        shown.push(
+            Message::More {
+                count: names.len() - shown.len(),
+            }
+            .text(locale),
+        );
+    }
+    shown.join(", ")
+}
+
+/// One line per Commander rule, ticked or crossed.
+fn commander_checks(report: &CommanderReport, locale: Locale) -> Vec<String> {
+    let passed = |message: Message| format!("✅ {}", message.text(locale));
+    let failed = |message: Message| format!("❌ {}", message.text(locale));
+
+    let mut checks = vec![if report.total() == DECK_SIZE {
+        passed(Message::DeckSize { size: DECK_SIZE })
+    } else {
+        failed(Message::WrongDeckSize {
+            total: report.total(),
+            size: DECK_SIZE,
+        })
+    }];
+    checks.push(if report.not_commanders().is_empty() {
+        passed(Message::CommanderCanLead)
+    } else {
+        failed(Message::CantBeCommander {
+            names: &name_list(report.not_commanders(), locale),
+        })
+    });
+    checks.push(if report.off_identity().is_empty() {
+        passed(Message::WithinColourIdentity)
+    } else {
+        failed(Message::OutsideColourIdentity {
+            names: &name_list(report.off_identity(), locale),
+        })
+    });
+    checks.push(if report.duplicates().is_empty() {
+        passed(Message::Singleton)
+    } else {
+        let duplicates: Vec<String> = report
+            .duplicates()
+            .iter()
+            .map(|(name, copies)| format!("{name} ×{copies}"))
+            .collect();
+        failed(Message::TooManyCopies {
+            names: &name_list(&duplicates, locale),
+        })
+    });
+    checks.push(if report.banned().is_empty() {
+        passed(Message::NoBannedCards)
+    } else {
+        failed(Message::BannedCards {
+            names: &name_list(report.banned(), locale),
+        })
+    });
+    if !report.not_legal().is_empty() {
+        checks.push(failed(Message::NotLegalInCommander {
+            names: &name_list(report.not_legal(), locale),
+        }));
+    }
+    checks
+}
+
+async fn build_commander_embed(report: &CommanderReport, locale: Locale) -> CreateEmbed {
+    let identity: String = report
+        .identity()
+        .iter()
+        .map(|colour| ["{", colour, "}"].concat())
+        .collect();
+    let identity = if identity.is_empty() {
+        Message::Colourless.text(locale)
+    } else {
+        add_emoji(&identity).await
+    };
+
+    let checks = commander_checks(report, locale);
+
+    let game_changers = if report.game_changers().is_empty() {
+        Message::NoGameChangers.text(locale)
+    } else {
+        format!(
+            "{}: {}",
+            report.game_changers().len(),
+            name_list(report.game_changers(), locale)
+        )
+    };
+
+    let mut embed = CreateEmbed::default()
+        .title(report.commanders().join(" & "))
+        .description(format!(
+            "{}\n\n{}",
+            Message::ColourIdentity {
+                identity: &identity
+            }
+            .text(locale),
+            checks.join("\n")
+        ))
+        .field(Message::GameChangers.text(locale), game_changers, false)
+        .field(
+            Message::Bracket.text(locale),
+            Message::BracketOrHigher {
+                bracket: report.bracket().name(),
+            }
+            .text(locale),
+            true,
+        )
+        .colour(if report.is_legal() {
+            0x0057_A773
+        } else {
+            0x00ED_4245
+        });
+
+    let unresolved = report.unresolved();
+    if !unresolved.is_empty() {
+        let lines: Vec<String> = unresolved
+            .iter()
+            .take(UNRESOLVED_SHOWN)
+            .map(|line| format!("- `{line}`"))
+            .collect();
+        embed = embed.field(Message::CouldNotFind.text(locale), lines.join("\n"), false);
+    }
+
+    embed
+}
+
+/// Discord rejects embed fields with empty values.
+fn non_empty(value: String) -> String {
+    if value.is_empty() {
+        String::from("-")
+    } else {
+        value
+    }
+}
+
+/// The `Analyse deck` context-menu action is deferred by the dispatcher
+/// while the list resolves, so this edits that response rather than
+/// creating one.
+#[async_trait]
+impl DeckInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.command().user.id.get(),
+            guild_id: self.command().guild_id.map(GuildId::get),
+        }
+    }
+
+    async fn send_commander_report(
+        &self,
+        report: CommanderReport,
+    ) -> Result<(), MessageInteractionError> {
+        let embed = build_commander_embed(&report, self.locale()).await;
+        self.command()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().embed(embed),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn send_report(&self, report: DeckReport) -> Result<(), MessageInteractionError> {
+        let embed = build_report_embed(&report, self.locale()).await;
+        self.command()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().embed(embed),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.command()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().content(message),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl DeckInteraction for DiscordModalInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(self.modal().guild_locale.as_deref(), &self.modal().locale)
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.modal().user.id.get(),
+            guild_id: self.modal().guild_id.map(GuildId::get),
+        }
+    }
+
+    async fn send_commander_report(
+        &self,
+        report: CommanderReport,
+    ) -> Result<(), MessageInteractionError> {
+        let embed = build_commander_embed(&report, self.locale()).await;
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().embed(embed),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn send_report(&self, report: DeckReport) -> Result<(), MessageInteractionError> {
+        let embed = build_report_embed(&report, self.locale()).await;
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().embed(embed),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().content(message),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
@@ -1,4 +1,6 @@
+use crate::adapters::drivers::discord::utils::interaction_locale;
 use crate::domain::functions::game::state::{Difficulty, GameState};
+use crate::domain::i18n::{Locale, Message};
 use crate::ports::drivers::client::{GameInteraction, MessageInteractionError};
 use async_trait::async_trait;
 use contracts::card::Card;
@@ -6,7 +8,8 @@ use contracts::image::Image;
 use discord_embeds::{add_emoji, create_embed, get_colour_identity, italicise_reminder_text};
 use serenity::all::{
     CommandInteraction, Context, CreateAttachment, CreateEmbed, CreateEmbedFooter,
-    CreateInteractionResponse, CreateInteractionResponseMessage, MessageBuilder,
+    CreateInteractionResponse, CreateInteractionResponseFollowup, CreateInteractionResponseMessage,
+    EditInteractionResponse, Mentionable,
 };
 use uuid::Uuid;
 
@@ -63,6 +66,47 @@ impl DiscordCommandInteraction {
     pub fn new(ctx: Context, command: CommandInteraction) -> Self {
         Self { ctx, command }
     }
+
+    pub(crate) fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    pub(crate) fn command(&self) -> &CommandInteraction {
+        &self.command
+    }
+
+    /// Answers a command the dispatcher deferred.
+    pub(crate) async fn edit(
+        &self,
+        response: EditInteractionResponse,
+    ) -> Result<(), MessageInteractionError> {
+        self.command
+            .edit_response(&self.ctx.http, response)
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    /// A deferred response can't be made ephemeral, so this swaps it for an
+    /// ephemeral follow-up.
+    pub(crate) async fn follow_up_ephemeral(
+        &self,
+        message: String,
+    ) -> Result<(), MessageInteractionError> {
+        if let Err(why) = self.command.delete_response(&self.ctx.http).await {
+            log::warn!("couldn't delete deferred response: {why}");
+        }
+        self.command
+            .create_followup(
+                &self.ctx.http,
+                CreateInteractionResponseFollowup::new()
+                    .content(message)
+                    .ephemeral(true),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
 }
 
 #[async_trait]
@@ -84,17 +128,13 @@ impl GameInteraction for DiscordCommandInteraction {
 
         let embed = create_game_embed(&state.card, state.multiplier(), state.guesses()).await;
 
-        let remaining_guesses = state.max_guesses() - state.number_of_guesses();
-        let guess_plural = if remaining_guesses > 1 {
-            "guesses"
-        } else {
-            "guess"
+        let message = Message::WrongGuess {
+            guess: &guess,
+            remaining: state.max_guesses() - state.number_of_guesses(),
         };
 
         let response = CreateInteractionResponseMessage::new()
-            .content(format!(
-                "'{guess}' was not the correct card. You have {remaining_guesses} {guess_plural} remaining",
-            ))
+            .content(message.text(self.locale()))
             .add_file(illustration)
             .embed(embed);
 
@@ -121,10 +161,11 @@ impl GameInteraction for DiscordCommandInteraction {
             CreateAttachment::bytes(images.bytes(), format!("{illustration_id}.png"));
         let difficulty = state.difficulty();
         let set_name = state.card().set_name();
-        let message = match difficulty {
-            Difficulty::Hard => format!("Difficulty is set to `{difficulty}`."),
-            _ => format!("Difficulty is set to `{difficulty}`. This card is from `{set_name}`"),
-        };
+        let message = Message::NewGame {
+            difficulty,
+            set_name: (!matches!(difficulty, Difficulty::Hard)).then_some(set_name),
+        }
+        .text(self.locale());
 
         let embed = create_game_embed(&state.card, state.multiplier(), state.guesses()).await;
         let response = CreateInteractionResponseMessage::new()
@@ -148,19 +189,11 @@ impl GameInteraction for DiscordCommandInteraction {
         let image =
             CreateAttachment::bytes(images.bytes(), format!("{}.png", state.card().image_id()));
 
-        let number_of_guesses = state.number_of_guesses();
-        let guess_plural = if number_of_guesses > 1 {
-            "guesses"
-        } else {
-            "guess"
-        };
-
-        let message = MessageBuilder::new()
-            .mention(&self.command.user)
-            .push(format!(
-                " has won after {number_of_guesses} {guess_plural}!",
-            ))
-            .build();
+        let message = Message::GameWon {
+            player: &self.command.user.mention().to_string(),
+            guesses: state.number_of_guesses(),
+        }
+        .text(self.locale());
 
         let embed = create_embed(&state.card).await;
 
@@ -191,18 +224,10 @@ impl GameInteraction for DiscordCommandInteraction {
     ) -> Result<(), MessageInteractionError> {
         let image =
             CreateAttachment::bytes(images.bytes(), format!("{}.png", state.card().image_id()));
-        let number_of_guesses = state.number_of_guesses();
-        let guess_plural = if number_of_guesses > 1 {
-            "guesses"
-        } else {
-            "guess"
-        };
-
-        let message = MessageBuilder::new()
-            .push(format!(
-                "You have all failed after {number_of_guesses} {guess_plural}!",
-            ))
-            .build();
+        let message = Message::GameLost {
+            guesses: state.number_of_guesses(),
+        }
+        .text(self.locale());
 
         let embed = create_embed(&state.card).await;
 
@@ -229,6 +254,18 @@ impl GameInteraction for DiscordCommandInteraction {
         self.command.channel_id.to_string()
     }
 
+    fn guild_id(&self) -> Option<String> {
+        self.command.guild_id.map(|guild_id| guild_id.to_string())
+    }
+
+    fn user_id(&self) -> u64 {
+        self.command.user.id.get()
+    }
+
+    fn locale(&self) -> Locale {
+        interaction_locale(self.command.guild_locale.as_deref(), &self.command.locale)
+    }
+
     async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
         let response = CreateInteractionResponse::Message(
             CreateInteractionResponseMessage::new()
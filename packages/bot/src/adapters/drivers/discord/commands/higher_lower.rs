@@ -0,0 +1,205 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::components::interaction::HIGHER_LOWER;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::adapters::drivers::discord::utils::reply::Reply;
+use crate::domain::functions::higher_lower::state::{HigherLowerState, Pick, Reveal};
+use crate::domain::i18n::{Locale, Message};
+use crate::ports::drivers::client::{HigherLowerInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use cards_sdk::GameStats;
+use contracts::image::Image;
+use contracts::priced_card::PricedCard;
+use discord_embeds::get_colour_identity;
+use serenity::all::{
+    ButtonStyle, CreateActionRow, CreateAttachment, CreateButton, CreateEmbed, Mentionable, User,
+};
+
+/// Discord rejects button labels longer than this.
+const LABEL_LIMIT: usize = 80;
+
+const LEFT_FILE: &str = "left.png";
+const RIGHT_FILE: &str = "right.png";
+
+fn pick_id(pick: Pick) -> &'static str {
+    match pick {
+        Pick::Left => "left",
+        Pick::Right => "right",
+    }
+}
+
+fn label(card: &PricedCard) -> String {
+    card.card().name().chars().take(LABEL_LIMIT).collect()
+}
+
+/// `price` is `None` for the card whose price is still hidden.
+fn card_embed(card: &PricedCard, price: Option<String>, file_name: &str) -> CreateEmbed {
+    CreateEmbed::default()
+        .title(card.card().name())
+        .url(card.card().url())
+        .description(price.unwrap_or_else(|| String::from("???")))
+        .colour(get_colour_identity(card.card().colour_identity()))
+        .attachment(file_name)
+}
+
+fn with_images<R: Reply>((left, right): (Image, Image)) -> R {
+    R::default()
+        .with_file(CreateAttachment::bytes(left.bytes(), LEFT_FILE))
+        .with_file(CreateAttachment::bytes(right.bytes(), RIGHT_FILE))
+}
+
+fn reveal_line(reveal: &Reveal) -> String {
+    format!(
+        "{} {} ({}) vs {} ({})",
+        if reveal.correct() { "✅" } else { "❌" },
+        reveal.left().card().name(),
+        reveal.left().formatted_price(),
+        reveal.right().card().name(),
+        reveal.right().formatted_price(),
+    )
+}
+
+/// The left card's price is always on show; the player calls whether the
+/// right one beats it. The owner is baked into the custom ids so only they
+/// can play the run.
+pub fn build_higher_lower_round<R: Reply>(
+    state: &HigherLowerState,
+    images: (Image, Image),
+    last: Option<&Reveal>,
+    owner: u64,
+    locale: Locale,
+) -> R {
+    let mut content: Vec<String> = last.map(reveal_line).into_iter().collect();
+    content.push(
+        Message::WhichCostsMore {
+            streak: state.streak(),
+        }
+        .text(locale),
+    );
+
+    let round = state.round();
+    let buttons = [(Pick::Left, state.left()), (Pick::Right, state.right())]
+        .into_iter()
+        .map(|(pick, card)| {
+            CreateButton::new(format!("{HIGHER_LOWER}{owner}:{round}:{}", pick_id(pick)))
+                .label(label(card))
+                .style(ButtonStyle::Primary)
+        })
+        .collect();
+
+    with_images::<R>(images)
+        .with_content(content.join("\n"))
+        .with_embeds(vec![
+            card_embed(
+                state.left(),
+                Some(state.left().formatted_price()),
+                LEFT_FILE,
+            ),
+            card_embed(state.right(), None, RIGHT_FILE),
+        ])
+        .with_components(vec![CreateActionRow::Buttons(buttons)])
+}
+
+/// Both prices revealed, with the buttons disabled and the losing pick
+/// marked.
+pub fn build_higher_lower_game_over<R: Reply>(
+    reveal: &Reveal,
+    stats: Option<&GameStats>,
+    images: (Image, Image),
+    user: &User,
+    locale: Locale,
+) -> R {
+    let mut content = vec![
+        reveal_line(reveal),
+        Message::RunEnded {
+            player: &user.mention().to_string(),
+            streak: reveal.streak(),
+        }
+        .text(locale),
+    ];
+    if let Some(stats) = stats {
+        content.push(Message::HigherLowerRecord { stats }.text(locale));
+    }
+
+    let buttons = [(Pick::Left, reveal.left()), (Pick::Right, reveal.right())]
+        .into_iter()
+        .map(|(pick, card)| {
+            let style = if pick == reveal.pick() {
+                ButtonStyle::Danger
+            } else {
+                ButtonStyle::Success
+            };
+            CreateButton::new(format!("{HIGHER_LOWER}done:{}", pick_id(pick)))
+                .label(label(card))
+                .style(style)
+                .disabled(true)
+        })
+        .collect();
+
+    with_images::<R>(images)
+        .with_content(content.join("\n"))
+        .with_embeds(vec![
+            card_embed(
+                reveal.left(),
+                Some(reveal.left().formatted_price()),
+                LEFT_FILE,
+            ),
+            card_embed(
+                reveal.right(),
+                Some(reveal.right().formatted_price()),
+                RIGHT_FILE,
+            ),
+        ])
+        .with_components(vec![CreateActionRow::Buttons(buttons)])
+}
+
+/// `/higherlower` is deferred by the dispatcher while it draws two priced
+/// cards, so this edits that response rather than creating one.
+#[async_trait]
+impl HigherLowerInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_round(
+        &self,
+        state: HigherLowerState,
+        images: (Image, Image),
+        last: Option<Reveal>,
+    ) -> Result<(), MessageInteractionError> {
+        self.edit(build_higher_lower_round(
+            &state,
+            images,
+            last.as_ref(),
+            self.user_id(),
+            self.locale(),
+        ))
+        .await
+    }
+
+    async fn send_game_over(
+        &self,
+        reveal: Reveal,
+        stats: Option<GameStats>,
+        images: (Image, Image),
+    ) -> Result<(), MessageInteractionError> {
+        self.edit(build_higher_lower_game_over(
+            &reveal,
+            stats.as_ref(),
+            images,
+            &self.command().user,
+            self.locale(),
+        ))
+        .await
+    }
+
+    fn user_id(&self) -> u64 {
+        self.command().user.id.get()
+    }
+
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.follow_up_ephemeral(message).await
+    }
+}
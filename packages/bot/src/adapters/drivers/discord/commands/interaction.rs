@@ -1,13 +1,17 @@
+use crate::adapters::drivers::discord::components::interaction::DeleteOwner;
+use crate::adapters::drivers::discord::utils::interaction_locale;
 use crate::adapters::drivers::discord::utils::message::{
-    build_flip_button, build_set_dropdown, build_similar_dropdown,
+    build_card_buttons, build_functional_dropdown, build_set_dropdown, build_similar_dropdown,
 };
+use crate::domain::i18n::Locale;
+use crate::domain::rate_limit::Requester;
 use crate::ports::drivers::client::{MessageInteraction, MessageInteractionError};
 use async_trait::async_trait;
 use contracts::search_result::SearchResultDto;
 use discord_embeds::create_embed;
 use serenity::all::{
     CommandInteraction, Context, CreateActionRow, CreateAttachment, CreateInteractionResponse,
-    CreateInteractionResponseMessage,
+    CreateInteractionResponseFollowup, CreateInteractionResponseMessage, GuildId,
 };
 use tokio::time::Instant;
 
@@ -52,15 +56,24 @@ impl MessageInteraction for DiscordCommand {
 
         let mut message = CreateInteractionResponseMessage::new().add_file(front_image);
 
-        if let Some(component) = build_set_dropdown(result.printings()).await {
+        if let Some(component) = build_set_dropdown(result.printings(), self.locale()).await {
             components.push(component);
         }
 
-        if let Some(component) = build_similar_dropdown(result.similar_cards()).await {
+        if let Some(component) = build_similar_dropdown(result.similar_cards(), self.locale()).await
+        {
             components.push(component);
         }
 
-        if let Some(component) = build_flip_button(card) {
+        if let Some(component) =
+            build_functional_dropdown(result.functionally_similar(), self.locale()).await
+        {
+            components.push(component);
+        }
+
+        if let Some(component) =
+            build_card_buttons(card, Some(DeleteOwner::new(self.command.user.id.get())))
+        {
             components.push(component);
         }
 
@@ -90,4 +103,26 @@ impl MessageInteraction for DiscordCommand {
 
         Ok(())
     }
+
+    async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.command
+            .create_followup(
+                &self.ctx,
+                CreateInteractionResponseFollowup::new().content(message),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    fn locale(&self) -> Locale {
+        interaction_locale(self.command.guild_locale.as_deref(), &self.command.locale)
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.command.user.id.get(),
+            guild_id: self.command.guild_id.map(GuildId::get),
+        }
+    }
 }
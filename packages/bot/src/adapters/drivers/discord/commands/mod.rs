@@ -1,4 +1,14 @@
+pub mod artist;
+pub mod cube;
+pub mod deck;
 pub mod game;
+pub mod higher_lower;
 pub mod interaction;
 mod options;
+pub mod oracle;
+pub mod pack;
+pub mod proxies;
 pub mod register;
+pub mod rules;
+pub mod set;
+pub mod trivia;
@@ -1,8 +1,15 @@
 use crate::adapters::drivers::discord::utils::parse::{ParseError, ResolveOption};
+use crate::domain::functions::artist::ArtistOptions;
 use crate::domain::functions::game::guess::GuessOptions;
 use crate::domain::functions::game::play::PlayOptions;
 use crate::domain::functions::game::state::Difficulty;
+use crate::domain::functions::higher_lower::play::HigherLowerOptions;
+use crate::domain::functions::oracle::OracleOptions;
+use crate::domain::functions::pack::open::PackOptions;
+use crate::domain::functions::rules::CrOptions;
+use crate::domain::functions::set::SetOptions;
 use crate::domain::query::QueryParams;
+use contracts::priced_card::Currency;
 use serenity::all::ResolvedValue;
 
 impl ResolveOption for PlayOptions {
@@ -106,3 +113,132 @@ impl ResolveOption for GuessOptions {
         Ok(GuessOptions::new(guess))
     }
 }
+
+impl ResolveOption for HigherLowerOptions {
+    fn resolve(options: Vec<(&str, ResolvedValue)>) -> Result<Self, ParseError> {
+        let mut currency = Currency::default();
+
+        for (name, value) in options {
+            if name == "currency" {
+                currency = match value {
+                    ResolvedValue::String("USD") => Currency::Usd,
+                    ResolvedValue::String("EUR") => Currency::Eur,
+                    ResolvedValue::String("TIX") => Currency::Tix,
+                    ResolvedValue::String(default) => {
+                        return Err(ParseError::new(&format!(
+                            "Could not parse {default} into currency"
+                        )))
+                    }
+                    _ => return Err(ParseError::new("currency ResolvedValue was not a string")),
+                };
+            }
+        }
+
+        Ok(HigherLowerOptions::new(currency))
+    }
+}
+
+impl ResolveOption for PackOptions {
+    fn resolve(options: Vec<(&str, ResolvedValue)>) -> Result<Self, ParseError> {
+        let mut set: Option<String> = None;
+
+        for (name, value) in options {
+            if name == "set" {
+                set = match value {
+                    ResolvedValue::String(set) => Some(set.to_string()),
+                    _ => return Err(ParseError::new("set ResolvedValue was not a string")),
+                };
+            }
+        }
+
+        let Some(set) = set else {
+            return Err(ParseError::new("No set given"));
+        };
+
+        Ok(PackOptions::new(set))
+    }
+}
+
+impl ResolveOption for SetOptions {
+    fn resolve(options: Vec<(&str, ResolvedValue)>) -> Result<Self, ParseError> {
+        let mut set: Option<String> = None;
+
+        for (name, value) in options {
+            if name == "set" {
+                set = match value {
+                    ResolvedValue::String(set) => Some(set.to_string()),
+                    _ => return Err(ParseError::new("set ResolvedValue was not a string")),
+                };
+            }
+        }
+
+        let Some(set) = set else {
+            return Err(ParseError::new("No set given"));
+        };
+
+        Ok(SetOptions::new(set))
+    }
+}
+
+impl ResolveOption for ArtistOptions {
+    fn resolve(options: Vec<(&str, ResolvedValue)>) -> Result<Self, ParseError> {
+        let mut artist: Option<String> = None;
+
+        for (name, value) in options {
+            if name == "name" {
+                artist = match value {
+                    ResolvedValue::String(artist) => Some(artist.to_string()),
+                    _ => return Err(ParseError::new("name ResolvedValue was not a string")),
+                };
+            }
+        }
+
+        let Some(artist) = artist else {
+            return Err(ParseError::new("No artist given"));
+        };
+
+        Ok(ArtistOptions::new(artist))
+    }
+}
+
+impl ResolveOption for OracleOptions {
+    fn resolve(options: Vec<(&str, ResolvedValue)>) -> Result<Self, ParseError> {
+        let mut text: Option<String> = None;
+
+        for (name, value) in options {
+            if name == "text" {
+                text = match value {
+                    ResolvedValue::String(text) => Some(text.to_string()),
+                    _ => return Err(ParseError::new("text ResolvedValue was not a string")),
+                };
+            }
+        }
+
+        let Some(text) = text else {
+            return Err(ParseError::new("No text given"));
+        };
+
+        Ok(OracleOptions::new(text))
+    }
+}
+
+impl ResolveOption for CrOptions {
+    fn resolve(options: Vec<(&str, ResolvedValue)>) -> Result<Self, ParseError> {
+        let mut query: Option<String> = None;
+
+        for (name, value) in options {
+            if name == "query" {
+                query = match value {
+                    ResolvedValue::String(query) => Some(query.to_string()),
+                    _ => return Err(ParseError::new("query ResolvedValue was not a string")),
+                };
+            }
+        }
+
+        let Some(query) = query else {
+            return Err(ParseError::new("No query given"));
+        };
+
+        Ok(CrOptions::new(query))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::components::interaction::{ORACLE_CARD_ID, ORACLE_PAGE};
+use crate::adapters::drivers::discord::utils::description::create_card_description;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::adapters::drivers::discord::utils::message::page_window;
+use crate::adapters::drivers::discord::utils::reply::Reply;
+use crate::domain::functions::oracle::{OracleResults, RESULTS_PER_PAGE};
+use crate::domain::i18n::{Locale, Message};
+use crate::ports::drivers::client::{MessageInteractionError, OracleInteraction};
+use async_trait::async_trait;
+use discord_embeds::{add_emoji, colour_id_emoji};
+use serenity::all::{
+    CreateActionRow, CreateEmbed, CreateEmbedFooter, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, EditInteractionResponse,
+};
+
+async fn build_card_dropdown(results: &OracleResults, locale: Locale) -> CreateActionRow {
+    let mut options = Vec::with_capacity(results.cards().len());
+    for card in results.cards() {
+        options.push(
+            CreateSelectMenuOption::new(card.name(), card.id().to_string())
+                .emoji(colour_id_emoji(card).await)
+                .description(create_card_description(card)),
+        );
+    }
+    let menu = CreateSelectMenu::new(ORACLE_CARD_ID, CreateSelectMenuKind::String { options })
+        .placeholder(Message::OpenCard.text(locale));
+    CreateActionRow::SelectMenu(menu)
+}
+
+fn build_page_dropdown(results: &OracleResults, locale: Locale) -> Option<CreateActionRow> {
+    if results.pages() < 2 {
+        return None;
+    }
+    let options = page_window(results.page(), results.pages())
+        .map(|number| {
+            let from = number * RESULTS_PER_PAGE + 1;
+            let to = from + results.cards_on(number).len() - 1;
+            CreateSelectMenuOption::new(
+                Message::PageRange {
+                    page: number + 1,
+                    from,
+                    to,
+                }
+                .text(locale),
+                number.to_string(),
+            )
+            .default_selection(number == results.page())
+        })
+        .collect();
+    let menu = CreateSelectMenu::new(
+        format!("{ORACLE_PAGE}{}", results.text()),
+        CreateSelectMenuKind::String { options },
+    )
+    .placeholder(Message::TurnToPage.text(locale));
+    Some(CreateActionRow::SelectMenu(menu))
+}
+
+pub async fn build_oracle_message<R: Reply>(results: &OracleResults, locale: Locale) -> R {
+    let mut lines = Vec::with_capacity(results.cards().len());
+    for card in results.cards() {
+        let line = format!("[{}]({})", card.name(), card.url());
+        if card.mana_cost().is_empty() {
+            lines.push(line);
+        } else {
+            lines.push(format!("{line} {}", add_emoji(card.mana_cost()).await));
+        }
+    }
+
+    let mut footer = format!(
+        "{} • {}",
+        Message::PageOf {
+            page: results.page() + 1,
+            pages: results.pages(),
+        }
+        .text(locale),
+        Message::CardCount {
+            count: results.total()
+        }
+        .text(locale)
+    );
+    if results.capped() {
+        footer.push_str(" — ");
+        footer.push_str(&Message::BestMatchesOnly.text(locale));
+    }
+
+    let embed = CreateEmbed::default()
+        .title(
+            Message::CardsThatSay {
+                text: results.text().trim_matches('"'),
+            }
+            .text(locale),
+        )
+        .description(lines.join("\n"))
+        .footer(CreateEmbedFooter::new(footer));
+
+    let components: Vec<CreateActionRow> = [
+        Some(build_card_dropdown(results, locale).await),
+        build_page_dropdown(results, locale),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    R::default().with_embed(embed).with_components(components)
+}
+
+/// `/oracle` is deferred by the dispatcher while the search runs, so these
+/// edit that response rather than creating one.
+#[async_trait]
+impl OracleInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_results(&self, results: OracleResults) -> Result<(), MessageInteractionError> {
+        self.edit(build_oracle_message(&results, self.locale()).await)
+            .await
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().content(message))
+            .await
+    }
+}
@@ -0,0 +1,124 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::pack::booster::{Booster, PackCard, SealedPool};
+use crate::domain::i18n::{Locale, Message};
+use crate::ports::drivers::client::{MessageInteractionError, PackInteraction};
+use async_trait::async_trait;
+use contracts::image::Image;
+use discord_embeds::{add_emoji, get_colour_identity};
+use serenity::all::{CreateAttachment, CreateEmbed, EditInteractionResponse};
+
+const EXPORT_FILE: &str = "sealed-pool.txt";
+
+/// One line per card: rarity code, linked name, mana cost, and a sparkle
+/// for foils.
+async fn pack_line(pack_card: &PackCard) -> String {
+    let card = pack_card.card().card();
+    let mut line = format!(
+        "`{}` [{}]({}) {}",
+        pack_card.card().rarity().symbol(),
+        card.name(),
+        card.url(),
+        add_emoji(card.mana_cost()).await
+    );
+    if pack_card.foil() {
+        line.push_str(" ✨");
+    }
+    line
+}
+
+async fn build_pack_message(
+    booster: &Booster,
+    image: Option<Image>,
+    locale: Locale,
+) -> EditInteractionResponse {
+    let mut lines = Vec::with_capacity(booster.cards().len());
+    for pack_card in booster.cards() {
+        lines.push(pack_line(pack_card).await);
+    }
+
+    let mut embed = CreateEmbed::default()
+        .title(
+            Message::Booster {
+                set_name: booster.set_name(),
+            }
+            .text(locale),
+        )
+        .description(lines.join("\n"));
+    let mut message = EditInteractionResponse::new();
+
+    if let Some(rare) = booster.rare() {
+        embed = embed.colour(get_colour_identity(rare.card().colour_identity()));
+        if let Some(image) = image {
+            let file_name = format!("{}.png", rare.card().image_id());
+            embed = embed.attachment(&file_name);
+            message = message.new_attachment(CreateAttachment::bytes(image.bytes(), file_name));
+        }
+    }
+
+    message.add_embed(embed)
+}
+
+/// The rares and mythics go in the embed; the full pool rides along as a
+/// text file ready to import into a deck builder.
+async fn build_sealed_message(pool: &SealedPool, locale: Locale) -> EditInteractionResponse {
+    let rares = pool.rares();
+    let mut lines = Vec::with_capacity(rares.len());
+    for pack_card in rares {
+        lines.push(pack_line(pack_card).await);
+    }
+
+    let embed = CreateEmbed::default()
+        .title(
+            Message::SealedPool {
+                set_name: pool.set_name(),
+            }
+            .text(locale),
+        )
+        .description(format!(
+            "{}\n{}",
+            Message::PacksOpened {
+                packs: pool.packs().len(),
+            }
+            .text(locale),
+            lines.join("\n")
+        ));
+
+    EditInteractionResponse::new()
+        .add_embed(embed)
+        .new_attachment(CreateAttachment::bytes(
+            pool.export().into_bytes(),
+            EXPORT_FILE,
+        ))
+}
+
+/// `/pack` and `/sealed` are deferred by the dispatcher while the packs are
+/// drawn, so these edit that response rather than creating one.
+#[async_trait]
+impl PackInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_pack(
+        &self,
+        booster: Booster,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError> {
+        let message = build_pack_message(&booster, image, self.locale()).await;
+        self.edit(message).await
+    }
+
+    async fn send_sealed(&self, pool: SealedPool) -> Result<(), MessageInteractionError> {
+        let message = build_sealed_message(&pool, self.locale()).await;
+        self.edit(message).await
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().content(message))
+            .await
+    }
+}
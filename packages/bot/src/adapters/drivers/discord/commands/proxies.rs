@@ -0,0 +1,108 @@
+use crate::adapters::drivers::discord::modals::interaction::{
+    DiscordModalInteraction, LIST_INPUT, PROXIES,
+};
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::proxies::pdf::Paper;
+use crate::domain::functions::proxies::print::MAX_COPIES;
+use crate::domain::i18n::{Locale, Message};
+use crate::domain::rate_limit::Requester;
+use crate::ports::drivers::client::{MessageInteractionError, ProxyInteraction};
+use async_trait::async_trait;
+use serenity::all::{
+    CreateActionRow, CreateAttachment, CreateInputText, CreateModal, EditInteractionResponse,
+    GuildId, InputTextStyle,
+};
+
+/// Discord caps text inputs at 4000 characters.
+const LIST_LIMIT: u16 = 4000;
+const SKIPPED_SHOWN: usize = 10;
+
+/// The paste box `/proxies` opens, with the layout options carried in the
+/// custom id for when it's submitted.
+pub fn build_proxies_modal(paper: Paper, cut_lines: bool, locale: Locale) -> CreateModal {
+    CreateModal::new(
+        format!("{PROXIES}{}:{cut_lines}", paper.name()),
+        Message::ProxiesModalTitle.text(locale),
+    )
+    .components(vec![CreateActionRow::InputText(
+        CreateInputText::new(
+            InputTextStyle::Paragraph,
+            Message::ProxyListLabel { max: MAX_COPIES }.text(locale),
+            LIST_INPUT,
+        )
+        .placeholder("4 Lightning Bolt\n1 Delver of Secrets")
+        .max_length(LIST_LIMIT),
+    )])
+}
+
+/// Reads the paper size and cut lines choice back out of a proxies modal's
+/// custom id, minus its prefix.
+pub fn parse_proxies_id(options: &str) -> (Paper, bool) {
+    let (paper, cut_lines) = options.split_once(':').unwrap_or((options, "true"));
+    (
+        Paper::from_name(paper).unwrap_or_default(),
+        cut_lines != "false",
+    )
+}
+
+#[async_trait]
+impl ProxyInteraction for DiscordModalInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(self.modal().guild_locale.as_deref(), &self.modal().locale)
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.modal().user.id.get(),
+            guild_id: self.modal().guild_id.map(GuildId::get),
+        }
+    }
+
+    async fn send_pdf(
+        &self,
+        pdf: Vec<u8>,
+        skipped: Vec<String>,
+    ) -> Result<(), MessageInteractionError> {
+        let locale = self.locale();
+        let mut content = vec![Message::ProxiesReady.text(locale)];
+        if !skipped.is_empty() {
+            content.push(Message::LeftOut.text(locale));
+            content.extend(
+                skipped
+                    .iter()
+                    .take(SKIPPED_SHOWN)
+                    .map(|name| format!("- `{name}`")),
+            );
+            if skipped.len() > SKIPPED_SHOWN {
+                content.push(
+                    Message::AndMore {
+                        count: skipped.len() - SKIPPED_SHOWN,
+                    }
+                    .text(locale),
+                );
+            }
+        }
+
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new()
+                    .content(content.join("\n"))
+                    .new_attachment(CreateAttachment::bytes(pdf, "proxies.pdf")),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.modal()
+            .edit_response(
+                &self.ctx().http,
+                EditInteractionResponse::new().content(message),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
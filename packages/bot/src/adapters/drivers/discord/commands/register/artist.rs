@@ -0,0 +1,9 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command("artist", "Look up an artist and browse their illustrations").add_option(
+        CreateCommandOption::new(CommandOptionType::String, "name", "The artist's name")
+            .required(true),
+    )
+}
@@ -0,0 +1,13 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command("cr", "Look up a rule in the Comprehensive Rules").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "query",
+            "A rule number, like 702.19b, or a keyword, like trample",
+        )
+        .required(true),
+    )
+}
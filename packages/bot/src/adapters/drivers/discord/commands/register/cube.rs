@@ -0,0 +1,60 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use crate::domain::functions::cube::draft::MAX_PACKS;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+/// Long enough for any sensible name while keeping the import modal's
+/// custom id under Discord's 100 character limit.
+const NAME_LIMIT: u16 = 50;
+
+fn name_option() -> CreateCommandOption {
+    CreateCommandOption::new(CommandOptionType::String, "name", "The cube's name")
+        .max_length(NAME_LIMIT)
+        .required(true)
+}
+
+pub fn register() -> CreateCommand {
+    localised_command("cube", "Build this server's cubes and draft from them")
+        .dm_permission(false)
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "create",
+                "Create a new, empty cube",
+            )
+            .add_sub_option(name_option()),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Add a card to a cube")
+                .add_sub_option(name_option())
+                .add_sub_option(
+                    CreateCommandOption::new(CommandOptionType::String, "card", "Card to add")
+                        .required(true),
+                ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "import",
+                "Paste a list of cards to add to a cube",
+            )
+            .add_sub_option(name_option()),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "pack",
+                "Deal random 15 card packs from a cube",
+            )
+            .add_sub_option(name_option())
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "packs",
+                    "How many packs to deal (default 1)",
+                )
+                .min_int_value(1)
+                .max_int_value(MAX_PACKS as u64)
+                .required(false),
+            ),
+        )
+}
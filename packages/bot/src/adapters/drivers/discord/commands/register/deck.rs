@@ -0,0 +1,28 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CommandType, CreateCommand, CreateCommandOption};
+
+/// The message context-menu action, shown under Apps when right-clicking a
+/// message with a deck list in it.
+pub const ANALYSE_DECK: &str = "Analyse deck";
+
+pub fn register() -> CreateCommand {
+    localised_command("deck", "Analyse a pasted deck list").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "currency",
+            "Which prices to total the deck in",
+        )
+        .add_string_choice("USD", "USD")
+        .add_string_choice("EUR", "EUR")
+        .add_string_choice("TIX", "TIX")
+        .required(false),
+    )
+}
+
+pub fn register_message_command() -> CreateCommand {
+    CreateCommand::new(ANALYSE_DECK).kind(CommandType::Message)
+}
+
+pub fn register_commander() -> CreateCommand {
+    localised_command("commander", "Check a pasted Commander deck")
+}
@@ -1,5 +1,6 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
 use serenity::builder::CreateCommand;
 
 pub fn register() -> CreateCommand {
-    CreateCommand::new("give_up").description("Give up on the current game")
+    localised_command("give_up", "Give up on the current game")
 }
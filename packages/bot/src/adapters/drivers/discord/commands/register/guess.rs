@@ -1,14 +1,13 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
 use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
 
 pub fn register() -> CreateCommand {
-    CreateCommand::new("guess")
-        .description("Guess the card")
-        .add_option(
-            CreateCommandOption::new(
-                CommandOptionType::String,
-                "card",
-                "The name of the card you want to guess",
-            )
-            .required(true),
+    localised_command("guess", "Guess the card").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "card",
+            "The name of the card you want to guess",
         )
+        .required(true),
+    )
 }
@@ -1,5 +1,6 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
 use serenity::all::CreateCommand;
 
 pub fn register() -> CreateCommand {
-    CreateCommand::new("help").description("Instructions on how to use the bot.")
+    localised_command("help", "Instructions on how to use the bot.")
 }
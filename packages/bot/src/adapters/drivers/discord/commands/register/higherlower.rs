@@ -0,0 +1,16 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command("higherlower", "Guess which of two cards costs more").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "currency",
+            "Which prices to play with",
+        )
+        .add_string_choice("USD", "USD")
+        .add_string_choice("EUR", "EUR")
+        .add_string_choice("TIX", "TIX")
+        .required(false),
+    )
+}
@@ -1,6 +1,34 @@
+pub mod artist;
+pub mod cr;
+pub mod cube;
+pub mod deck;
 pub mod give_up;
 pub mod guess;
 pub mod help;
+pub mod higherlower;
+pub mod oracle;
+pub mod pack;
 pub mod play;
+pub mod proxies;
+pub mod sealed;
 pub mod search;
+pub mod set;
 pub mod spoilers;
+pub mod trivia;
+
+use crate::domain::i18n::{self, Locale};
+use serenity::all::CreateCommand;
+
+/// A slash command, with its name and description translated for every
+/// locale that has them. Discord still sends back the English name.
+pub fn localised_command(name: &str, description: &str) -> CreateCommand {
+    Locale::ALL.into_iter().fold(
+        CreateCommand::new(name).description(description),
+        |command, locale| match i18n::command(locale, name) {
+            Some((localised_name, localised_description)) => command
+                .name_localized(locale.discord_code(), localised_name)
+                .description_localized(locale.discord_code(), localised_description),
+            None => command,
+        },
+    )
+}
@@ -0,0 +1,18 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+/// Plenty for a line of rules text while keeping the page menu's custom id
+/// under Discord's 100 character limit.
+const TEXT_LIMIT: u16 = 80;
+
+pub fn register() -> CreateCommand {
+    localised_command("oracle", "Find cards by their rules text").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "text",
+            "Rules text to look for — put a phrase in quotes to match it exactly",
+        )
+        .max_length(TEXT_LIMIT)
+        .required(true),
+    )
+}
@@ -0,0 +1,13 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command("pack", "Open a booster pack from a set").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "set",
+            "The set to open a pack of (name or code)",
+        )
+        .required(true),
+    )
+}
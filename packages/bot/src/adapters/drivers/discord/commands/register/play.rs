@@ -1,8 +1,8 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
 use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
 
 pub fn register() -> CreateCommand {
-    CreateCommand::new("play")
-        .description("Start a guess the card game")
+    localised_command("play", "Start a guess the card game")
         .add_option(
             CreateCommandOption::new(
                 CommandOptionType::String,
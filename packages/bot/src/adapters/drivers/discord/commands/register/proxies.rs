@@ -0,0 +1,23 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command(
+        "proxies",
+        "Make a printable PDF of proxies from a pasted card list",
+    )
+    .add_option(
+        CreateCommandOption::new(CommandOptionType::String, "paper", "Page size to print on")
+            .add_string_choice("A4", "A4")
+            .add_string_choice("Letter", "Letter")
+            .required(false),
+    )
+    .add_option(
+        CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "cut_lines",
+            "Draw lines in the margins to cut along (default: yes)",
+        )
+        .required(false),
+    )
+}
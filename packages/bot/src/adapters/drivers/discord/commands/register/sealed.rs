@@ -0,0 +1,13 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command("sealed", "Open six booster packs as a sealed pool").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "set",
+            "The set to open packs of (name or code)",
+        )
+        .required(true),
+    )
+}
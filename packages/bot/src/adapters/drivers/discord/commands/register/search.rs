@@ -1,8 +1,8 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
 use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
 
 pub fn register() -> CreateCommand {
-    CreateCommand::new("search")
-        .description("Search for a card")
+    localised_command("search", "Search for a card")
         .add_option(
             CreateCommandOption::new(CommandOptionType::String, "name", "Name of the card")
                 .required(true),
@@ -0,0 +1,13 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption};
+
+pub fn register() -> CreateCommand {
+    localised_command("set", "Look up a set and browse its cards").add_option(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "set",
+            "The set to look up (name or code)",
+        )
+        .required(true),
+    )
+}
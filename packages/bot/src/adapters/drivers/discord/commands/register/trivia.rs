@@ -0,0 +1,6 @@
+use crate::adapters::drivers::discord::commands::register::localised_command;
+use serenity::builder::CreateCommand;
+
+pub fn register() -> CreateCommand {
+    localised_command("trivia", "Name the card from its art or rules text")
+}
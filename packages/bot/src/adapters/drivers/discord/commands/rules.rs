@@ -0,0 +1,142 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::rules::RuleEntry;
+use crate::domain::i18n::Locale;
+use crate::ports::drivers::client::{MessageInteractionError, RulesInteraction};
+use async_trait::async_trait;
+use serenity::all::{CreateEmbed, CreateEmbedFooter, EditInteractionResponse};
+
+/// Discord's limit on an embed's description.
+const DESCRIPTION_LIMIT: usize = 4096;
+/// Room kept for saying how many rules didn't fit.
+const MORE_RESERVE: usize = 32;
+
+/// The rule's own text, unless it's just its title, then the rules under
+/// it for as long as they fit.
+fn rule_description(entry: &RuleEntry) -> String {
+    let section = entry.section();
+    let mut description = if section.title() == Some(section.text()) {
+        String::new()
+    } else {
+        section.text().to_string()
+    };
+
+    let subsections = entry.subsections();
+    for (index, subsection) in subsections.iter().enumerate() {
+        let line = match subsection.title() {
+            Some(title) => format!("**{}.** {title}", subsection.number()),
+            None => format!("**{}** {}", subsection.number(), subsection.text()),
+        };
+        let left = subsections.len() - index;
+        let reserve = if left > 1 { MORE_RESERVE } else { 0 };
+        if description.chars().count() + line.chars().count() + reserve + 2 > DESCRIPTION_LIMIT {
+            description.push_str("\n\n…and ");
+            description.push_str(&left.to_string());
+            description.push_str(" more");
+            break;
+        }
+        if !description.is_empty() {
+            description.push_str("\n\n");
+        }
+        description.push_str(&line);
+    }
+    description
+}
+
+fn build_rule_embed(entry: &RuleEntry) -> CreateEmbed {
+    let section = entry.section();
+    let title = match section.title() {
+        Some(title) => format!("{}. {title}", section.number()),
+        None => format!("Rule {}", section.number()),
+    };
+    CreateEmbed::default()
+        .title(title)
+        .description(rule_description(entry))
+        .footer(CreateEmbedFooter::new(
+            "Magic: The Gathering Comprehensive Rules",
+        ))
+}
+
+/// `/cr` is deferred by the dispatcher while the rule is looked up, so
+/// these edit that response rather than creating one.
+#[async_trait]
+impl RulesInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_rule(&self, entry: RuleEntry) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().embed(build_rule_embed(&entry)))
+            .await
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().content(message))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts::rule_section::RuleSection;
+
+    fn section(number: &str, title: Option<&str>, text: &str) -> RuleSection {
+        RuleSection::new(
+            number.to_string(),
+            title.map(str::to_string),
+            text.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_named_rule_lists_its_subrules() {
+        let entry = RuleEntry::new(vec![
+            section("702.19", Some("Trample"), "Trample"),
+            section("702.19a", None, "Trample is a static ability."),
+            section("702.19b", None, "Assign lethal damage to blockers first."),
+        ])
+        .unwrap();
+        assert_eq!(
+            rule_description(&entry),
+            "**702.19a** Trample is a static ability.\n\n**702.19b** Assign lethal damage to blockers first."
+        );
+    }
+
+    #[test]
+    fn test_chapter_lists_rule_titles() {
+        let entry = RuleEntry::new(vec![
+            section("702", Some("Keyword Abilities"), "Keyword Abilities"),
+            section(
+                "702.1",
+                None,
+                "Most abilities describe exactly what they do.",
+            ),
+            section("702.2", Some("Deathtouch"), "Deathtouch"),
+        ])
+        .unwrap();
+        assert_eq!(
+            rule_description(&entry),
+            "**702.1** Most abilities describe exactly what they do.\n\n**702.2.** Deathtouch"
+        );
+    }
+
+    #[test]
+    fn test_long_rules_are_cut_short() {
+        let mut sections = vec![section(
+            "702",
+            Some("Keyword Abilities"),
+            "Keyword Abilities",
+        )];
+        sections.extend(
+            (1..=200)
+                .map(|rule| section(&format!("702.{rule}"), None, &"Some rules text. ".repeat(5))),
+        );
+        let description = rule_description(&RuleEntry::new(sections).unwrap());
+        assert!(description.chars().count() <= DESCRIPTION_LIMIT);
+        assert!(description.ends_with(" more"));
+    }
+}
@@ -0,0 +1,214 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::components::interaction::{SET_CARD_ID, SET_PAGE};
+use crate::adapters::drivers::discord::utils::description::create_card_description;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::adapters::drivers::discord::utils::message::page_window;
+use crate::adapters::drivers::discord::utils::reply::Reply;
+use crate::domain::functions::set::{SetPage, CARDS_PER_PAGE};
+use crate::domain::i18n::{Locale, Message};
+use crate::ports::drivers::client::{MessageInteractionError, SetInteraction};
+use async_trait::async_trait;
+use contracts::set_details::SetDetails;
+use discord_embeds::{colour_id_emoji, get_emoji};
+use serenity::all::{
+    CreateActionRow, CreateEmbed, CreateEmbedFooter, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, EditInteractionResponse,
+};
+
+/// Scryfall's `draft_innovation` reads as "Draft innovation".
+fn set_type_name(set_type: &str) -> String {
+    let mut name = set_type.replace('_', " ");
+    if let Some(first) = name.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    name
+}
+
+/// The set symbol, release date and card count, with whatever else is known
+/// about the set on the lines after.
+async fn set_summary(details: &SetDetails, locale: Locale) -> String {
+    let mut headline = Vec::with_capacity(3);
+    if let Some(emoji) = get_emoji(details.abbreviation()).await {
+        headline.push(format!("<:{}:{}>", emoji.name, emoji.id));
+    }
+    if let Some(release_date) = details.release_date() {
+        headline.push(
+            Message::SetReleased {
+                date: &release_date.to_string(),
+            }
+            .text(locale),
+        );
+    }
+    if let Some(card_count) = details.card_count() {
+        headline.push(
+            Message::CardCount {
+                count: card_count as usize,
+            }
+            .text(locale),
+        );
+    }
+
+    let mut lines = vec![headline.join(" • ")];
+    if let Some(set_type) = details.set_type() {
+        lines.push(format!(
+            "**{}:** {}",
+            Message::SetType.text(locale),
+            set_type_name(set_type)
+        ));
+    }
+    if let Some(block) = details.block() {
+        lines.push(format!("**{}:** {block}", Message::SetBlock.text(locale)));
+    }
+    if let Some(parent) = details.parent_abbreviation() {
+        lines.push(format!(
+            "**{}:** {}",
+            Message::SetParent.text(locale),
+            parent.to_uppercase()
+        ));
+    }
+    lines.join("\n")
+}
+
+async fn build_card_dropdown(page: &SetPage, locale: Locale) -> Option<CreateActionRow> {
+    if page.cards().is_empty() {
+        return None;
+    }
+    let mut options = Vec::with_capacity(page.cards().len());
+    for set_card in page.cards() {
+        let card = set_card.card();
+        options.push(
+            CreateSelectMenuOption::new(card.name(), card.id().to_string())
+                .emoji(colour_id_emoji(card).await)
+                .description(format!(
+                    "{} • {}",
+                    set_card.rarity().name(),
+                    create_card_description(card)
+                )),
+        );
+    }
+    let menu = CreateSelectMenu::new(SET_CARD_ID, CreateSelectMenuKind::String { options })
+        .placeholder(Message::OpenCard.text(locale));
+    Some(CreateActionRow::SelectMenu(menu))
+}
+
+/// Each page is described by the rarities it runs between.
+fn build_page_dropdown(page: &SetPage, locale: Locale) -> Option<CreateActionRow> {
+    if page.pages() < 2 {
+        return None;
+    }
+    let options = page_window(page.page(), page.pages())
+        .filter_map(|number| {
+            let cards = page.cards_on(number);
+            let (start, end) = (cards.first()?.rarity(), cards.last()?.rarity());
+            let rarities = if start == end {
+                start.name().to_string()
+            } else {
+                format!("{} to {}", start.name(), end.name())
+            };
+            let from = number * CARDS_PER_PAGE + 1;
+            Some(
+                CreateSelectMenuOption::new(
+                    Message::PageRange {
+                        page: number + 1,
+                        from,
+                        to: from + cards.len() - 1,
+                    }
+                    .text(locale),
+                    number.to_string(),
+                )
+                .description(rarities)
+                .default_selection(number == page.page()),
+            )
+        })
+        .collect();
+    let menu = CreateSelectMenu::new(
+        format!("{SET_PAGE}{}", page.details().abbreviation()),
+        CreateSelectMenuKind::String { options },
+    )
+    .placeholder(Message::TurnToPage.text(locale));
+    Some(CreateActionRow::SelectMenu(menu))
+}
+
+pub async fn build_set_message<R: Reply>(page: &SetPage, locale: Locale) -> R {
+    let details = page.details();
+    let lines: Vec<String> = page
+        .cards()
+        .iter()
+        .map(|set_card| {
+            format!(
+                "`{}` [{}]({})",
+                set_card.rarity().symbol(),
+                set_card.card().name(),
+                set_card.card().url()
+            )
+        })
+        .collect();
+
+    let embed = CreateEmbed::default()
+        .title(format!(
+            "{} ({})",
+            details.name(),
+            details.abbreviation().to_uppercase()
+        ))
+        .description(format!(
+            "{}\n\n{}",
+            set_summary(details, locale).await,
+            lines.join("\n")
+        ))
+        .footer(CreateEmbedFooter::new(format!(
+            "{} • {}",
+            Message::PageOf {
+                page: page.page() + 1,
+                pages: page.pages(),
+            }
+            .text(locale),
+            Message::CardsListed {
+                count: page.total()
+            }
+            .text(locale)
+        )));
+
+    let components: Vec<CreateActionRow> = [
+        build_card_dropdown(page, locale).await,
+        build_page_dropdown(page, locale),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    R::default().with_embed(embed).with_components(components)
+}
+
+/// `/set` is deferred by the dispatcher while the set is looked up, so
+/// these edit that response rather than creating one.
+#[async_trait]
+impl SetInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_set(&self, page: SetPage) -> Result<(), MessageInteractionError> {
+        self.edit(build_set_message(&page, self.locale()).await)
+            .await
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.edit(EditInteractionResponse::new().content(message))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_type_name() {
+        assert_eq!(set_type_name("expansion"), "Expansion");
+        assert_eq!(set_type_name("draft_innovation"), "Draft innovation");
+        assert_eq!(set_type_name(""), "");
+    }
+}
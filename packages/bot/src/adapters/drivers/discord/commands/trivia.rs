@@ -0,0 +1,151 @@
+use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
+use crate::adapters::drivers::discord::components::interaction::TRIVIA;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::adapters::drivers::discord::utils::reply::Reply;
+use crate::domain::functions::trivia::state::{TriviaOutcome, TriviaState};
+use crate::domain::i18n::{Locale, Message};
+use crate::ports::drivers::client::{MessageInteractionError, TriviaInteraction};
+use async_trait::async_trait;
+use contracts::image::Image;
+use discord_embeds::{add_emoji, create_embed, get_colour_identity, italicise_reminder_text};
+use serenity::all::{
+    ButtonStyle, CreateActionRow, CreateAttachment, CreateButton, CreateEmbed, Mentionable, User,
+};
+
+/// Discord rejects button labels longer than this.
+const LABEL_LIMIT: usize = 80;
+
+/// One button per choice, with the round (the answer's card id) baked into
+/// each custom id. Once answered, the buttons are disabled and recoloured
+/// to show the right answer and, if different, the one that was picked.
+fn build_trivia_buttons(state: &TriviaState, picked: Option<usize>) -> CreateActionRow {
+    let round = state.card().id();
+    let buttons = state
+        .choices()
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| {
+            let label: String = choice.chars().take(LABEL_LIMIT).collect();
+            let button = CreateButton::new(format!("{TRIVIA}{round}:{index}")).label(label);
+            match picked {
+                None => button.style(ButtonStyle::Primary),
+                Some(_) if index == state.answer() => {
+                    button.style(ButtonStyle::Success).disabled(true)
+                }
+                Some(picked) if picked == index => button.style(ButtonStyle::Danger).disabled(true),
+                Some(_) => button.style(ButtonStyle::Secondary).disabled(true),
+            }
+        })
+        .collect();
+
+    CreateActionRow::Buttons(buttons)
+}
+
+pub async fn build_trivia_question<R: Reply>(
+    state: &TriviaState,
+    image: Option<Image>,
+    locale: Locale,
+) -> R {
+    let card = state.card();
+    let mut embed = CreateEmbed::default().title(Message::WhichCard.text(locale));
+    let mut message = R::default();
+
+    if let (Some(image), Some(illustration_id)) = (image, card.illustration_id()) {
+        let file_name = format!("{illustration_id}.png");
+        embed = embed.attachment(&file_name);
+        message = message.with_file(CreateAttachment::bytes(image.bytes(), file_name));
+    } else {
+        let rules_text = add_emoji(&state.redacted_rules_text()).await;
+        embed = embed
+            .description(format!(
+                "{}\n\n{}",
+                card.type_line(),
+                italicise_reminder_text(&rules_text)
+            ))
+            .colour(get_colour_identity(card.colour_identity()));
+    }
+
+    message
+        .with_embed(embed)
+        .with_components(vec![build_trivia_buttons(state, None)])
+}
+
+pub async fn build_trivia_outcome<R: Reply>(
+    outcome: &TriviaOutcome,
+    image: &Image,
+    user: &User,
+    locale: Locale,
+) -> R {
+    let state = outcome.state();
+    let card = state.card();
+
+    let player = user.mention().to_string();
+    let mut content = vec![if outcome.won() {
+        Message::GotIt { player: &player }.text(locale)
+    } else {
+        Message::PickedWrong {
+            player: &player,
+            picked: state.choices().get(outcome.choice()).map(String::as_str),
+            answer: card.name(),
+        }
+        .text(locale)
+    }];
+    if let Some(stats) = outcome.stats() {
+        content.push(Message::TriviaRecord { stats }.text(locale));
+    }
+
+    R::default()
+        .with_content(content.join("\n"))
+        .with_file(CreateAttachment::bytes(
+            image.bytes(),
+            format!("{}.png", card.image_id()),
+        ))
+        .with_embed(create_embed(card).await)
+        .with_components(vec![build_trivia_buttons(state, Some(outcome.choice()))])
+}
+
+/// `/trivia` is deferred by the dispatcher while it picks a card and its
+/// decoys, so this edits that response rather than creating one.
+#[async_trait]
+impl TriviaInteraction for DiscordCommandInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.command().guild_locale.as_deref(),
+            &self.command().locale,
+        )
+    }
+
+    async fn send_question(
+        &self,
+        state: TriviaState,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError> {
+        self.edit(build_trivia_question(&state, image, self.locale()).await)
+            .await
+    }
+
+    async fn send_outcome(
+        &self,
+        outcome: TriviaOutcome,
+        image: Image,
+    ) -> Result<(), MessageInteractionError> {
+        self.edit(build_trivia_outcome(&outcome, &image, &self.command().user, self.locale()).await)
+            .await
+    }
+
+    fn id(&self) -> String {
+        self.command().channel_id.to_string()
+    }
+
+    fn guild_id(&self) -> Option<String> {
+        self.command().guild_id.map(|guild_id| guild_id.to_string())
+    }
+
+    fn user_id(&self) -> u64 {
+        self.command().user.id.get()
+    }
+
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.follow_up_ephemeral(message).await
+    }
+}
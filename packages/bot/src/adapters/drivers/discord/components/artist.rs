@@ -0,0 +1,49 @@
+use crate::adapters::drivers::discord::commands::artist::build_gallery_message;
+use crate::adapters::drivers::discord::components::interaction::DiscordComponentInteraction;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::artist::ArtistGallery;
+use crate::domain::i18n::Locale;
+use crate::ports::drivers::client::{ArtistInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use contracts::image::Image;
+use serenity::all::{CreateInteractionResponse, CreateInteractionResponseMessage};
+
+/// Turning the page replaces the gallery in place.
+#[async_trait]
+impl ArtistInteraction for DiscordComponentInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.component().guild_locale.as_deref(),
+            &self.component().locale,
+        )
+    }
+
+    async fn send_gallery(
+        &self,
+        gallery: ArtistGallery,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError> {
+        let message = build_gallery_message(&gallery, image, self.locale()).await;
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::UpdateMessage(message),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message)
+                        .ephemeral(true),
+                ),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
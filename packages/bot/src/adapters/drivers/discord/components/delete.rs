@@ -0,0 +1,73 @@
+use crate::adapters::drivers::discord::components::interaction::{
+    DeleteOwner, DiscordComponentInteraction, DELETE,
+};
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::i18n::Locale;
+use crate::ports::drivers::client::{DeleteInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use serenity::all::{CreateInteractionResponse, CreateInteractionResponseMessage, Permissions};
+
+#[async_trait]
+impl DeleteInteraction for DiscordComponentInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.component().guild_locale.as_deref(),
+            &self.component().locale,
+        )
+    }
+
+    fn user_id(&self) -> u64 {
+        self.component().user.id.get()
+    }
+
+    fn can_manage_messages(&self) -> bool {
+        self.component()
+            .member
+            .as_ref()
+            .and_then(|member| member.permissions)
+            .is_some_and(Permissions::manage_messages)
+    }
+
+    fn source(&self) -> Option<String> {
+        let source = self
+            .component()
+            .data
+            .custom_id
+            .strip_prefix(DELETE)
+            .and_then(DeleteOwner::parse)?
+            .source()?;
+        Some(format!("{}:{source}", self.component().channel_id))
+    }
+
+    fn reply_id(&self) -> u64 {
+        self.component().message.id.get()
+    }
+
+    /// Acknowledges the press first, since there'll be no message left to
+    /// respond on afterwards.
+    async fn delete_reply(&self) -> Result<(), MessageInteractionError> {
+        self.component()
+            .create_response(&self.ctx().http, CreateInteractionResponse::Acknowledge)
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))?;
+        self.component()
+            .message
+            .delete(&self.ctx().http)
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message)
+                        .ephemeral(true),
+                ),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
@@ -0,0 +1,81 @@
+use crate::adapters::drivers::discord::commands::higher_lower::{
+    build_higher_lower_game_over, build_higher_lower_round,
+};
+use crate::adapters::drivers::discord::components::interaction::DiscordComponentInteraction;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::higher_lower::state::{HigherLowerState, Reveal};
+use crate::domain::i18n::Locale;
+use crate::ports::drivers::client::{HigherLowerInteraction, MessageInteractionError};
+use async_trait::async_trait;
+use cards_sdk::GameStats;
+use contracts::image::Image;
+use serenity::all::{CreateInteractionResponse, CreateInteractionResponseMessage};
+
+/// Every call after the first arrives as a button press, so each new round
+/// (and the final result) replaces the previous one in place.
+#[async_trait]
+impl HigherLowerInteraction for DiscordComponentInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.component().guild_locale.as_deref(),
+            &self.component().locale,
+        )
+    }
+
+    async fn send_round(
+        &self,
+        state: HigherLowerState,
+        images: (Image, Image),
+        last: Option<Reveal>,
+    ) -> Result<(), MessageInteractionError> {
+        let message =
+            build_higher_lower_round(&state, images, last.as_ref(), self.user_id(), self.locale());
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::UpdateMessage(message),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn send_game_over(
+        &self,
+        reveal: Reveal,
+        stats: Option<GameStats>,
+        images: (Image, Image),
+    ) -> Result<(), MessageInteractionError> {
+        let message = build_higher_lower_game_over(
+            &reveal,
+            stats.as_ref(),
+            images,
+            &self.component().user,
+            self.locale(),
+        );
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::UpdateMessage(message),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    fn user_id(&self) -> u64 {
+        self.component().user.id.get()
+    }
+
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message)
+                        .ephemeral(true),
+                ),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
@@ -1,27 +1,150 @@
+use crate::adapters::drivers::discord::utils::interaction_locale;
 use crate::adapters::drivers::discord::utils::message::{
-    build_flip_button, build_set_dropdown, build_similar_dropdown,
+    build_card_buttons, build_functional_dropdown, build_set_dropdown, build_similar_dropdown,
 };
+use crate::domain::i18n::Locale;
+use crate::domain::rate_limit::Requester;
 use crate::ports::drivers::client::{MessageInteraction, MessageInteractionError};
 use async_trait::async_trait;
 use contracts::search_result::SearchResultDto;
 use discord_embeds::create_embed;
 use serenity::all::{
-    ComponentInteraction, Context, CreateActionRow, CreateAttachment, CreateInteractionResponse,
-    CreateInteractionResponseMessage,
+    ActionRowComponent, ButtonKind, ComponentInteraction, Context, CreateActionRow,
+    CreateAttachment, CreateInteractionResponse, CreateInteractionResponseFollowup,
+    CreateInteractionResponseMessage, GuildId,
 };
 
 pub const PICK_PRINT_ID: &str = "pick-print-id";
 pub const SIMILAR_ID: &str = "similar-id";
+/// The select menu of cards that play like the one shown.
+pub const FUNCTIONAL_ID: &str = "functional-id";
+/// The select menu under a card grid.
+pub const GRID_ID: &str = "grid-id";
+/// The select menu of illustrations under `/artist`.
+pub const GALLERY_ID: &str = "gallery-id";
+/// Prefix for the page select menu under `/artist`:
+/// `artist-page:{normalised artist name}`.
+pub const ARTIST_PAGE: &str = "artist-page:";
+/// The select menu of cards under `/oracle`.
+pub const ORACLE_CARD_ID: &str = "oracle-card-id";
+/// Prefix for the page select menu under `/oracle`: `oracle-page:{text}`.
+pub const ORACLE_PAGE: &str = "oracle-page:";
+/// The select menu of cards under `/set`.
+pub const SET_CARD_ID: &str = "set-card-id";
+/// Prefix for the page select menu under `/set`: `set-page:{abbreviation}`.
+pub const SET_PAGE: &str = "set-page:";
 pub const FLIP: &str = "flip:";
+/// Prefix for the delete button under a reply: `delete:{requester}`, or
+/// `delete:{requester}:{source}` for an inline reply.
+pub const DELETE: &str = "delete:";
+/// Prefix for trivia answer buttons: `trivia:{round}:{choice}`.
+pub const TRIVIA: &str = "trivia:";
+/// Prefix for higher-or-lower buttons: `higherlower:{owner}:{round}:{left|right}`.
+pub const HIGHER_LOWER: &str = "higherlower:";
+
+/// Whose reply a delete button is on, and for an inline reply, the id of
+/// the message it answered, so that message's tracked replies can drop it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteOwner {
+    requester: u64,
+    source: Option<u64>,
+}
+
+impl DeleteOwner {
+    pub fn new(requester: u64) -> Self {
+        Self {
+            requester,
+            source: None,
+        }
+    }
+
+    pub fn inline(requester: u64, source: u64) -> Self {
+        Self {
+            requester,
+            source: Some(source),
+        }
+    }
+
+    /// Reads what follows `DELETE` in a button's custom id.
+    pub fn parse(owner: &str) -> Option<Self> {
+        match owner.split_once(':') {
+            Some((requester, source)) => {
+                Some(Self::inline(requester.parse().ok()?, source.parse().ok()?))
+            }
+            None => owner.parse().ok().map(Self::new),
+        }
+    }
+
+    pub fn requester(self) -> u64 {
+        self.requester
+    }
+
+    pub fn source(self) -> Option<u64> {
+        self.source
+    }
+
+    pub fn custom_id(self) -> String {
+        match self.source {
+            Some(source) => format!("{DELETE}{}:{source}", self.requester),
+            None => format!("{DELETE}{}", self.requester),
+        }
+    }
+}
 
 pub struct DiscordComponentInteraction {
     ctx: Context,
     component: ComponentInteraction,
+    new_message: bool,
 }
 
 impl DiscordComponentInteraction {
     pub fn new(ctx: Context, component: ComponentInteraction) -> Self {
-        Self { ctx, component }
+        Self {
+            ctx,
+            component,
+            new_message: false,
+        }
+    }
+
+    /// Like `new`, but `send_card` posts a new message instead of replacing
+    /// the one the component is on — so picking from a card grid leaves the
+    /// grid in place.
+    pub fn replying(ctx: Context, component: ComponentInteraction) -> Self {
+        Self {
+            ctx,
+            component,
+            new_message: true,
+        }
+    }
+
+    pub(crate) fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    pub(crate) fn component(&self) -> &ComponentInteraction {
+        &self.component
+    }
+
+    /// Whoever asked for the card: the presser, for a new message, or else
+    /// whoever the delete button on the message being replaced was for.
+    fn requester(&self) -> Option<DeleteOwner> {
+        if self.new_message {
+            return Some(DeleteOwner::new(self.component.user.id.get()));
+        }
+        self.component
+            .message
+            .components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                ActionRowComponent::Button(button) => match &button.data {
+                    ButtonKind::NonLink { custom_id, .. } => {
+                        custom_id.strip_prefix(DELETE).and_then(DeleteOwner::parse)
+                    }
+                    ButtonKind::Link { .. } | ButtonKind::Premium { .. } => None,
+                },
+                _ => None,
+            })
     }
 }
 
@@ -35,15 +158,22 @@ impl MessageInteraction for DiscordComponentInteraction {
 
         let mut message = CreateInteractionResponseMessage::new().add_file(front_image);
 
-        if let Some(component) = build_set_dropdown(result.printings()).await {
+        if let Some(component) = build_set_dropdown(result.printings(), self.locale()).await {
+            components.push(component);
+        }
+
+        if let Some(component) = build_similar_dropdown(result.similar_cards(), self.locale()).await
+        {
             components.push(component);
         }
 
-        if let Some(component) = build_similar_dropdown(result.similar_cards()).await {
+        if let Some(component) =
+            build_functional_dropdown(result.functionally_similar(), self.locale()).await
+        {
             components.push(component);
         }
 
-        if let Some(component) = build_flip_button(card) {
+        if let Some(component) = build_card_buttons(card, self.requester()) {
             components.push(component);
         }
 
@@ -53,8 +183,13 @@ impl MessageInteraction for DiscordComponentInteraction {
 
         let front = create_embed(card).await;
         message = message.add_embed(front);
+        let response = if self.new_message {
+            CreateInteractionResponse::Message(message)
+        } else {
+            CreateInteractionResponse::UpdateMessage(message)
+        };
         self.component
-            .create_response(&self.ctx, CreateInteractionResponse::UpdateMessage(message))
+            .create_response(&self.ctx, response)
             .await
             .map_err(|e| MessageInteractionError::new(e.to_string()))
     }
@@ -84,4 +219,29 @@ impl MessageInteraction for DiscordComponentInteraction {
             .await
             .map_err(|e| MessageInteractionError::new(e.to_string()))
     }
+
+    async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.component
+            .create_followup(
+                &self.ctx,
+                CreateInteractionResponseFollowup::new().content(message),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.component.guild_locale.as_deref(),
+            &self.component.locale,
+        )
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.component.user.id.get(),
+            guild_id: self.component.guild_id.map(GuildId::get),
+        }
+    }
 }
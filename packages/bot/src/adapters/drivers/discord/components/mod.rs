@@ -1 +1,7 @@
+mod artist;
+mod delete;
+mod higher_lower;
 pub mod interaction;
+mod oracle;
+mod set;
+mod trivia;
@@ -0,0 +1,44 @@
+use crate::adapters::drivers::discord::commands::oracle::build_oracle_message;
+use crate::adapters::drivers::discord::components::interaction::DiscordComponentInteraction;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::oracle::OracleResults;
+use crate::domain::i18n::Locale;
+use crate::ports::drivers::client::{MessageInteractionError, OracleInteraction};
+use async_trait::async_trait;
+use serenity::all::{CreateInteractionResponse, CreateInteractionResponseMessage};
+
+/// Turning the page replaces the results in place.
+#[async_trait]
+impl OracleInteraction for DiscordComponentInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.component().guild_locale.as_deref(),
+            &self.component().locale,
+        )
+    }
+
+    async fn send_results(&self, results: OracleResults) -> Result<(), MessageInteractionError> {
+        let message = build_oracle_message(&results, self.locale()).await;
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::UpdateMessage(message),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message)
+                        .ephemeral(true),
+                ),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
@@ -0,0 +1,82 @@
+use crate::adapters::drivers::discord::commands::trivia::{
+    build_trivia_outcome, build_trivia_question,
+};
+use crate::adapters::drivers::discord::components::interaction::DiscordComponentInteraction;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions::trivia::state::{TriviaOutcome, TriviaState};
+use crate::domain::i18n::Locale;
+use crate::ports::drivers::client::{MessageInteractionError, TriviaInteraction};
+use async_trait::async_trait;
+use contracts::image::Image;
+use serenity::all::{CreateInteractionResponse, CreateInteractionResponseMessage};
+
+/// Answers arrive as button presses on the question, so the outcome
+/// replaces the question message in place rather than posting a new one.
+#[async_trait]
+impl TriviaInteraction for DiscordComponentInteraction {
+    fn locale(&self) -> Locale {
+        interaction_locale(
+            self.component().guild_locale.as_deref(),
+            &self.component().locale,
+        )
+    }
+
+    async fn send_question(
+        &self,
+        state: TriviaState,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError> {
+        let message = build_trivia_question(&state, image, self.locale()).await;
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::Message(message),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    async fn send_outcome(
+        &self,
+        outcome: TriviaOutcome,
+        image: Image,
+    ) -> Result<(), MessageInteractionError> {
+        let message =
+            build_trivia_outcome(&outcome, &image, &self.component().user, self.locale()).await;
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::UpdateMessage(message),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+
+    fn id(&self) -> String {
+        self.component().channel_id.to_string()
+    }
+
+    fn guild_id(&self) -> Option<String> {
+        self.component()
+            .guild_id
+            .map(|guild_id| guild_id.to_string())
+    }
+
+    fn user_id(&self) -> u64 {
+        self.component().user.id.get()
+    }
+
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.component()
+            .create_response(
+                &self.ctx().http,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .content(message)
+                        .ephemeral(true),
+                ),
+            )
+            .await
+            .map_err(|e| MessageInteractionError::new(e.to_string()))
+    }
+}
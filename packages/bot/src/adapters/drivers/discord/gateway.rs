@@ -0,0 +1,67 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long the gateway can be down before the bot is restarted. Serenity
+/// reconnects by itself, so this only catches a session that never will.
+const RECONNECT_GRACE: Duration = Duration::from_mins(2);
+
+/// Whether the gateway session is up, and since when it's been that way.
+/// Starts down, as of startup, so a bot that never connects is restarted too.
+pub struct Gateway {
+    connected: AtomicBool,
+    since: AtomicU64,
+}
+
+pub static GATEWAY: LazyLock<Gateway> = LazyLock::new(|| Gateway {
+    connected: AtomicBool::new(false),
+    since: AtomicU64::new(now()),
+});
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+impl Gateway {
+    pub fn set_connected(&self, connected: bool) {
+        if self.connected.swap(connected, Ordering::Relaxed) != connected {
+            self.since.store(now(), Ordering::Relaxed);
+            log::info!("Gateway connected: {connected}");
+        }
+    }
+
+    pub fn connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Connected, or not disconnected for long enough to give up on it.
+    pub fn alive(&self) -> bool {
+        self.connected()
+            || now().saturating_sub(self.since.load(Ordering::Relaxed)) < RECONNECT_GRACE.as_secs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gateway_is_alive_within_grace() {
+        let gateway = Gateway {
+            connected: AtomicBool::new(false),
+            since: AtomicU64::new(now()),
+        };
+        assert!(!gateway.connected());
+        assert!(gateway.alive());
+
+        gateway.since.store(0, Ordering::Relaxed);
+        assert!(!gateway.alive());
+
+        gateway.set_connected(true);
+        assert!(gateway.connected());
+        assert!(gateway.alive());
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::LazyLock;
+use tokio::sync::Notify;
+
+/// How many events are still being handled, so shutdown can wait for the
+/// interactions it's already accepted before the process exits.
+pub struct InFlight {
+    count: AtomicUsize,
+    idle: Notify,
+}
+
+pub static IN_FLIGHT: LazyLock<InFlight> = LazyLock::new(InFlight::new);
+
+/// Held for as long as an event's being handled.
+pub struct Handling<'a>(&'a InFlight);
+
+impl Drop for Handling<'_> {
+    fn drop(&mut self) {
+        if self.0.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+impl InFlight {
+    fn new() -> Self {
+        Self {
+            count: AtomicUsize::new(0),
+            idle: Notify::new(),
+        }
+    }
+
+    pub fn enter(&self) -> Handling<'_> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        Handling(self)
+    }
+
+    /// Resolves once nothing's being handled.
+    pub async fn drained(&self) {
+        loop {
+            // Created before the check, so a drop in between still wakes it.
+            let idle = self.idle.notified();
+            if self.count.load(Ordering::SeqCst) == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_drained_waits_for_every_handler() {
+        let in_flight = InFlight::new();
+        let first = in_flight.enter();
+        let second = in_flight.enter();
+
+        drop(first);
+        let waiting = tokio::time::timeout(Duration::from_millis(10), in_flight.drained()).await;
+        assert!(waiting.is_err());
+
+        drop(second);
+        tokio::time::timeout(Duration::from_millis(10), in_flight.drained())
+            .await
+            .unwrap();
+    }
+}
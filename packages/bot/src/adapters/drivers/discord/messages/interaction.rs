@@ -1,13 +1,23 @@
+use crate::adapters::drivers::discord::components::interaction::DeleteOwner;
 use crate::adapters::drivers::discord::utils::message::{
-    build_flip_button, build_set_dropdown, build_similar_dropdown,
+    build_card_buttons, build_delete_button, build_functional_dropdown, build_grid_dropdown,
+    build_set_dropdown, build_similar_dropdown,
+};
+use crate::domain::grid::CardGrid;
+use crate::domain::i18n::Locale;
+use crate::domain::rate_limit::Requester;
+use crate::ports::drivers::client::{
+    GridInteraction, MessageInteraction, MessageInteractionError, ReplyCleanup, ReplyTracking,
 };
-use crate::ports::drivers::client::{MessageInteraction, MessageInteractionError};
 use async_trait::async_trait;
 use contracts::search_result::SearchResultDto;
 use discord_embeds::create_embed;
 use serenity::all::{
-    Context, CreateActionRow, CreateAttachment, CreateMessage, Mentionable, Message,
+    ChannelId, Context, CreateActionRow, CreateAttachment, CreateEmbed, CreateMessage,
+    EditAttachments, EditMessage, GuildId, Http, Mentionable, Message, MessageId,
 };
+use std::collections::VecDeque;
+use std::sync::Mutex;
 use tokio::time::Instant;
 
 fn bot_not_mentioned_warning(user: &impl Mentionable, bot: &impl Mentionable) -> String {
@@ -22,35 +32,136 @@ fn bot_not_mentioned_warning(user: &impl Mentionable, bot: &impl Mentionable) ->
     )
 }
 
+/// One reply, kept in parts so it can go out either as a new message or
+/// as an edit of an earlier reply.
+#[derive(Clone, Default)]
+struct Reply {
+    content: Option<String>,
+    embed: Option<CreateEmbed>,
+    file: Option<CreateAttachment>,
+    components: Vec<CreateActionRow>,
+}
+
+impl Reply {
+    fn text(content: String) -> Self {
+        Self {
+            content: Some(content),
+            ..Self::default()
+        }
+    }
+
+    fn into_create(self) -> CreateMessage {
+        let mut message = CreateMessage::new();
+        if let Some(content) = self.content {
+            message = message.content(content);
+        }
+        if let Some(embed) = self.embed {
+            message = message.add_embed(embed);
+        }
+        if let Some(file) = self.file {
+            message = message.add_file(file);
+        }
+        if !self.components.is_empty() {
+            message = message.components(self.components);
+        }
+        message
+    }
+
+    /// Replaces everything on the earlier reply, clearing whatever this one
+    /// doesn't have.
+    fn into_edit(self) -> EditMessage {
+        let mut attachments = EditAttachments::new();
+        if let Some(file) = self.file {
+            attachments = attachments.add(file);
+        }
+        EditMessage::new()
+            .content(self.content.unwrap_or_default())
+            .embeds(self.embed.into_iter().collect())
+            .attachments(attachments)
+            .components(self.components)
+    }
+}
+
+/// Replies sent to the message so far, and earlier ones still to be
+/// edited in place before any new ones go out.
+#[derive(Default)]
+struct Replies {
+    reusable: VecDeque<MessageId>,
+    sent: Vec<MessageId>,
+}
+
+async fn delete_messages(http: &Http, channel_id: ChannelId, replies: Vec<u64>) {
+    for reply in replies {
+        if let Err(why) = channel_id.delete_message(http, MessageId::new(reply)).await {
+            log::warn!("Couldn't delete inline reply {reply}: {why}");
+        }
+    }
+}
+
 pub struct DiscordMessageInteration {
     ctx: Context,
     msg: Message,
+    replies: Mutex<Replies>,
 }
 
 impl DiscordMessageInteration {
     pub fn new(ctx: Context, msg: Message) -> Self {
-        Self { ctx, msg }
+        Self {
+            ctx,
+            msg,
+            replies: Mutex::new(Replies::default()),
+        }
     }
 
     pub fn content(&self) -> &str {
         &self.msg.content
     }
 
-    async fn send_message(&self, message: CreateMessage) -> Result<(), MessageInteractionError> {
-        let start = Instant::now();
-        let message = if self.msg.mentions_me(&self.ctx.http).await.unwrap_or(false) {
-            message
+    fn delete_owner(&self) -> DeleteOwner {
+        DeleteOwner::inline(self.msg.author.id.get(), self.msg.id.get())
+    }
+
+    async fn with_mention_warning(&self, reply: Reply) -> Reply {
+        if self.msg.mentions_me(&self.ctx.http).await.unwrap_or(false) {
+            reply
         } else {
             let bot_id = self.ctx.cache.current_user().id;
-            message.content(bot_not_mentioned_warning(&self.msg.author.id, &bot_id))
+            Reply {
+                content: Some(bot_not_mentioned_warning(&self.msg.author.id, &bot_id)),
+                ..reply
+            }
+        }
+    }
+
+    /// Edits the next earlier reply if there is one, otherwise sends a new
+    /// message — as it also does if that reply has since been deleted.
+    async fn send_message(&self, reply: Reply) -> Result<(), MessageInteractionError> {
+        let start = Instant::now();
+        let channel_id = self.msg.channel_id;
+        let reusable = self
+            .replies
+            .lock()
+            .ok()
+            .and_then(|mut replies| replies.reusable.pop_front());
+
+        let edited = match reusable {
+            Some(reply_id) => channel_id
+                .edit_message(&self.ctx.http, reply_id, reply.clone().into_edit())
+                .await
+                .inspect_err(|why| log::warn!("Couldn't edit inline reply {reply_id}: {why}"))
+                .ok(),
+            None => None,
+        };
+        let sent = match edited {
+            Some(edited) => Ok(edited),
+            None => {
+                channel_id
+                    .send_message(&self.ctx.http, reply.into_create())
+                    .await
+            }
         };
 
-        match self
-            .msg
-            .channel_id
-            .send_message(&self.ctx.http, message)
-            .await
-        {
+        match sent {
             Err(why) => Err(MessageInteractionError::new(why.to_string())),
             Ok(response) => {
                 log::info!(
@@ -58,6 +169,9 @@ impl DiscordMessageInteration {
                     start.elapsed().as_millis(),
                     response.channel_id.to_string()
                 );
+                if let Ok(mut replies) = self.replies.lock() {
+                    replies.sent.push(response.id);
+                }
                 Ok(())
             }
         }
@@ -73,38 +187,39 @@ impl MessageInteraction for DiscordMessageInteration {
 
         let mut components: Vec<CreateActionRow> = Vec::with_capacity(2);
 
-        let mut message = CreateMessage::new().add_file(front_image);
-        if let Some(component) = build_set_dropdown(result.printings()).await {
+        if let Some(component) = build_set_dropdown(result.printings(), self.locale()).await {
             components.push(component);
         }
 
-        if let Some(component) = build_similar_dropdown(result.similar_cards()).await {
+        if let Some(component) = build_similar_dropdown(result.similar_cards(), self.locale()).await
+        {
             components.push(component);
         }
 
-        if let Some(component) = build_flip_button(card) {
+        if let Some(component) =
+            build_functional_dropdown(result.functionally_similar(), self.locale()).await
+        {
             components.push(component);
         }
 
-        if !components.is_empty() {
-            message = message.components(components);
+        if let Some(component) = build_card_buttons(card, Some(self.delete_owner())) {
+            components.push(component);
         }
 
-        let embed = create_embed(card).await;
-        message = message.add_embed(embed);
-        self.send_message(message).await?;
-
-        Ok(())
+        let reply = Reply {
+            content: None,
+            embed: Some(create_embed(card).await),
+            file: Some(front_image),
+            components,
+        };
+        let reply = self.with_mention_warning(reply).await;
+        self.send_message(reply).await
     }
 
     async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
-        self.msg
-            .channel_id
-            .say(&self.ctx, message)
+        self.send_message(Reply::text(message))
             .await
-            .map_err(|_| MessageInteractionError::new(String::from("Failed to send message")))?;
-
-        Ok(())
+            .map_err(|_| MessageInteractionError::new(String::from("Failed to send message")))
     }
 
     /// Plain channel messages (unlike slash command/component interaction
@@ -113,4 +228,102 @@ impl MessageInteraction for DiscordMessageInteration {
     async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
         self.reply(message).await
     }
+
+    async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.reply(message).await
+    }
+
+    /// Messages don't carry a locale, so this goes by the server's.
+    fn locale(&self) -> Locale {
+        self.msg
+            .guild_id
+            .and_then(|guild_id| {
+                self.ctx
+                    .cache
+                    .guild(guild_id)
+                    .map(|guild| Locale::from_discord(&guild.preferred_locale))
+            })
+            .unwrap_or_default()
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: self.msg.author.id.get(),
+            guild_id: self.msg.guild_id.map(GuildId::get),
+        }
+    }
+}
+
+#[async_trait]
+impl GridInteraction for DiscordMessageInteration {
+    async fn send_grid(&self, grid: CardGrid) -> Result<(), MessageInteractionError> {
+        let reply = Reply {
+            content: (grid.missing() > 0)
+                .then(|| format!("Couldn't find {} of those cards :(", grid.missing())),
+            embed: None,
+            file: Some(CreateAttachment::bytes(grid.image().bytes(), "cards.png")),
+            components: vec![
+                build_grid_dropdown(grid.cards(), self.locale()).await,
+                CreateActionRow::Buttons(vec![build_delete_button(self.delete_owner())]),
+            ],
+        };
+        let reply = self.with_mention_warning(reply).await;
+        self.send_message(reply).await
+    }
+}
+
+#[async_trait]
+impl ReplyCleanup for DiscordMessageInteration {
+    fn source(&self) -> String {
+        format!("{}:{}", self.msg.channel_id, self.msg.id)
+    }
+
+    async fn delete_replies(&self, replies: Vec<u64>) {
+        delete_messages(&self.ctx.http, self.msg.channel_id, replies).await;
+    }
+}
+
+#[async_trait]
+impl ReplyTracking for DiscordMessageInteration {
+    fn sent(&self) -> Vec<u64> {
+        self.replies
+            .lock()
+            .map(|replies| replies.sent.iter().map(|id| id.get()).collect())
+            .unwrap_or_default()
+    }
+
+    fn reuse(&self, replies: Vec<u64>) {
+        if let Ok(mut tracked) = self.replies.lock() {
+            tracked.reusable = replies.into_iter().map(MessageId::new).collect();
+        }
+    }
+}
+
+/// A message that's been deleted, or edited to have no inline queries
+/// left, so all there is to do is tidy up its replies.
+pub struct DiscordDeletedMessage {
+    ctx: Context,
+    channel_id: ChannelId,
+    message_id: MessageId,
+}
+
+impl DiscordDeletedMessage {
+    pub fn new(ctx: Context, channel_id: ChannelId, message_id: MessageId) -> Self {
+        Self {
+            ctx,
+            channel_id,
+            message_id,
+        }
+    }
+}
+
+#[async_trait]
+impl ReplyCleanup for DiscordDeletedMessage {
+    fn source(&self) -> String {
+        format!("{}:{}", self.channel_id, self.message_id)
+    }
+
+    async fn delete_replies(&self, replies: Vec<u64>) {
+        delete_messages(&self.ctx.http, self.channel_id, replies).await;
+    }
 }
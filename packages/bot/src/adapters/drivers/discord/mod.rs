@@ -1,32 +1,125 @@
 pub mod client;
 mod commands;
 mod components;
+pub mod gateway;
+mod in_flight;
 mod messages;
+mod modals;
 mod utils;
 
+use crate::adapters::drivers::discord::commands::cube::{build_import_modal, can_manage_guild};
+use crate::adapters::drivers::discord::commands::deck::{build_commander_modal, build_deck_modal};
 use crate::adapters::drivers::discord::commands::game::DiscordCommandInteraction;
 use crate::adapters::drivers::discord::commands::interaction::DiscordCommand;
+use crate::adapters::drivers::discord::commands::proxies::{build_proxies_modal, parse_proxies_id};
 use crate::adapters::drivers::discord::commands::register::{
-    give_up, guess, help, play, search, spoilers,
+    artist, cr, cube, deck, give_up, guess, help, higherlower, oracle, pack, play, proxies, sealed,
+    search, set, spoilers, trivia,
 };
 use crate::adapters::drivers::discord::components::interaction::{
-    DiscordComponentInteraction, FLIP, PICK_PRINT_ID, SIMILAR_ID,
+    DeleteOwner, DiscordComponentInteraction, ARTIST_PAGE, DELETE, FLIP, FUNCTIONAL_ID, GALLERY_ID,
+    GRID_ID, HIGHER_LOWER, ORACLE_CARD_ID, ORACLE_PAGE, PICK_PRINT_ID, SET_CARD_ID, SET_PAGE,
+    SIMILAR_ID, TRIVIA,
 };
-use crate::adapters::drivers::discord::messages::interaction::DiscordMessageInteration;
-use crate::adapters::drivers::discord::utils::help::HELP;
+use crate::adapters::drivers::discord::gateway::GATEWAY;
+use crate::adapters::drivers::discord::in_flight::IN_FLIGHT;
+use crate::adapters::drivers::discord::messages::interaction::{
+    DiscordDeletedMessage, DiscordMessageInteration,
+};
+use crate::adapters::drivers::discord::modals::interaction::{
+    DiscordModalInteraction, COMMANDER, CUBE_IMPORT, DECK, LIST_INPUT, PROXIES,
+};
+use crate::adapters::drivers::discord::utils::help::help;
+use crate::adapters::drivers::discord::utils::interaction_locale;
+use crate::domain::functions;
+use crate::domain::functions::artist::{ArtistOptions, ArtistPageOptions};
+use crate::domain::functions::cube::draft::CubePackOptions;
+use crate::domain::functions::cube::manage::{CubeAdd, CubeImport, CubeOptions};
+use crate::domain::functions::deck::analyse::DeckOptions;
 use crate::domain::functions::game::play::PlayOptions;
+use crate::domain::functions::higher_lower::answer::HigherLowerAnswer;
+use crate::domain::functions::higher_lower::play::HigherLowerOptions;
+use crate::domain::functions::higher_lower::state::Pick;
+use crate::domain::functions::oracle::{OracleOptions, OraclePageOptions};
+use crate::domain::functions::pack::open::PackOptions;
+use crate::domain::functions::proxies::pdf::Paper;
+use crate::domain::functions::proxies::print::ProxyOptions;
+use crate::domain::functions::rules::CrOptions;
+use crate::domain::functions::set::{SetOptions, SetPageOptions};
+use crate::domain::functions::trivia::answer::TriviaAnswer;
+use crate::domain::i18n;
 use crate::domain::query::QueryParams;
-use crate::domain::{card, functions};
+use crate::domain::utils::REGEX_COLLECTION;
+use crate::ports::drivers::client::MessageInteraction;
 use crate::{impl_app, impl_async_for_app};
 use cards_sdk::{ChannelId, GuildId};
+use contracts::priced_card::Currency;
 use discord_embeds::warmup_emoji;
 use serenity::all::{
-    Command, CommandInteraction, ComponentInteractionDataKind, Context, EventHandler, Interaction,
-    Message, Ready, ResolvedValue,
+    ChannelId as DiscordChannelId, Command, CommandInteraction, ComponentInteraction,
+    ComponentInteractionDataKind, ConnectionStage, Context, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateModal, EventHandler, GuildId as DiscordGuildId,
+    Interaction, Message, MessageId, MessageUpdateEvent, ModalInteraction, Ready, ResolvedOption,
+    ResolvedTarget, ResolvedValue, ShardStageUpdateEvent,
 };
 use utils::parse;
 use uuid::Uuid;
 
+async fn open_modal(ctx: &Context, command: &CommandInteraction, modal: CreateModal) {
+    let response = CreateInteractionResponse::Modal(modal);
+    if let Err(why) = command.create_response(&ctx.http, response).await {
+        log::warn!("couldn't open modal: {why}");
+    }
+}
+
+/// Acknowledges a command that may take longer than Discord's three
+/// seconds to answer, showing it as thinking until the answer is edited in.
+async fn defer(ctx: Context, command: CommandInteraction) -> Option<DiscordCommandInteraction> {
+    if let Err(why) = command.defer(&ctx.http).await {
+        log::warn!("couldn't defer command: {why}");
+        return None;
+    }
+    Some(DiscordCommandInteraction::new(ctx, command))
+}
+
+fn string_option(options: &[ResolvedOption<'_>], name: &str) -> Option<String> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::String(value) if option.name == name => Some(value.to_string()),
+        _ => None,
+    })
+}
+
+fn bool_option(options: &[ResolvedOption<'_>], name: &str) -> Option<bool> {
+    options.iter().find_map(|option| match option.value {
+        ResolvedValue::Boolean(value) if option.name == name => Some(value),
+        _ => None,
+    })
+}
+
+/// The paste box for commands that take a card list, which can't fit in
+/// a slash command option.
+fn list_modal(command: &CommandInteraction) -> Option<CreateModal> {
+    let options = command.data.options();
+    let locale = interaction_locale(command.guild_locale.as_deref(), &command.locale);
+    match command.data.name.as_str() {
+        "deck" => {
+            let currency = string_option(&options, "currency")
+                .and_then(|code| Currency::from_code(&code))
+                .unwrap_or_default();
+            Some(build_deck_modal(currency, locale))
+        }
+        "commander" => Some(build_commander_modal(locale)),
+        "proxies" => {
+            let paper = string_option(&options, "paper")
+                .and_then(|name| Paper::from_name(&name))
+                .unwrap_or_default();
+            let cut_lines = bool_option(&options, "cut_lines").unwrap_or(true);
+            Some(build_proxies_modal(paper, cut_lines, locale))
+        }
+        _ => None,
+    }
+}
+
 impl_app! {
     async fn dispatch_spoilers_command(&self, ctx: Context, command: CommandInteraction) {
         let Some(guild_id) = command.guild_id else {
@@ -83,23 +176,468 @@ impl_app! {
             _ => (),
         }
     }
+
+    async fn dispatch_cube_command(&self, ctx: Context, command: CommandInteraction) {
+        let Some(guild_id) = command.guild_id else {
+            return;
+        };
+        let guild_id = GuildId::from(guild_id.get());
+        let options = command.data.options();
+        let Some(sub) = options.first() else {
+            return;
+        };
+        let ResolvedValue::SubCommand(sub_options) = &sub.value else {
+            return;
+        };
+        let Some(name) = string_option(sub_options, "name") else {
+            return;
+        };
+
+        match sub.name {
+            "create" => {
+                let Some(interaction) = defer(ctx, command).await else {
+                    return;
+                };
+                self.cube_create(&interaction, CubeOptions::new(guild_id, name))
+                    .await;
+            }
+            "add" => {
+                let Some(card) = string_option(sub_options, "card") else {
+                    return;
+                };
+                let Some(interaction) = defer(ctx, command).await else {
+                    return;
+                };
+                self.cube_add(&interaction, CubeAdd::new(guild_id, name, card))
+                    .await;
+            }
+            "import" => {
+                // Checked again on submit; this just saves pasting a list
+                // that would be turned away.
+                let locale = interaction_locale(command.guild_locale.as_deref(), &command.locale);
+                if !can_manage_guild(command.member.as_deref()) {
+                    let response = CreateInteractionResponse::Message(
+                        CreateInteractionResponseMessage::new()
+                            .content(i18n::Message::CubeNotAllowed.text(locale))
+                            .ephemeral(true),
+                    );
+                    if let Err(why) = command.create_response(&ctx.http, response).await {
+                        log::warn!("couldn't create interaction response: {why}");
+                    }
+                    return;
+                }
+                open_modal(&ctx, &command, build_import_modal(&name, locale)).await;
+            }
+            "pack" => {
+                let packs = sub_options
+                    .iter()
+                    .find_map(|option| match option.value {
+                        ResolvedValue::Integer(packs) if option.name == "packs" => {
+                            usize::try_from(packs).ok()
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or(1);
+                let Some(interaction) = defer(ctx, command).await else {
+                    return;
+                };
+                self.cube_pack(&interaction, CubePackOptions::new(guild_id, name, packs))
+                    .await;
+            }
+            _ => (),
+        }
+    }
+
+    async fn dispatch_modal(&self, ctx: Context, modal: ModalInteraction) {
+        if let Some(name) = modal.data.custom_id.strip_prefix(CUBE_IMPORT) {
+            let Some(guild_id) = modal.guild_id else {
+                return;
+            };
+            let name = name.to_string();
+            if let Err(why) = modal.defer(&ctx.http).await {
+                log::warn!("couldn't defer modal: {why}");
+                return;
+            }
+            let interaction = DiscordModalInteraction::new(ctx, modal);
+            let list = interaction.input(LIST_INPUT).unwrap_or_default();
+            self.cube_import(
+                &interaction,
+                CubeImport::new(GuildId::from(guild_id.get()), name, list),
+            )
+            .await;
+        } else if let Some(currency) = modal.data.custom_id.strip_prefix(DECK) {
+            let currency = Currency::from_code(currency).unwrap_or_default();
+            if let Err(why) = modal.defer(&ctx.http).await {
+                log::warn!("couldn't defer modal: {why}");
+                return;
+            }
+            let interaction = DiscordModalInteraction::new(ctx, modal);
+            let list = interaction.input(LIST_INPUT).unwrap_or_default();
+            self.deck_command(&interaction, DeckOptions::new(list, currency))
+                .await;
+        } else if modal.data.custom_id == COMMANDER {
+            if let Err(why) = modal.defer(&ctx.http).await {
+                log::warn!("couldn't defer modal: {why}");
+                return;
+            }
+            let interaction = DiscordModalInteraction::new(ctx, modal);
+            let list = interaction.input(LIST_INPUT).unwrap_or_default();
+            self.commander_command(&interaction, list).await;
+        } else if let Some(options) = modal.data.custom_id.strip_prefix(PROXIES) {
+            let (paper, cut_lines) = parse_proxies_id(options);
+            if let Err(why) = modal.defer(&ctx.http).await {
+                log::warn!("couldn't defer modal: {why}");
+                return;
+            }
+            let interaction = DiscordModalInteraction::new(ctx, modal);
+            let list = interaction.input(LIST_INPUT).unwrap_or_default();
+            self.proxies_command(&interaction, ProxyOptions::new(list, paper, cut_lines))
+                .await;
+        }
+    }
+
+    /// The `Analyse deck` context-menu action reads the list from the
+    /// message it was used on.
+    async fn dispatch_analyse_deck(&self, ctx: Context, command: CommandInteraction) {
+        let Some(ResolvedTarget::Message(message)) = command.data.target() else {
+            return;
+        };
+        let list = message.content.clone();
+        if let Err(why) = command.defer(&ctx.http).await {
+            log::warn!("couldn't defer command: {why}");
+            return;
+        }
+        let interaction = DiscordCommandInteraction::new(ctx, command);
+        self.deck_command(&interaction, DeckOptions::new(list, Currency::default()))
+            .await;
+    }
+
+    async fn dispatch_browse_command(&self, ctx: Context, command: CommandInteraction) {
+        let options = command.data.options();
+        match command.data.name.as_str() {
+            "artist" => match parse::options::<ArtistOptions>(options) {
+                Ok(options) => {
+                    if let Some(interaction) = defer(ctx, command).await {
+                        self.artist_command(&interaction, options).await;
+                    }
+                }
+                Err(err) => log::warn!("{err}"),
+            },
+            "cr" => match parse::options::<CrOptions>(options) {
+                Ok(options) => {
+                    if let Some(interaction) = defer(ctx, command).await {
+                        self.cr_command(&interaction, options).await;
+                    }
+                }
+                Err(err) => log::warn!("{err}"),
+            },
+            "oracle" => match parse::options::<OracleOptions>(options) {
+                Ok(options) => {
+                    if let Some(interaction) = defer(ctx, command).await {
+                        self.oracle_command(&interaction, options).await;
+                    }
+                }
+                Err(err) => log::warn!("{err}"),
+            },
+            "set" => match parse::options::<SetOptions>(options) {
+                Ok(options) => {
+                    if let Some(interaction) = defer(ctx, command).await {
+                        self.set_command(&interaction, options).await;
+                    }
+                }
+                Err(err) => log::warn!("{err}"),
+            },
+            name => log::warn!("Unknown browse command: {name}"),
+        }
+    }
+
+    /// Turns the page of a `/set`, `/artist` or `/oracle` browse.
+    async fn dispatch_page_select(&self, ctx: Context, component: ComponentInteraction) {
+        let page = match &component.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } => {
+                values.first().and_then(|page| page.parse::<usize>().ok())
+            }
+            _ => None,
+        };
+        let Some(page) = page else {
+            log::warn!("Invalid page in {}", component.data.custom_id);
+            return;
+        };
+
+        let custom_id = component.data.custom_id.clone();
+        let interaction = DiscordComponentInteraction::new(ctx, component);
+        if let Some(abbreviation) = custom_id.strip_prefix(SET_PAGE) {
+            let options = SetPageOptions::new(abbreviation.to_string(), page);
+            self.set_page(&interaction, options).await;
+        } else if let Some(artist_name) = custom_id.strip_prefix(ARTIST_PAGE) {
+            let options = ArtistPageOptions::new(artist_name.to_string(), page);
+            self.artist_page(&interaction, options).await;
+        } else if let Some(text) = custom_id.strip_prefix(ORACLE_PAGE) {
+            let options = OraclePageOptions::new(text.to_string(), page);
+            self.oracle_page(&interaction, options).await;
+        }
+    }
+
+    async fn dispatch_command(&self, ctx: Context, command: CommandInteraction) {
+        if let Some(modal) = list_modal(&command) {
+            open_modal(&ctx, &command, modal).await;
+            return;
+        }
+
+        match command.data.name.as_str() {
+            "help" => {
+                let interaction = DiscordCommand::new(ctx, command);
+                functions::help::run(&interaction, help(interaction.locale())).await;
+            }
+            "search" => {
+                let query_params =
+                    match parse::options::<QueryParams>(command.data.options()) {
+                        Ok(params) => params,
+                        Err(err) => {
+                            log::warn!("{err}");
+                            return;
+                        }
+                    };
+                let interaction = DiscordCommand::new(ctx, command);
+                self.search(&interaction, query_params).await;
+            }
+            "play" => {
+                let options = match parse::options::<PlayOptions>(command.data.options()) {
+                    Ok(options) => options,
+                    Err(err) => {
+                        log::warn!("{err}");
+                        return;
+                    }
+                };
+                let interaction = DiscordCommandInteraction::new(ctx, command);
+                self.play_command(&interaction, options).await;
+            }
+            "guess" => {
+                let guess_options = match parse::options(command.data.options()) {
+                    Ok(value) => value,
+                    Err(err) => {
+                        log::warn!("Failed to parse guess: {err}");
+                        return;
+                    }
+                };
+                let interaction = DiscordCommandInteraction::new(ctx, command);
+                self.guess_command(&interaction, guess_options).await;
+            }
+            "give_up" => {
+                let interaction = DiscordCommandInteraction::new(ctx, command);
+                self.give_up_command(&interaction).await;
+            }
+            "spoilers" => {
+                self.dispatch_spoilers_command(ctx, command).await;
+            }
+            "trivia" => {
+                if let Some(interaction) = defer(ctx, command).await {
+                    self.trivia_command(&interaction).await;
+                }
+            }
+            "higherlower" => {
+                let options =
+                    match parse::options::<HigherLowerOptions>(command.data.options()) {
+                        Ok(options) => options,
+                        Err(err) => {
+                            log::warn!("{err}");
+                            return;
+                        }
+                    };
+                if let Some(interaction) = defer(ctx, command).await {
+                    self.higher_lower_command(&interaction, options).await;
+                }
+            }
+            "cube" => {
+                self.dispatch_cube_command(ctx, command).await;
+            }
+            deck::ANALYSE_DECK => {
+                self.dispatch_analyse_deck(ctx, command).await;
+            }
+            "pack" | "sealed" => {
+                let options = match parse::options::<PackOptions>(command.data.options()) {
+                    Ok(options) => options,
+                    Err(err) => {
+                        log::warn!("{err}");
+                        return;
+                    }
+                };
+                let sealed = command.data.name == "sealed";
+                let Some(interaction) = defer(ctx, command).await else {
+                    return;
+                };
+                if sealed {
+                    self.sealed_command(&interaction, options).await;
+                } else {
+                    self.pack_command(&interaction, options).await;
+                }
+            }
+            "artist" | "cr" | "oracle" | "set" => {
+                self.dispatch_browse_command(ctx, command).await;
+            }
+            _ => (),
+        }
+    }
+
+    async fn dispatch_component(&self, ctx: Context, component: ComponentInteraction) {
+        if component.data.custom_id == PICK_PRINT_ID
+            || component.data.custom_id == SIMILAR_ID
+            || component.data.custom_id == FUNCTIONAL_ID
+            || component.data.custom_id == GRID_ID
+            || component.data.custom_id == SET_CARD_ID
+            || component.data.custom_id == GALLERY_ID
+            || component.data.custom_id == ORACLE_CARD_ID
+        {
+            if let ComponentInteractionDataKind::StringSelect { values } =
+                &component.data.kind
+            {
+                if let Some(card_id_str) = values.first() {
+                    log::info!(
+                        "Received Pick print command for {} from {}",
+                        card_id_str,
+                        component.channel_id,
+                    );
+                    match Uuid::parse_str(card_id_str) {
+                        Ok(card_id) => {
+                            let interaction = if component.data.custom_id == GRID_ID
+                                || component.data.custom_id == SET_CARD_ID
+                                || component.data.custom_id == GALLERY_ID
+                                || component.data.custom_id == ORACLE_CARD_ID
+                            {
+                                DiscordComponentInteraction::replying(ctx, component)
+                            } else {
+                                DiscordComponentInteraction::new(ctx, component)
+                            };
+                            self.select_print(&interaction, card_id).await;
+                        }
+                        Err(why) => log::warn!("Invalid card_id in print_select: {why}"),
+                    }
+                }
+            }
+        } else if component.data.custom_id.starts_with(FLIP) {
+            let id = component.data.custom_id.strip_prefix(FLIP).unwrap();
+            match Uuid::parse_str(id) {
+                Ok(id) => {
+                    let interaction = DiscordComponentInteraction::new(ctx, component);
+                    self.select_print(&interaction, id).await;
+                }
+                Err(why) => log::warn!("Invalid id in card flip: {why}"),
+            }
+        } else if component.data.custom_id.starts_with(SET_PAGE)
+            || component.data.custom_id.starts_with(ARTIST_PAGE)
+            || component.data.custom_id.starts_with(ORACLE_PAGE)
+        {
+            self.dispatch_page_select(ctx, component).await;
+        } else if let Some(owner) = component.data.custom_id.strip_prefix(DELETE) {
+            match DeleteOwner::parse(owner) {
+                Some(owner) => {
+                    let interaction = DiscordComponentInteraction::new(ctx, component);
+                    self.delete_reply(&interaction, owner.requester()).await;
+                }
+                None => log::warn!("Invalid requester in delete: {owner}"),
+            }
+        } else if let Some(answer) = component.data.custom_id.strip_prefix(TRIVIA) {
+            let Some((round, choice)) = answer.split_once(':') else {
+                log::warn!("Invalid trivia answer: {answer}");
+                return;
+            };
+            match (Uuid::parse_str(round), choice.parse::<usize>()) {
+                (Ok(round), Ok(choice)) => {
+                    let interaction = DiscordComponentInteraction::new(ctx, component);
+                    self.trivia_answer(&interaction, TriviaAnswer::new(round, choice))
+                        .await;
+                }
+                _ => log::warn!("Invalid trivia answer: {answer}"),
+            }
+        } else if let Some(answer) = component.data.custom_id.strip_prefix(HIGHER_LOWER) {
+            let mut parts = answer.splitn(3, ':');
+            let (Some(owner), Some(round), Some(pick)) = (parts.next(), parts.next(), parts.next())
+            else {
+                log::warn!("Invalid higher lower answer: {answer}");
+                return;
+            };
+            let pick = match pick {
+                "left" => Pick::Left,
+                "right" => Pick::Right,
+                _ => {
+                    log::warn!("Invalid higher lower answer: {answer}");
+                    return;
+                }
+            };
+            match (owner.parse::<u64>(), Uuid::parse_str(round)) {
+                (Ok(owner), Ok(round)) => {
+                    let interaction = DiscordComponentInteraction::new(ctx, component);
+                    let answer = HigherLowerAnswer::new(owner, round, pick);
+                    self.higher_lower_answer(&interaction, answer).await;
+                }
+                _ => log::warn!("Invalid higher lower answer: {answer}"),
+            }
+        }
+    }
 }
 
 impl_async_for_app!( EventHandler {
      async fn message(&self, ctx: Context, msg: Message) {
+        let _handling = IN_FLIGHT.enter();
         if msg.author.id == ctx.cache.current_user().id || msg.author.bot {
             return;
         } else if msg.content == "!help" {
             let interaction = DiscordMessageInteration::new(ctx, msg);
-            functions::help::run(&interaction, &HELP).await;
+            functions::help::run(&interaction, help(interaction.locale())).await;
         } else {
             let interaction = DiscordMessageInteration::new(ctx, msg);
-            for result in self.parse_message(interaction.content()).await {
-                card::card_response(result, &interaction).await;
-            }
+            self.inline_search(&interaction, interaction.content()).await;
         }
     }
 
+    /// Follows an edit of a message with inline queries. Once there are
+    /// none left in it, its replies are just deleted.
+    async fn message_update(
+        &self,
+        ctx: Context,
+        _: Option<Message>,
+        new: Option<Message>,
+        event: MessageUpdateEvent,
+    ) {
+        let _handling = IN_FLIGHT.enter();
+        if event.author.as_ref().is_some_and(|author| author.bot) {
+            return;
+        }
+        let Some(content) = event.content else {
+            return;
+        };
+        if !REGEX_COLLECTION.cards.is_match(&content) {
+            let message = DiscordDeletedMessage::new(ctx, event.channel_id, event.id);
+            self.inline_delete(&message).await;
+            return;
+        }
+
+        let msg = match new {
+            Some(msg) => msg,
+            None => match event.channel_id.message(&ctx.http, event.id).await {
+                Ok(msg) => msg,
+                Err(why) => {
+                    log::warn!("Couldn't fetch edited message: {why}");
+                    return;
+                }
+            },
+        };
+        let interaction = DiscordMessageInteration::new(ctx, msg);
+        self.inline_edit(&interaction, &content).await;
+    }
+
+    async fn message_delete(
+        &self,
+        ctx: Context,
+        channel_id: DiscordChannelId,
+        deleted_message_id: MessageId,
+        _: Option<DiscordGuildId>,
+    ) {
+        let _handling = IN_FLIGHT.enter();
+        let message = DiscordDeletedMessage::new(ctx, channel_id, deleted_message_id);
+        self.inline_delete(&message).await;
+    }
+
     async fn ready(&self, ctx: Context, _: Ready) {
         warmup_emoji().await;
         log::info!("Emojis toasty warm");
@@ -140,10 +678,96 @@ impl_async_for_app!( EventHandler {
             log::info!("Created spoilers command");
         }
 
+        if let Err(err) = Command::create_global_command(&ctx, trivia::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created trivia command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, higherlower::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created higherlower command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, pack::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created pack command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, sealed::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created sealed command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, artist::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created artist command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, cr::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created cr command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, oracle::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created oracle command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, set::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created set command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, cube::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created cube command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, deck::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created deck command");
+        }
+
+        if let Err(err) =
+            Command::create_global_command(&ctx, deck::register_message_command()).await
+        {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created analyse deck command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, deck::register_commander()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created commander command");
+        }
+
+        if let Err(err) = Command::create_global_command(&ctx, proxies::register()).await {
+            log::warn!("Could not create command {err:?}");
+        } else {
+            log::info!("Created proxies command");
+        }
+
         log::info!("Bot ready!");
     }
 
+    /// Sent on every change, including connecting after `ready` or a resume.
+    async fn shard_stage_update(&self, _: Context, event: ShardStageUpdateEvent) {
+        GATEWAY.set_connected(event.new == ConnectionStage::Connected);
+    }
+
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let _handling = IN_FLIGHT.enter();
         match interaction {
             Interaction::Command(command) => {
                 if command.user.bot {
@@ -156,88 +780,13 @@ impl_async_for_app!( EventHandler {
                     command.channel_id,
                 );
 
-                match command.data.name.as_str() {
-                    "help" => {
-                        let interaction = DiscordCommand::new(ctx, command);
-                        functions::help::run(&interaction, &HELP).await;
-                    }
-                    "search" => {
-                        let query_params =
-                            match parse::options::<QueryParams>(command.data.options()) {
-                                Ok(params) => params,
-                                Err(err) => {
-                                    log::warn!("{err}");
-                                    return;
-                                }
-                            };
-                        let interaction = DiscordCommand::new(ctx, command);
-                        self.search(&interaction, query_params).await;
-                    }
-                    "play" => {
-                        let options = match parse::options::<PlayOptions>(command.data.options()) {
-                            Ok(options) => options,
-                            Err(err) => {
-                                log::warn!("{err}");
-                                return;
-                            }
-                        };
-                        let interaction = DiscordCommandInteraction::new(ctx, command);
-                        self.play_command(&interaction, options).await;
-                    }
-                    "guess" => {
-                        let guess_options = match parse::options(command.data.options()) {
-                            Ok(value) => value,
-                            Err(err) => {
-                                log::warn!("Failed to parse guess: {err}");
-                                return;
-                            }
-                        };
-                        let interaction = DiscordCommandInteraction::new(ctx, command);
-                        self.guess_command(&interaction, guess_options).await;
-                    }
-                    "give_up" => {
-                        let interaction = DiscordCommandInteraction::new(ctx, command);
-                        self.give_up_command(&interaction).await;
-                    }
-                    "spoilers" => {
-                        self.dispatch_spoilers_command(ctx, command).await;
-                    }
-                    _ => (),
-                }
+                self.dispatch_command(ctx, command).await;
             }
             Interaction::Component(component) => {
-                if component.data.custom_id == PICK_PRINT_ID
-                    || component.data.custom_id == SIMILAR_ID
-                {
-                    if let ComponentInteractionDataKind::StringSelect { values } =
-                        &component.data.kind
-                    {
-                        if let Some(card_id_str) = values.first() {
-                            log::info!(
-                                "Received Pick print command for {} from {}",
-                                card_id_str,
-                                component.channel_id,
-                            );
-                            match Uuid::parse_str(card_id_str) {
-                                Ok(card_id) => {
-                                    let interaction =
-                                        DiscordComponentInteraction::new(ctx, component);
-                                    self.select_print(&interaction, card_id).await;
-                                }
-                                Err(why) => log::warn!("Invalid card_id in print_select: {why}"),
-                            }
-                        }
-                    }
-                } else if component.data.custom_id.starts_with(FLIP) {
-                    let id = component.data.custom_id.strip_prefix(FLIP).unwrap();
-                    match Uuid::parse_str(id) {
-                        Ok(id) => {
-                            let interaction = DiscordComponentInteraction::new(ctx, component);
-                            self.select_print(&interaction, id).await;
-                        }
-                        Err(why) => log::warn!("Invalid id in card flip: {why}"),
-                    }
-                }
+                self.dispatch_component(ctx, component).await;
+            }
+            Interaction::Modal(modal) => {
+                self.dispatch_modal(ctx, modal).await;
             }
             _ => {}
         }
@@ -0,0 +1,49 @@
+use serenity::all::{ActionRowComponent, Context, ModalInteraction};
+
+/// Prefix for the cube import modal: `cube-import:{name}`.
+pub const CUBE_IMPORT: &str = "cube-import:";
+/// Prefix for the deck analysis modal: `deck:{currency}`.
+pub const DECK: &str = "deck:";
+/// The Commander check modal, which carries nothing else.
+pub const COMMANDER: &str = "commander";
+/// Prefix for the proxies modal: `proxies:{paper}:{cut lines}`.
+pub const PROXIES: &str = "proxies:";
+/// The modal's paragraph input holding the pasted list.
+pub const LIST_INPUT: &str = "list";
+
+/// A submitted modal. Modals are for pasting long text that then takes a
+/// while to process, so the dispatcher defers them up front and every
+/// reply edits that deferred response.
+pub struct DiscordModalInteraction {
+    ctx: Context,
+    modal: ModalInteraction,
+}
+
+impl DiscordModalInteraction {
+    pub fn new(ctx: Context, modal: ModalInteraction) -> Self {
+        Self { ctx, modal }
+    }
+
+    pub(crate) fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    pub(crate) fn modal(&self) -> &ModalInteraction {
+        &self.modal
+    }
+
+    /// The value typed into the input with `custom_id`, if there is one.
+    pub fn input(&self, custom_id: &str) -> Option<String> {
+        self.modal
+            .data
+            .components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                ActionRowComponent::InputText(input) if input.custom_id == custom_id => {
+                    input.value.clone()
+                }
+                _ => None,
+            })
+    }
+}
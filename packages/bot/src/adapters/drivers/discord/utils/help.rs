@@ -1,19 +1,38 @@
+use crate::domain::i18n::Locale;
 use std::sync::LazyLock;
 
-pub static HELP: LazyLock<String> = LazyLock::new(|| {
-    let server_url =
-        std::env::var("DISCORD_SERVER_URL").unwrap_or_else(|_| String::from("Not available"));
+/// Discord rejects messages longer than this.
+const MESSAGE_LIMIT: usize = 2000;
+const PAGE_START: &str = "```ansi\n";
+const PAGE_END: &str = "```";
 
-    format!(
-        "
- ```ansi
-\x1b[1;10;4;31mThe Guessing Game:\x1b[0m
+static ENGLISH: LazyLock<Vec<String>> = LazyLock::new(|| {
+    paginate(&format!(
+        "\x1b[1;10;4;31mThe Guessing Game:\x1b[0m
 Use \x1b[1;34m/play\x1b[0m to start. Options: set (pull the card from a specific set) and difficulty (Easy, Medium, or Hard — defaults to Medium).
 
 Use \x1b[1;34m/guess\x1b[0m to guess — slight typos are forgiven, no need to be super accurate with spelling.
 
 The more you get the card wrong the more of the card is revealed.
 
+Prefer multiple choice? Use \x1b[1;34m/trivia\x1b[0m — name the card from its art or rules text, first answer wins.
+
+Or test your price knowledge with \x1b[1;34m/higherlower\x1b[0m — pick which of two cards costs more and keep your streak alive.
+
+
+\x1b[1;10;4;31mPacks and sealed:\x1b[0m
+Use \x1b[1;34m/pack\x1b[0m with a set name or code to crack a booster, or \x1b[1;34m/sealed\x1b[0m to open six at once as a sealed pool — the full pool comes as a text file you can import into a deck builder.
+
+Build a cube for your server with \x1b[1;34m/cube create\x1b[0m, then fill it with \x1b[1;34m/cube add\x1b[0m or paste a whole list with \x1b[1;34m/cube import\x1b[0m. \x1b[1;34m/cube pack\x1b[0m deals draft packs from it with no repeats.
+
+
+\x1b[1;10;4;31mDeck lists:\x1b[0m
+Use \x1b[1;34m/deck\x1b[0m and paste a list (MTGO, Arena or plain 4 Lightning Bolt) for its curve, colours, types, price and legality. Already posted a list? Right-click the message and pick Apps → Analyse deck.
+
+Playing Commander? \x1b[1;34m/commander\x1b[0m checks deck size, colour identity, singleton and the banned list, and estimates a bracket from your game changers.
+
+Printing proxies? \x1b[1;34m/proxies\x1b[0m turns a pasted list into a PDF of true-size cards, nine to a page.
+
 
 \x1b[1;10;4;31mSearching for cards:\x1b[0m
 You can use the \x1b[1;34m/search\x1b[0m command to search a card or do the following.
@@ -32,6 +51,18 @@ You can also use these mid-sentence, and stack several in one message:
 \x1b[1;34m/play\x1b[0m - Options: (set, difficulty) - Start a game of guess the Magic the Gathering card.
 \x1b[1;34m/guess\x1b[0m - Options: () - Make a guess for an active guess the card game.
 \x1b[1;34m/give_up\x1b[0m - Options: () - Give up on the current game and return the answer.
+\x1b[1;34m/trivia\x1b[0m - Options: () - Name the card from four choices.
+\x1b[1;34m/higherlower\x1b[0m - Options: (currency) - Guess which of two cards costs more.
+\x1b[1;34m/pack\x1b[0m - Options: (set) - Open a booster pack from a set.
+\x1b[1;34m/sealed\x1b[0m - Options: (set) - Open six booster packs as a sealed pool.
+\x1b[1;34m/cr\x1b[0m - Options: (query) - Look up a rule by number or keyword.
+\x1b[1;34m/oracle\x1b[0m - Options: (text) - Find cards by their rules text.
+\x1b[1;34m/set\x1b[0m - Options: (set) - Look up a set and browse its cards.
+\x1b[1;34m/artist\x1b[0m - Options: (name) - Look up an artist and browse their illustrations.
+\x1b[1;34m/cube\x1b[0m - Options: (create/add/import/pack, name) - Build this server's cubes and deal draft packs from them.
+\x1b[1;34m/deck\x1b[0m - Options: (currency) - Analyse a pasted deck list.
+\x1b[1;34m/commander\x1b[0m - Options: () - Check a pasted Commander deck.
+\x1b[1;34m/proxies\x1b[0m - Options: (paper, cut_lines) - Make a printable PDF of proxies.
 \x1b[1;34m/spoilers\x1b[0m - Options: (subscribe/unsubscribe, channel) - [Beta] Auto-post new cards to a channel.
 
 \x1b[1;10;4;31mHaving issues or have suggestions?\x1b[0m
@@ -39,7 +70,202 @@ Please raise a ticket here https://github.com/i-h-bar/rustcord/issues
 
 or if you don't want to use github please raise a ticket in this server
 {server_url}
-```
-    "
-    )
+",
+        server_url = server_url()
+    ))
+});
+
+static FRENCH: LazyLock<Vec<String>> = LazyLock::new(|| {
+    paginate(&format!(
+        "\x1b[1;10;4;31mLe jeu de devinettes :\x1b[0m
+Lancez une partie avec \x1b[1;34m/jouer\x1b[0m. Options : extension (tirer la carte d'une extension précise) et difficulté (Facile, Moyen ou Difficile — Moyen par défaut).
+
+Proposez une carte avec \x1b[1;34m/deviner\x1b[0m — les petites fautes de frappe sont pardonnées, inutile d'être parfait sur l'orthographe.
+
+Plus vous vous trompez, plus la carte se dévoile.
+
+Vous préférez les QCM ? Essayez \x1b[1;34m/quiz\x1b[0m — nommez la carte d'après son illustration ou son texte, la première bonne réponse gagne.
+
+Ou testez vos connaissances des prix avec \x1b[1;34m/plusoumoins\x1b[0m — choisissez laquelle de deux cartes coûte le plus cher et prolongez votre série.
+
+
+\x1b[1;10;4;31mBoosters et scellé :\x1b[0m
+Utilisez \x1b[1;34m/booster\x1b[0m avec le nom ou le code d'une extension pour ouvrir un booster, ou \x1b[1;34m/scellé\x1b[0m pour en ouvrir six d'un coup en pool scellé — le pool complet arrive en fichier texte à importer dans un éditeur de deck.
+
+Construisez un cube pour votre serveur avec \x1b[1;34m/cube create\x1b[0m, remplissez-le avec \x1b[1;34m/cube add\x1b[0m ou collez toute une liste avec \x1b[1;34m/cube import\x1b[0m. \x1b[1;34m/cube pack\x1b[0m en distribue des boosters de draft sans doublons.
+
+
+\x1b[1;10;4;31mListes de deck :\x1b[0m
+Utilisez \x1b[1;34m/deck\x1b[0m et collez une liste (MTGO, Arena ou simplement 4 Lightning Bolt) pour sa courbe, ses couleurs, ses types, son prix et sa légalité. Liste déjà postée ? Clic droit sur le message puis Applications → Analyse deck.
+
+Vous jouez en Commander ? \x1b[1;34m/commander\x1b[0m vérifie la taille du deck, l'identité couleur, le singleton et la liste des cartes bannies, et estime un bracket d'après vos game changers.
+
+Besoin de proxies ? \x1b[1;34m/proxies\x1b[0m transforme une liste collée en PDF de cartes à taille réelle, neuf par page.
+
+
+\x1b[1;10;4;31mChercher des cartes :\x1b[0m
+Utilisez la commande \x1b[1;34m/chercher\x1b[0m pour chercher une carte, ou bien procédez comme suit.
+
+Pour chercher une carte, mettez-la entre doubles crochets et mentionnez le bot : \x1b[1;34m[[lightning bolt]]\x1b[0m — les petites fautes sont pardonnées, et les noms français aussi : \x1b[1;34m[[foudre]]\x1b[0m
+
+Pour affiner la recherche, précisez l'artiste ou l'extension (abréviation ou nom complet) : \x1b[1;34m[[lightning bolt | set=m11]]\x1b[0m ou \x1b[1;34m[[relentless rats | artist = thomas m baxa]]\x1b[0m
+
+Vous pouvez aussi les glisser en pleine phrase, et en mettre plusieurs par message :
+\x1b[1;34mJ'adore [[the gitrog monster | set=bloomburrow commander]], le classique [[gitrog monster | set=soi]] reste sympa aussi.\x1b[0m
+
+
+\x1b[1;10;4;31mToutes les commandes :\x1b[0m
+\x1b[1;34m/chercher\x1b[0m - Options : (set, artist) - Recherche approximative d'une carte Magic the Gathering.
+\x1b[1;34m/aide\x1b[0m - Options : () - Afficher ce message.
+\x1b[1;34m/jouer\x1b[0m - Options : (set, difficulty) - Lancer une partie de devine la carte Magic the Gathering.
+\x1b[1;34m/deviner\x1b[0m - Options : () - Proposer une carte dans la partie en cours.
+\x1b[1;34m/abandonner\x1b[0m - Options : () - Abandonner la partie en cours et révéler la réponse.
+\x1b[1;34m/quiz\x1b[0m - Options : () - Nommer la carte parmi quatre choix.
+\x1b[1;34m/plusoumoins\x1b[0m - Options : (currency) - Deviner laquelle de deux cartes coûte le plus cher.
+\x1b[1;34m/booster\x1b[0m - Options : (set) - Ouvrir un booster d'une extension.
+\x1b[1;34m/scellé\x1b[0m - Options : (set) - Ouvrir six boosters en pool scellé.
+\x1b[1;34m/regles\x1b[0m - Options : (query) - Chercher une règle par numéro ou mot-clé.
+\x1b[1;34m/oracle\x1b[0m - Options : (text) - Trouver des cartes d'après leur texte de règles.
+\x1b[1;34m/extension\x1b[0m - Options : (set) - Chercher une extension et parcourir ses cartes.
+\x1b[1;34m/artiste\x1b[0m - Options : (name) - Chercher un artiste et parcourir ses illustrations.
+\x1b[1;34m/cube\x1b[0m - Options : (create/add/import/pack, name) - Construire les cubes du serveur et en distribuer des boosters de draft.
+\x1b[1;34m/deck\x1b[0m - Options : (currency) - Analyser une liste de deck collée.
+\x1b[1;34m/commander\x1b[0m - Options : () - Vérifier un deck Commander collé.
+\x1b[1;34m/proxies\x1b[0m - Options : (paper, cut_lines) - Créer un PDF de proxies à imprimer.
+\x1b[1;34m/spoilers\x1b[0m - Options : (subscribe/unsubscribe, channel) - [Bêta] Publier automatiquement les nouvelles cartes dans un salon.
+
+\x1b[1;10;4;31mUn problème ou une suggestion ?\x1b[0m
+Ouvrez un ticket ici https://github.com/i-h-bar/rustcord/issues
+
+ou si vous préférez éviter GitHub, ouvrez un ticket sur ce serveur
+{server_url}
+",
+        server_url = server_url()
+    ))
 });
+
+fn server_url() -> String {
+    std::env::var("DISCORD_SERVER_URL").unwrap_or_else(|_| String::from("Not available"))
+}
+
+/// The help text in `locale`, split between sections into as few messages
+/// as fit under Discord's length limit, each its own ANSI code block.
+pub fn help(locale: Locale) -> &'static [String] {
+    match locale {
+        Locale::English => &ENGLISH,
+        Locale::French => &FRENCH,
+    }
+}
+
+fn paginate(text: &str) -> Vec<String> {
+    // Each page also gets a newline before its closing fence.
+    let room = MESSAGE_LIMIT - PAGE_START.len() - PAGE_END.len() - 1;
+    let mut pages: Vec<String> = Vec::new();
+    let mut page = String::new();
+    for section in text
+        .split("\n\n\n")
+        .flat_map(|section| split(section, room))
+    {
+        if !page.is_empty() && page.chars().count() + section.chars().count() + 3 > room {
+            pages.push(page);
+            page = String::new();
+        }
+        if !page.is_empty() {
+            page.push_str("\n\n\n");
+        }
+        page.push_str(&section);
+    }
+    pages.push(page);
+
+    pages
+        .into_iter()
+        .map(|page| [PAGE_START, page.trim_end(), "\n", PAGE_END].concat())
+        .collect()
+}
+
+/// Cuts a section too long for a page between lines, and a line too long
+/// for a page wherever it has to.
+fn split(section: &str, room: usize) -> Vec<String> {
+    if section.chars().count() <= room {
+        return vec![section.to_string()];
+    }
+
+    let mut pieces = Vec::new();
+    let mut piece: Vec<String> = Vec::new();
+    let mut length = 0;
+    for line in section.lines().flat_map(|line| chunks(line, room)) {
+        let line_length = line.chars().count();
+        if !piece.is_empty() && length + 1 + line_length > room {
+            pieces.push(piece.join("\n"));
+            piece.clear();
+            length = 0;
+        }
+        length += line_length + usize::from(!piece.is_empty());
+        piece.push(line);
+    }
+    pieces.push(piece.join("\n"));
+    pieces
+}
+
+fn chunks(line: &str, room: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        return vec![String::new()];
+    }
+    chars
+        .chunks(room)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pages_fit_in_a_message() {
+        for locale in Locale::ALL {
+            let pages = help(locale);
+            assert!(pages.len() > 1);
+            for page in pages {
+                assert!(page.chars().count() <= MESSAGE_LIMIT);
+                assert!(page.starts_with(PAGE_START) && page.ends_with(PAGE_END));
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_section_is_split_between_lines() {
+        let section = vec!["a line of help text"; 300].join("\n");
+        let pages = paginate(&format!("intro\n\n\n{section}"));
+
+        assert!(pages.len() > 2);
+        for page in &pages {
+            assert!(page.chars().count() <= MESSAGE_LIMIT);
+            assert!(page.starts_with(PAGE_START) && page.ends_with(PAGE_END));
+        }
+        let lines: usize = pages
+            .iter()
+            .map(|page| page.matches("a line of help text").count())
+            .sum();
+        assert_eq!(lines, 300);
+    }
+
+    #[test]
+    fn test_long_line_is_cut() {
+        let pages = paginate(&"x".repeat(MESSAGE_LIMIT * 2));
+
+        assert_eq!(pages.len(), 3);
+        assert!(pages
+            .iter()
+            .all(|page| page.chars().count() <= MESSAGE_LIMIT));
+    }
+
+    #[test]
+    fn test_short_text_is_one_page() {
+        assert_eq!(
+            paginate("one\n\n\ntwo\n"),
+            vec![String::from("```ansi\none\n\n\ntwo\n```")]
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use crate::adapters::drivers::discord::components::interaction::{FLIP, PICK_PRINT_ID, SIMILAR_ID};
+use crate::adapters::drivers::discord::components::interaction::{
+    DeleteOwner, FLIP, FUNCTIONAL_ID, GRID_ID, PICK_PRINT_ID, SIMILAR_ID,
+};
 use crate::adapters::drivers::discord::utils::description::{
     create_card_description, create_set_description,
 };
+use crate::domain::i18n::{Locale, Message};
 use contracts::card::Card;
 use contracts::card_set::CardSet;
 use discord_embeds::{colour_id_emoji, get_emoji};
@@ -10,8 +13,24 @@ use serenity::all::{
     CreateSelectMenuOption,
 };
 use std::cmp::max;
+use std::ops::Range;
+
+/// Discord's limit on the options in a select menu.
+const MAX_OPTIONS: usize = 25;
+
+/// The pages to offer in a page select menu: all of them if they fit,
+/// otherwise as many as fit either side of the current one.
+pub fn page_window(page: usize, pages: usize) -> Range<usize> {
+    let first = page
+        .saturating_sub(MAX_OPTIONS / 2)
+        .min(pages.saturating_sub(MAX_OPTIONS));
+    first..pages.min(first + MAX_OPTIONS)
+}
 
-pub async fn build_set_dropdown(sets: Option<&Vec<CardSet>>) -> Option<CreateActionRow> {
+pub async fn build_set_dropdown(
+    sets: Option<&Vec<CardSet>>,
+    locale: Locale,
+) -> Option<CreateActionRow> {
     if let Some(sets) = sets {
         if sets.is_empty() {
             return None;
@@ -29,7 +48,7 @@ pub async fn build_set_dropdown(sets: Option<&Vec<CardSet>>) -> Option<CreateAct
             }
             let menu =
                 CreateSelectMenu::new(PICK_PRINT_ID, CreateSelectMenuKind::String { options })
-                    .placeholder("Select a print...");
+                    .placeholder(Message::SelectPrint.text(locale));
             return Some(CreateActionRow::SelectMenu(menu));
         }
     }
@@ -37,33 +56,100 @@ pub async fn build_set_dropdown(sets: Option<&Vec<CardSet>>) -> Option<CreateAct
     None
 }
 
-pub async fn build_similar_dropdown(similar: Option<&Vec<Card>>) -> Option<CreateActionRow> {
-    if let Some(cards) = similar {
-        if cards.is_empty() {
-            return None;
+pub async fn build_similar_dropdown(
+    similar: Option<&Vec<Card>>,
+    locale: Locale,
+) -> Option<CreateActionRow> {
+    build_card_dropdown(SIMILAR_ID, Message::SimilarCards.text(locale), similar?).await
+}
+
+/// Cards that play like the one shown, rather than ones named like it.
+pub async fn build_functional_dropdown(
+    similar: Option<&Vec<Card>>,
+    locale: Locale,
+) -> Option<CreateActionRow> {
+    build_card_dropdown(
+        FUNCTIONAL_ID,
+        Message::FunctionallySimilar.text(locale),
+        similar?,
+    )
+    .await
+}
+
+async fn build_card_dropdown(
+    custom_id: &str,
+    placeholder: String,
+    cards: &[Card],
+) -> Option<CreateActionRow> {
+    if cards.is_empty() {
+        return None;
+    }
+    let mut options = Vec::with_capacity(cards.len().min(25));
+    for c in cards.iter().take(25) {
+        // Discord's hard limit
+        let option = CreateSelectMenuOption::new(c.name(), c.id().to_string())
+            .emoji(colour_id_emoji(c).await)
+            .description(create_card_description(c));
+        options.push(option);
+    }
+    let menu = CreateSelectMenu::new(custom_id, CreateSelectMenuKind::String { options })
+        .placeholder(placeholder);
+    Some(CreateActionRow::SelectMenu(menu))
+}
+
+/// The flip button for double-faced cards, and the delete button for
+/// whoever asked for the card, if it's known.
+pub fn build_card_buttons(card: &Card, owner: Option<DeleteOwner>) -> Option<CreateActionRow> {
+    let mut buttons = Vec::with_capacity(2);
+    if let Some(back_id) = card.back_id() {
+        buttons.push(
+            CreateButton::new(format!("{FLIP}{back_id}"))
+                .label("🔁")
+                .style(ButtonStyle::Secondary),
+        );
+    }
+    if let Some(owner) = owner {
+        buttons.push(build_delete_button(owner));
+    }
+    (!buttons.is_empty()).then_some(CreateActionRow::Buttons(buttons))
+}
+
+pub fn build_delete_button(owner: DeleteOwner) -> CreateButton {
+    CreateButton::new(owner.custom_id())
+        .label("🗑️")
+        .style(ButtonStyle::Secondary)
+}
+
+/// One option per card in a grid, in grid order. Discord rejects repeated
+/// option values, so a card queried twice is listed once.
+pub async fn build_grid_dropdown(cards: &[Card], locale: Locale) -> CreateActionRow {
+    let mut options: Vec<CreateSelectMenuOption> = Vec::with_capacity(cards.len());
+    let mut listed: Vec<&Card> = Vec::with_capacity(cards.len());
+    for c in cards {
+        if listed.iter().any(|seen| seen.id() == c.id()) {
+            continue;
         }
-        let mut options = Vec::with_capacity(cards.len().min(25));
-        for c in cards.iter().take(25) {
-            // Discord's hard limit
-            let option = CreateSelectMenuOption::new(c.name(), c.id().to_string())
+        listed.push(c);
+        options.push(
+            CreateSelectMenuOption::new(c.name(), c.id().to_string())
                 .emoji(colour_id_emoji(c).await)
-                .description(create_card_description(c));
-            options.push(option);
-        }
-        let menu = CreateSelectMenu::new(SIMILAR_ID, CreateSelectMenuKind::String { options })
-            .placeholder("Similar cards...");
-        return Some(CreateActionRow::SelectMenu(menu));
+                .description(create_card_description(c)),
+        );
     }
-    None
+    let menu = CreateSelectMenu::new(GRID_ID, CreateSelectMenuKind::String { options })
+        .placeholder(Message::OpenCard.text(locale));
+    CreateActionRow::SelectMenu(menu)
 }
 
-pub fn build_flip_button(card: &Card) -> Option<CreateActionRow> {
-    if let Some(back_id) = card.back_id() {
-        let button = CreateButton::new(format!("{FLIP}{back_id}"))
-            .label("🔁")
-            .style(ButtonStyle::Secondary);
-        Some(CreateActionRow::Buttons(vec![button]))
-    } else {
-        None
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_window() {
+        assert_eq!(page_window(0, 3), 0..3);
+        assert_eq!(page_window(0, 40), 0..25);
+        assert_eq!(page_window(20, 40), 8..33);
+        assert_eq!(page_window(39, 40), 15..40);
     }
 }
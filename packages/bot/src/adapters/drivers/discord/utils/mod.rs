@@ -2,3 +2,11 @@ pub mod description;
 pub mod help;
 pub mod message;
 pub mod parse;
+pub mod reply;
+
+use crate::domain::i18n::Locale;
+
+/// The server's language, for an interaction in one, or else the user's.
+pub fn interaction_locale(guild_locale: Option<&str>, locale: &str) -> Locale {
+    Locale::from_discord(guild_locale.unwrap_or(locale))
+}
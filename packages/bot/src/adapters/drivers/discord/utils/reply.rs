@@ -0,0 +1,63 @@
+use serenity::all::{
+    CreateActionRow, CreateAttachment, CreateEmbed, CreateInteractionResponseMessage,
+    EditInteractionResponse,
+};
+
+/// What the reply builders fill in, so one builder serves both a fresh
+/// response and the edit that answers a deferred command.
+pub trait Reply: Default {
+    #[must_use]
+    fn with_content(self, content: String) -> Self;
+    #[must_use]
+    fn with_embed(self, embed: CreateEmbed) -> Self;
+    #[must_use]
+    fn with_embeds(self, embeds: Vec<CreateEmbed>) -> Self;
+    #[must_use]
+    fn with_components(self, components: Vec<CreateActionRow>) -> Self;
+    #[must_use]
+    fn with_file(self, file: CreateAttachment) -> Self;
+}
+
+impl Reply for CreateInteractionResponseMessage {
+    fn with_content(self, content: String) -> Self {
+        self.content(content)
+    }
+
+    fn with_embed(self, embed: CreateEmbed) -> Self {
+        self.add_embed(embed)
+    }
+
+    fn with_embeds(self, embeds: Vec<CreateEmbed>) -> Self {
+        self.add_embeds(embeds)
+    }
+
+    fn with_components(self, components: Vec<CreateActionRow>) -> Self {
+        self.components(components)
+    }
+
+    fn with_file(self, file: CreateAttachment) -> Self {
+        self.add_file(file)
+    }
+}
+
+impl Reply for EditInteractionResponse {
+    fn with_content(self, content: String) -> Self {
+        self.content(content)
+    }
+
+    fn with_embed(self, embed: CreateEmbed) -> Self {
+        self.add_embed(embed)
+    }
+
+    fn with_embeds(self, embeds: Vec<CreateEmbed>) -> Self {
+        self.add_embeds(embeds)
+    }
+
+    fn with_components(self, components: Vec<CreateActionRow>) -> Self {
+        self.components(components)
+    }
+
+    fn with_file(self, file: CreateAttachment) -> Self {
+        self.new_attachment(file)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::adapters::drivers::discord::gateway::GATEWAY;
+use crate::ports::services::cache::Cache;
+use crate::ports::services::card_store::CardStore;
+use async_trait::async_trait;
+use telemetry::{Check, Health};
+
+/// Live while the Discord gateway is up or still reconnecting, and ready
+/// while it's connected and Postgres and the cache both answer. Serving the
+/// API instead, there's no gateway to check.
+pub struct BotHealth<CS, C> {
+    card_store: CS,
+    cache: C,
+    gateway: bool,
+}
+
+impl<CS, C> BotHealth<CS, C> {
+    pub fn new(card_store: CS, cache: C, gateway: bool) -> Self {
+        Self {
+            card_store,
+            cache,
+            gateway,
+        }
+    }
+}
+
+#[async_trait]
+impl<CS, C> Health for BotHealth<CS, C>
+where
+    CS: CardStore + Send + Sync,
+    C: Cache + Send + Sync,
+{
+    async fn liveness(&self) -> Vec<Check> {
+        if self.gateway {
+            vec![Check::new("discord gateway", GATEWAY.alive())]
+        } else {
+            Vec::new()
+        }
+    }
+
+    async fn readiness(&self) -> Vec<Check> {
+        let (postgres, cache) = tokio::join!(self.card_store.ping(), self.cache.ping());
+        let mut checks = vec![Check::new("postgres", postgres), Check::new("cache", cache)];
+        if self.gateway {
+            checks.push(Check::new("discord gateway", GATEWAY.connected()));
+        }
+        checks
+    }
+}
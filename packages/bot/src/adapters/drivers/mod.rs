@@ -1,20 +1,45 @@
+pub mod api;
 pub mod discord;
+pub mod health;
+pub mod terminal;
 
+use crate::adapters::drivers::api::Api;
 use crate::adapters::drivers::discord::client::Discord;
+use crate::adapters::drivers::terminal::Terminal;
 use crate::domain::app::App;
 use crate::ports::drivers::client::Client;
 use crate::ports::services::cache::Cache;
 use crate::ports::services::card_store::CardStore;
 use crate::ports::services::image_store::ImageStore;
 use crate::ports::services::spoiler_subscription::SpoilerSubscription;
-use cards_sdk::SpoilerQueue;
+use cards_sdk::{CubeRepository, SpoilerQueue, StatsRepository};
 
 pub async fn create_client<IS, CS, C, Sub>(app: App<IS, CS, C, Sub>) -> impl Client
 where
     IS: ImageStore + Send + Sync + 'static,
-    CS: CardStore + SpoilerQueue + Send + Sync + 'static,
+    CS: CardStore + SpoilerQueue + StatsRepository + CubeRepository + Send + Sync + 'static,
     C: Cache + Send + Sync + 'static,
     Sub: SpoilerSubscription + Send + Sync + 'static,
 {
     Discord::new(app).await
 }
+
+pub fn create_api<IS, CS, C, Sub>(app: App<IS, CS, C, Sub>) -> impl Client
+where
+    IS: ImageStore + Send + Sync + 'static,
+    CS: CardStore + SpoilerQueue + StatsRepository + CubeRepository + Send + Sync + 'static,
+    C: Cache + Send + Sync + 'static,
+    Sub: SpoilerSubscription + Send + Sync + 'static,
+{
+    Api::new(app)
+}
+
+pub fn create_terminal<IS, CS, C, Sub>(app: App<IS, CS, C, Sub>) -> impl Client
+where
+    IS: ImageStore + Send + Sync + 'static,
+    CS: CardStore + SpoilerQueue + StatsRepository + CubeRepository + Send + Sync + 'static,
+    C: Cache + Send + Sync + 'static,
+    Sub: SpoilerSubscription + Send + Sync + 'static,
+{
+    Terminal::new(app)
+}
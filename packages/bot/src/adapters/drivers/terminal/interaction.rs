@@ -0,0 +1,287 @@
+use crate::domain::functions::game::state::{Difficulty, GameState};
+use crate::domain::grid::CardGrid;
+use crate::domain::i18n::{Locale, Message};
+use crate::domain::rate_limit::Requester;
+use crate::ports::drivers::client::{
+    GameInteraction, GridInteraction, MessageInteraction, MessageInteractionError, ReplyCleanup,
+    ReplyTracking,
+};
+use async_trait::async_trait;
+use contracts::card::Card;
+use contracts::image::Image;
+use contracts::search_result::SearchResultDto;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// There's only ever one user, in one channel.
+const USER_ID: u64 = 1;
+const CHANNEL_ID: &str = "terminal";
+
+/// One line typed at the prompt. Everything the bot would post is printed
+/// instead, and images are saved to `images_dir` with their paths printed.
+pub struct TerminalInteraction {
+    line: u64,
+    images_dir: PathBuf,
+    next_reply: AtomicU64,
+    sent: Mutex<Vec<u64>>,
+}
+
+impl TerminalInteraction {
+    pub fn new(line: u64, images_dir: PathBuf) -> Self {
+        Self {
+            line,
+            images_dir,
+            next_reply: AtomicU64::new(1),
+            sent: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn print(&self, text: &str) {
+        let reply = self.next_reply.fetch_add(1, Ordering::Relaxed);
+        self.sent
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(reply);
+        println!("{text}\n");
+    }
+
+    fn save(&self, image: &Image, name: &str) -> String {
+        let path = self.images_dir.join(format!("{name}.png"));
+        match fs::create_dir_all(&self.images_dir).and_then(|()| fs::write(&path, image.bytes())) {
+            Ok(()) => format!("🖼️ {}", path.display()),
+            Err(why) => {
+                log::warn!("Couldn't save image to {}: {why}", path.display());
+                String::from("🖼️ (couldn't save the image)")
+            }
+        }
+    }
+}
+
+fn stats(card: &Card) -> Option<String> {
+    if let Some(power) = card.power() {
+        Some(format!("{power}/{}", card.toughness().unwrap_or("0")))
+    } else {
+        card.loyalty().or(card.defence()).map(ToString::to_string)
+    }
+}
+
+fn describe(card: &Card) -> String {
+    let mut lines = vec![
+        format!("{}    {}", card.name(), card.mana_cost()),
+        card.type_line().to_string(),
+    ];
+    if !card.oracle_text().is_empty() {
+        lines.push(card.oracle_text().to_string());
+    }
+    lines.extend(stats(card));
+    lines.push(format!(
+        "{} ({}) · 🖌️ {}",
+        card.set_name(),
+        card.set_abbreviation().to_uppercase(),
+        card.artist()
+    ));
+    lines.join("\n")
+}
+
+/// What the guessing game has revealed so far, as `create_game_embed` shows
+/// it on Discord.
+fn clues(state: &GameState) -> String {
+    let card = state.card();
+    let mut lines = vec![format!("🖌️ {}", card.artist())];
+    if state.guesses() > state.multiplier() {
+        lines.push(format!("???? {}", card.mana_cost()));
+    }
+    if state.guesses() > state.multiplier() * 2 {
+        lines.push(card.type_line().to_string());
+        lines.push(card.oracle_text().to_string());
+        lines.extend(stats(card));
+    }
+    lines.join("\n")
+}
+
+fn names(cards: Option<&Vec<Card>>) -> Option<String> {
+    let cards = cards.filter(|cards| !cards.is_empty())?;
+    Some(cards.iter().map(Card::name).collect::<Vec<_>>().join(", "))
+}
+
+#[async_trait]
+impl MessageInteraction for TerminalInteraction {
+    async fn send_card(&self, result: SearchResultDto) -> Result<(), MessageInteractionError> {
+        let card = result.card();
+        let mut lines = vec![
+            describe(card),
+            self.save(result.image(), &card.id().to_string()),
+        ];
+        if let Some(printings) = result.printings() {
+            lines.push(format!("Printings: {}", printings.len()));
+        }
+        if let Some(similar) = names(result.similar_cards()) {
+            lines.push(format!("Similar: {similar}"));
+        }
+        if let Some(similar) = names(result.functionally_similar()) {
+            lines.push(format!("Functionally similar: {similar}"));
+        }
+        self.print(&lines.join("\n"));
+        Ok(())
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.print(&message);
+        Ok(())
+    }
+
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.print(&message);
+        Ok(())
+    }
+
+    async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.print(&message);
+        Ok(())
+    }
+
+    fn locale(&self) -> Locale {
+        Locale::English
+    }
+
+    fn requester(&self) -> Requester {
+        Requester {
+            user_id: USER_ID,
+            guild_id: None,
+        }
+    }
+}
+
+#[async_trait]
+impl GridInteraction for TerminalInteraction {
+    async fn send_grid(&self, grid: CardGrid) -> Result<(), MessageInteractionError> {
+        let mut lines: Vec<String> = grid.cards().iter().map(describe).collect();
+        lines.push(self.save(grid.image(), &format!("grid-{}", self.line)));
+        if grid.missing() > 0 {
+            lines.push(format!("{} not found", grid.missing()));
+        }
+        self.print(&lines.join("\n\n"));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ReplyCleanup for TerminalInteraction {
+    fn source(&self) -> String {
+        self.line.to_string()
+    }
+
+    /// Printed lines can't be taken back, and lines are never edited.
+    async fn delete_replies(&self, _: Vec<u64>) {}
+}
+
+#[async_trait]
+impl ReplyTracking for TerminalInteraction {
+    fn sent(&self) -> Vec<u64> {
+        self.sent
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    fn reuse(&self, _: Vec<u64>) {}
+}
+
+#[async_trait]
+impl GameInteraction for TerminalInteraction {
+    async fn send_guess_wrong_message(
+        &self,
+        state: GameState,
+        images: Image,
+        guess: String,
+    ) -> Result<(), MessageInteractionError> {
+        let message = Message::WrongGuess {
+            guess: &guess,
+            remaining: state.max_guesses() - state.number_of_guesses(),
+        };
+        let illustration = self.save(&images, "game");
+        self.print(&format!(
+            "{}\n{}\n{illustration}",
+            message.text(Locale::English),
+            clues(&state)
+        ));
+        Ok(())
+    }
+
+    async fn send_new_game_message(
+        &self,
+        state: GameState,
+        images: Image,
+    ) -> Result<(), MessageInteractionError> {
+        let difficulty = state.difficulty();
+        let message = Message::NewGame {
+            difficulty,
+            set_name: (!matches!(difficulty, Difficulty::Hard)).then_some(state.card().set_name()),
+        };
+        let illustration = self.save(&images, "game");
+        self.print(&format!(
+            "{}\n{}\n{illustration}",
+            message.text(Locale::English),
+            clues(&state)
+        ));
+        Ok(())
+    }
+
+    async fn send_win_message(
+        &self,
+        state: GameState,
+        images: Image,
+    ) -> Result<(), MessageInteractionError> {
+        let message = Message::GameWon {
+            player: "You",
+            guesses: state.number_of_guesses(),
+        };
+        let image = self.save(&images, &state.card().id().to_string());
+        self.print(&format!(
+            "{}\n{}\n{image}",
+            message.text(Locale::English),
+            describe(state.card())
+        ));
+        Ok(())
+    }
+
+    async fn game_failed_message(
+        &self,
+        state: GameState,
+        images: Image,
+    ) -> Result<(), MessageInteractionError> {
+        let message = Message::GameLost {
+            guesses: state.number_of_guesses(),
+        };
+        let image = self.save(&images, &state.card().id().to_string());
+        self.print(&format!(
+            "{}\n{}\n{image}",
+            message.text(Locale::English),
+            describe(state.card())
+        ));
+        Ok(())
+    }
+
+    fn id(&self) -> String {
+        String::from(CHANNEL_ID)
+    }
+
+    fn guild_id(&self) -> Option<String> {
+        None
+    }
+
+    fn user_id(&self) -> u64 {
+        USER_ID
+    }
+
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError> {
+        self.print(&message);
+        Ok(())
+    }
+
+    fn locale(&self) -> Locale {
+        Locale::English
+    }
+}
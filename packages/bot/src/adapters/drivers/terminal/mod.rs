@@ -0,0 +1,201 @@
+mod interaction;
+
+use crate::adapters::drivers::terminal::interaction::TerminalInteraction;
+use crate::domain::app::App;
+use crate::domain::functions::game::guess::GuessOptions;
+use crate::domain::functions::game::play::PlayOptions;
+use crate::domain::functions::game::state::Difficulty;
+use crate::domain::search;
+use crate::ports::drivers::client::Client;
+use crate::ports::services::cache::Cache;
+use crate::ports::services::card_store::CardStore;
+use crate::ports::services::image_store::ImageStore;
+use crate::ports::services::spoiler_subscription::SpoilerSubscription;
+use async_trait::async_trait;
+use cards_sdk::{CubeRepository, SpoilerQueue, StatsRepository};
+use std::env;
+use std::io::Write;
+use std::path::PathBuf;
+use tokio::io::{stdin, AsyncBufReadExt, BufReader};
+
+const USAGE: &str = "\
+[[card]]                  search, as inline in a message — [[name | set=... | artist=...]]
+/play [difficulty] [set]  start a guessing game: easy, medium (default) or hard
+/guess <card>             guess the card
+/give_up                  reveal the card
+/help                     show this
+/quit                     leave";
+
+/// One line typed at the prompt.
+#[derive(Debug, PartialEq)]
+enum Line {
+    Search(String),
+    Play {
+        difficulty: Difficulty,
+        set: Option<String>,
+    },
+    Guess(String),
+    GiveUp,
+    Help,
+    Quit,
+    Unknown,
+}
+
+impl Line {
+    fn parse(line: &str) -> Self {
+        let line = line.trim();
+        let Some(command) = line.strip_prefix('/') else {
+            return Self::Search(line.to_string());
+        };
+        let (name, rest) = command.split_once(' ').unwrap_or((command, ""));
+        let rest = rest.trim();
+
+        match name {
+            "play" => {
+                let (first, after) = rest.split_once(' ').unwrap_or((rest, ""));
+                let difficulty = match first.to_lowercase().as_str() {
+                    "easy" => Some(Difficulty::Easy),
+                    "medium" => Some(Difficulty::Medium),
+                    "hard" => Some(Difficulty::Hard),
+                    _ => None,
+                };
+                let set = match difficulty {
+                    Some(_) => after.trim(),
+                    None => rest,
+                };
+                Self::Play {
+                    difficulty: difficulty.unwrap_or(Difficulty::Medium),
+                    set: (!set.is_empty()).then(|| set.to_string()),
+                }
+            }
+            "guess" if !rest.is_empty() => Self::Guess(rest.to_string()),
+            "give_up" => Self::GiveUp,
+            "help" => Self::Help,
+            "quit" | "exit" => Self::Quit,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Drives the bot from a terminal, for trying changes end to end without
+/// Discord. Cards, games and replies are printed; images are saved to
+/// `TERMINAL_IMAGES_DIR`, or a directory under the system temp dir.
+pub struct Terminal<IS, CS, C, Sub> {
+    app: App<IS, CS, C, Sub>,
+    images_dir: PathBuf,
+}
+
+impl<IS, CS, C, Sub> Terminal<IS, CS, C, Sub> {
+    pub fn new(app: App<IS, CS, C, Sub>) -> Self {
+        let images_dir = env::var("TERMINAL_IMAGES_DIR")
+            .map_or_else(|_| env::temp_dir().join("rustcord"), PathBuf::from);
+        Self { app, images_dir }
+    }
+}
+
+fn prompt() {
+    print!("> ");
+    if let Err(why) = std::io::stdout().flush() {
+        log::warn!("Couldn't flush the prompt: {why}");
+    }
+}
+
+#[async_trait]
+impl<IS, CS, C, Sub> Client for Terminal<IS, CS, C, Sub>
+where
+    IS: ImageStore + Send + Sync + 'static,
+    CS: CardStore + SpoilerQueue + StatsRepository + CubeRepository + Send + Sync + 'static,
+    C: Cache + Send + Sync + 'static,
+    Sub: SpoilerSubscription + Send + Sync + 'static,
+{
+    async fn run(&mut self) {
+        println!("{USAGE}\n");
+        let mut lines = BufReader::new(stdin()).lines();
+        let mut number = 0;
+
+        loop {
+            prompt();
+            let line = match lines.next_line().await {
+                Ok(Some(line)) => line,
+                Ok(None) => break,
+                Err(why) => {
+                    log::error!("Couldn't read from the terminal - {why}");
+                    break;
+                }
+            };
+            number += 1;
+            let interaction = TerminalInteraction::new(number, self.images_dir.clone());
+
+            match Line::parse(&line) {
+                Line::Search(text) if text.is_empty() => {}
+                Line::Search(text) if search::queries(&text).is_empty() => {
+                    println!("No [[card]] queries in that. Try /help\n");
+                }
+                Line::Search(text) => self.app.inline_search(&interaction, &text).await,
+                Line::Play { difficulty, set } => {
+                    self.app
+                        .play_command(&interaction, PlayOptions::new(set, difficulty))
+                        .await;
+                }
+                Line::Guess(guess) => {
+                    self.app
+                        .guess_command(&interaction, GuessOptions::new(guess))
+                        .await;
+                }
+                Line::GiveUp => self.app.give_up_command(&interaction).await,
+                Line::Help | Line::Unknown => println!("{USAGE}\n"),
+                Line::Quit => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search() {
+        assert_eq!(
+            Line::parse("  [[lightning bolt]] "),
+            Line::Search(String::from("[[lightning bolt]]"))
+        );
+    }
+
+    #[test]
+    fn test_parse_play() {
+        assert_eq!(
+            Line::parse("/play"),
+            Line::Play {
+                difficulty: Difficulty::Medium,
+                set: None
+            }
+        );
+        assert_eq!(
+            Line::parse("/play hard dominaria united"),
+            Line::Play {
+                difficulty: Difficulty::Hard,
+                set: Some(String::from("dominaria united"))
+            }
+        );
+        assert_eq!(
+            Line::parse("/play m11"),
+            Line::Play {
+                difficulty: Difficulty::Medium,
+                set: Some(String::from("m11"))
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            Line::parse("/guess black lotus"),
+            Line::Guess(String::from("black lotus"))
+        );
+        assert_eq!(Line::parse("/guess"), Line::Unknown);
+        assert_eq!(Line::parse("/give_up"), Line::GiveUp);
+        assert_eq!(Line::parse("/quit"), Line::Quit);
+        assert_eq!(Line::parse("/oracle"), Line::Unknown);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::adapters::services::cache::EXPIRY;
+use crate::ports::services::cache::{Cache, CacheError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// How many writes go by between sweeps for expired entries, so a sweep's
+/// cost is spread over the writes that made it necessary.
+const SWEEP_EVERY: usize = 1024;
+
+struct Entry {
+    value: String,
+    expires: Instant,
+}
+
+#[derive(Default)]
+struct Entries {
+    map: HashMap<String, Entry>,
+    writes: usize,
+}
+
+/// Keeps everything in the process, so a single instance can run without
+/// Redis. Entries expire like they do in Redis: an expired entry is dropped
+/// when it's read, and every `SWEEP_EVERY` writes the rest are swept out, so
+/// keys that are never read again don't pile up.
+#[derive(Clone)]
+pub struct InMemory {
+    entries: Arc<Mutex<Entries>>,
+    ttl: Duration,
+}
+
+impl InMemory {
+    fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            entries: Arc::default(),
+            ttl,
+        }
+    }
+
+    fn entries(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[async_trait]
+impl Cache for InMemory {
+    fn create() -> Self {
+        Self::with_ttl(EXPIRY)
+    }
+
+    async fn get(&self, key: String) -> Option<String> {
+        let mut entries = self.entries();
+        match entries.map.get(&key) {
+            Some(entry) if entry.expires > Instant::now() => Some(entry.value.clone()),
+            Some(_) => {
+                entries.map.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn set(&self, key: String, value: String) -> Result<(), CacheError> {
+        let now = Instant::now();
+        let mut entries = self.entries();
+        entries.writes += 1;
+        if entries.writes >= SWEEP_EVERY {
+            entries.writes = 0;
+            entries.map.retain(|_, entry| entry.expires > now);
+        }
+        entries.map.insert(
+            key,
+            Entry {
+                value,
+                expires: now + self.ttl,
+            },
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, key: String) -> Result<(), CacheError> {
+        self.entries().map.remove(&key);
+        Ok(())
+    }
+
+    async fn ping(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_set_get_delete() {
+        let cache = InMemory::create();
+        cache
+            .set(String::from("key"), String::from("value"))
+            .await
+            .unwrap();
+        assert_eq!(
+            cache.get(String::from("key")).await.as_deref(),
+            Some("value")
+        );
+
+        cache.delete(String::from("key")).await.unwrap();
+        assert_eq!(cache.get(String::from("key")).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_gone() {
+        let cache = InMemory::with_ttl(Duration::ZERO);
+        cache
+            .set(String::from("stale"), String::from("value"))
+            .await
+            .unwrap();
+        assert_eq!(cache.get(String::from("stale")).await, None);
+        assert!(!cache.entries().map.contains_key("stale"));
+    }
+
+    #[tokio::test]
+    async fn test_unread_entries_are_swept() {
+        let cache = InMemory::with_ttl(Duration::ZERO);
+        for i in 0..SWEEP_EVERY - 1 {
+            cache
+                .set(format!("stale-{i}"), String::new())
+                .await
+                .unwrap();
+        }
+        assert_eq!(cache.entries().map.len(), SWEEP_EVERY - 1);
+
+        cache
+            .set(String::from("last"), String::new())
+            .await
+            .unwrap();
+        assert_eq!(cache.entries().map.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_entries() {
+        let cache = InMemory::create();
+        cache
+            .clone()
+            .set(String::from("key"), String::from("value"))
+            .await
+            .unwrap();
+        assert_eq!(
+            cache.get(String::from("key")).await.as_deref(),
+            Some("value")
+        );
+    }
+}
@@ -1,9 +1,66 @@
+mod memory;
 mod redis;
 
+use crate::adapters::services::cache::memory::InMemory;
 use crate::adapters::services::cache::redis::Redis;
-use crate::ports::services::cache::Cache;
+use crate::ports::services::cache::{Cache, CacheError};
+use async_trait::async_trait;
+use std::env;
+use std::time::Duration;
 
+/// How long anything set in the cache lasts, whichever backs it.
+const EXPIRY: Duration = Duration::from_hours(24);
+
+/// Redis, unless `CACHE` is `memory`. Everything the bot caches is only
+/// shared between instances through Redis, so keep it when running more
+/// than one.
 #[must_use]
-pub fn init_cache() -> impl Cache {
-    Redis::create()
+pub fn init_cache() -> impl Cache + Clone {
+    Selected::create()
+}
+
+#[derive(Clone)]
+enum Selected {
+    Redis(Redis),
+    InMemory(InMemory),
+}
+
+#[async_trait]
+impl Cache for Selected {
+    fn create() -> Self {
+        if env::var("CACHE").is_ok_and(|cache| cache.eq_ignore_ascii_case("memory")) {
+            log::info!("Caching in memory");
+            Self::InMemory(InMemory::create())
+        } else {
+            Self::Redis(Redis::create())
+        }
+    }
+
+    async fn get(&self, key: String) -> Option<String> {
+        match self {
+            Self::Redis(cache) => cache.get(key).await,
+            Self::InMemory(cache) => cache.get(key).await,
+        }
+    }
+
+    async fn set(&self, key: String, value: String) -> Result<(), CacheError> {
+        match self {
+            Self::Redis(cache) => cache.set(key, value).await,
+            Self::InMemory(cache) => cache.set(key, value).await,
+        }
+    }
+
+    async fn delete(&self, key: String) -> Result<(), CacheError> {
+        match self {
+            Self::Redis(cache) => cache.delete(key).await,
+            Self::InMemory(cache) => cache.delete(key).await,
+        }
+    }
+
+    async fn ping(&self) -> bool {
+        match self {
+            Self::Redis(cache) => cache.ping().await,
+            Self::InMemory(cache) => cache.ping().await,
+        }
+    }
 }
@@ -1,9 +1,11 @@
+use crate::adapters::services::cache::EXPIRY;
 use crate::ports::services::cache::{Cache, CacheError};
 use async_trait::async_trait;
 use redis::aio::MultiplexedConnection;
 use redis::{AsyncCommands, Client, SetExpiry, SetOptions};
 use std::env;
 
+#[derive(Clone)]
 pub struct Redis {
     client: Client,
 }
@@ -33,7 +35,7 @@ impl Cache for Redis {
             .set_options::<String, String, ()>(
                 key,
                 value,
-                SetOptions::default().with_expiration(SetExpiry::EX(86400)),
+                SetOptions::default().with_expiration(SetExpiry::EX(EXPIRY.as_secs())),
             )
             .await
         {
@@ -52,6 +54,22 @@ impl Cache for Redis {
             Ok(())
         }
     }
+
+    async fn ping(&self) -> bool {
+        let Ok(mut connection) = self.new_connection().await else {
+            return false;
+        };
+        match redis::cmd("PING")
+            .query_async::<String>(&mut connection)
+            .await
+        {
+            Ok(_) => true,
+            Err(why) => {
+                log::warn!("Error pinging cache {why:?}");
+                false
+            }
+        }
+    }
 }
 
 impl Redis {
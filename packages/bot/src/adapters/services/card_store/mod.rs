@@ -1,6 +1,7 @@
 use crate::ports::services::card_store::CardStore;
-use cards_sdk::SpoilerQueue;
+use cards_sdk::{CubeRepository, SpoilerQueue, StatsRepository};
 
-pub async fn init_card_store() -> impl CardStore + SpoilerQueue {
+pub async fn init_card_store(
+) -> impl CardStore + SpoilerQueue + StatsRepository + CubeRepository + Clone {
     cards_sdk::Postgres::create().await
 }
@@ -19,7 +19,9 @@ pub struct DiscordWebhookRegistrar {
 #[async_trait]
 impl SpoilerSubscription for DiscordWebhookRegistrar {
     fn create() -> Self {
-        let token = env::var("BOT_TOKEN").expect("BOT_TOKEN wasn't in env vars");
+        // Only reached from Discord, which won't start without the token —
+        // other drivers never subscribe, so they don't need one.
+        let token = env::var("BOT_TOKEN").unwrap_or_default();
         Self {
             http: Http::new(&token),
         }
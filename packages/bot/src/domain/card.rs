@@ -1,4 +1,7 @@
-use crate::ports::drivers::client::MessageInteraction;
+use crate::domain::grid::{self, CardGrid, MAX_GRID_CARDS};
+use crate::domain::i18n::Message;
+use crate::ports::drivers::client::{GridInteraction, MessageInteraction};
+use contracts::image::Image;
 use contracts::search_result::SearchResultDto;
 
 pub async fn card_response<MI: MessageInteraction>(
@@ -8,7 +11,7 @@ pub async fn card_response<MI: MessageInteraction>(
     match result {
         None => {
             if let Err(why) = interaction
-                .reply(String::from("Failed to find card :("))
+                .reply(Message::QueryNotMatched.text(interaction.locale()))
                 .await
             {
                 log::error!("Error sending card not found message :( {why:?}");
@@ -22,13 +25,69 @@ pub async fn card_response<MI: MessageInteraction>(
     }
 }
 
+/// Replies to a message's inline queries, stitching the cards into grids
+/// once at least `threshold` are found so they don't fill the channel.
+/// Below that, or with a `threshold` of `0`, each gets its own message.
+pub async fn inline_response<I: MessageInteraction + GridInteraction>(
+    results: Vec<Option<SearchResultDto>>,
+    interaction: &I,
+    threshold: usize,
+) {
+    let found = results.iter().filter(|result| result.is_some()).count();
+    if threshold == 0 || found < threshold {
+        for result in results {
+            card_response(result, interaction).await;
+        }
+        return;
+    }
+
+    let mut missing = results.len() - found;
+    let mut results: Vec<SearchResultDto> = results.into_iter().flatten().collect();
+    while !results.is_empty() {
+        let chunk: Vec<SearchResultDto> =
+            results.drain(..MAX_GRID_CARDS.min(results.len())).collect();
+        let images: Vec<Image> = chunk.iter().map(|result| result.image().clone()).collect();
+
+        // Decoding and re-encoding the scans takes a while, so it's kept
+        // off the async workers.
+        let composed = tokio::task::spawn_blocking(move || grid::compose(&images))
+            .await
+            .ok()
+            .flatten();
+        let Some(image) = composed else {
+            log::warn!("couldn't compose a card grid, sending the cards separately");
+            for result in chunk {
+                card_response(Some(result), interaction).await;
+            }
+            continue;
+        };
+
+        let cards = chunk
+            .into_iter()
+            .map(|result| result.card().clone())
+            .collect();
+        if let Err(why) = interaction
+            .send_grid(CardGrid::new(image, cards, missing))
+            .await
+        {
+            log::error!("Error sending card grid :( {why:?}");
+        }
+        missing = 0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::i18n::Locale;
+    use crate::domain::rate_limit::Requester;
+    use crate::domain::utils::raster::Raster;
     use crate::ports::drivers::client::{MessageInteractionError, MockMessageInteraction};
+    use async_trait::async_trait;
     use contracts::card::Card;
     use contracts::image::Image;
     use contracts::search_result::SearchResultDto;
+    use mockall::{mock, Sequence};
     use uuid::Uuid;
 
     fn create_test_card() -> Card {
@@ -56,6 +115,115 @@ mod tests {
         )
     }
 
+    mock! {
+        InlineInteraction {}
+
+        #[async_trait]
+        impl MessageInteraction for InlineInteraction {
+            async fn send_card(&self, result: SearchResultDto) -> Result<(), MessageInteractionError>;
+            async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+            async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError>;
+            async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError>;
+            fn locale(&self) -> Locale;
+            fn requester(&self) -> Requester;
+        }
+
+        #[async_trait]
+        impl GridInteraction for InlineInteraction {
+            async fn send_grid(&self, grid: CardGrid) -> Result<(), MessageInteractionError>;
+        }
+    }
+
+    fn png_result() -> SearchResultDto {
+        let png = Raster::blank(4, 4).encode_png().unwrap();
+        SearchResultDto::new(create_test_card(), Image::new(png))
+    }
+
+    #[tokio::test]
+    async fn test_inline_response_below_threshold() {
+        let mut interaction = MockInlineInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_grid().times(0);
+        interaction
+            .expect_send_card()
+            .times(2)
+            .returning(|_| Ok(()));
+        interaction.expect_reply().times(1).returning(|_| Ok(()));
+
+        inline_response(
+            vec![Some(png_result()), None, Some(png_result())],
+            &interaction,
+            3,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_inline_response_grid() {
+        let mut interaction = MockInlineInteraction::new();
+        interaction.expect_send_card().times(0);
+        interaction.expect_reply().times(0);
+        interaction
+            .expect_send_grid()
+            .times(1)
+            .withf(|grid| grid.cards().len() == 3 && grid.missing() == 1)
+            .returning(|_| Ok(()));
+
+        inline_response(
+            vec![
+                Some(png_result()),
+                None,
+                Some(png_result()),
+                Some(png_result()),
+            ],
+            &interaction,
+            3,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_inline_response_splits_large_grids() {
+        let mut sequence = Sequence::new();
+        let mut interaction = MockInlineInteraction::new();
+        interaction
+            .expect_send_grid()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .withf(|grid| grid.cards().len() == MAX_GRID_CARDS)
+            .returning(|_| Ok(()));
+        interaction
+            .expect_send_grid()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .withf(|grid| grid.cards().len() == 2)
+            .returning(|_| Ok(()));
+
+        inline_response(
+            (0..12).map(|_| Some(png_result())).collect(),
+            &interaction,
+            3,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_inline_response_disabled() {
+        let mut interaction = MockInlineInteraction::new();
+        interaction.expect_send_grid().times(0);
+        interaction
+            .expect_send_card()
+            .times(4)
+            .returning(|_| Ok(()));
+
+        inline_response(
+            (0..4).map(|_| Some(png_result())).collect(),
+            &interaction,
+            0,
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn test_card_response_with_card_success() {
         let card = create_test_card();
@@ -91,6 +259,9 @@ mod tests {
     #[tokio::test]
     async fn test_card_response_none_success() {
         let mut mock_interaction = MockMessageInteraction::new();
+        mock_interaction
+            .expect_locale()
+            .return_const(Locale::English);
         mock_interaction
             .expect_reply()
             .with(mockall::predicate::eq(String::from(
@@ -105,6 +276,9 @@ mod tests {
     #[tokio::test]
     async fn test_card_response_none_error() {
         let mut mock_interaction = MockMessageInteraction::new();
+        mock_interaction
+            .expect_locale()
+            .return_const(Locale::English);
         mock_interaction
             .expect_reply()
             .with(mockall::predicate::eq(String::from(
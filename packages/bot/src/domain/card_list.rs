@@ -0,0 +1,322 @@
+use crate::domain::query::QueryParams;
+use crate::domain::utils;
+use crate::impl_app;
+use contracts::card::Card;
+use regex::Regex;
+use serenity::futures::stream::{self, StreamExt};
+use std::sync::LazyLock;
+
+/// How many lines are looked up at once, so a long paste doesn't flood the
+/// connection pool.
+const CONCURRENT_LOOKUPS: usize = 8;
+
+/// The most copies one line can ask for. Anything bigger is a typo or a
+/// prank, and is reported back as unreadable rather than summed.
+const MAX_LINE_COUNT: u32 = 999;
+
+/// `4 Lightning Bolt`, `4x Lightning Bolt` or plain `Lightning Bolt`, with
+/// an optional Arena-style `(M11) 149` set and collector number on the end.
+static LINE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:(\d+)x?\s+)?(.+?)(?:\s+\(([A-Za-z0-9]+)\)(?:\s+\S+)?)?$")
+        .expect("Invalid regex")
+});
+
+/// Which part of a deck a line belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Main,
+    Sideboard,
+    Commander,
+}
+
+/// What a heading line switches the following lines to. Maybeboards and
+/// Arena's `About` block aren't part of the deck, so their lines are
+/// dropped.
+enum Heading {
+    Section(Section),
+    Ignored,
+}
+
+/// Section headings some exporters put on their own line, with or without
+/// a trailing colon.
+fn heading(line: &str) -> Option<Heading> {
+    match line.trim_end_matches(':').to_lowercase().as_str() {
+        "deck" | "main" | "mainboard" => Some(Heading::Section(Section::Main)),
+        "sideboard" | "companion" => Some(Heading::Section(Section::Sideboard)),
+        "commander" => Some(Heading::Section(Section::Commander)),
+        "maybeboard" | "about" => Some(Heading::Ignored),
+        _ => None,
+    }
+}
+
+/// One line of a pasted card list.
+pub struct CardListLine {
+    line: String,
+    count: u32,
+    section: Section,
+    /// `None` when the line couldn't be read as a card, so it's reported
+    /// back as unresolved rather than silently dropped.
+    query: Option<QueryParams>,
+}
+
+/// Parses a pasted list one card per line, skipping `//` and `#` comments.
+/// Arena and Moxfield headings pick the section; MTGO exports have none and
+/// put the sideboard after the first blank line instead, or prefix each
+/// sideboard line with `SB:`.
+#[must_use]
+pub fn parse(text: &str) -> Vec<CardListLine> {
+    let mut section = Some(Section::Main);
+    let mut saw_heading = false;
+    let mut lines = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            if !saw_heading && !lines.is_empty() {
+                section = Some(Section::Sideboard);
+            }
+            continue;
+        }
+        if line.starts_with("//") || line.starts_with('#') {
+            continue;
+        }
+        if let Some(heading) = heading(line) {
+            saw_heading = true;
+            section = match heading {
+                Heading::Section(section) => Some(section),
+                Heading::Ignored => None,
+            };
+            continue;
+        }
+        let Some(current) = section else {
+            continue;
+        };
+
+        let (line, section) = match line.strip_prefix("SB:") {
+            Some(rest) => (rest.trim(), Section::Sideboard),
+            None => (line, current),
+        };
+        lines.push(parse_line(line, section).unwrap_or_else(|| CardListLine {
+            line: line.to_string(),
+            count: 1,
+            section,
+            query: None,
+        }));
+    }
+
+    lines
+}
+
+fn parse_line(line: &str, section: Section) -> Option<CardListLine> {
+    let captures = LINE_RE.captures(line)?;
+    let count = captures
+        .get(1)
+        .map_or(Some(1), |count| count.as_str().parse().ok())
+        .filter(|count| *count <= MAX_LINE_COUNT)?;
+    let name = utils::normalise_card_name(captures.get(2)?.as_str());
+    let set_code = captures
+        .get(3)
+        .map(|set| utils::normalise_card_name(set.as_str()));
+
+    Some(CardListLine {
+        line: line.to_string(),
+        count,
+        section,
+        query: Some(QueryParams::new(None, name, set_code, None)),
+    })
+}
+
+/// A resolved line: how many copies, where in the deck, and the card.
+pub struct ListedCard {
+    count: u32,
+    section: Section,
+    card: Card,
+}
+
+impl ListedCard {
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    #[must_use]
+    pub fn section(&self) -> Section {
+        self.section
+    }
+
+    #[must_use]
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+}
+
+/// The cards a list resolved to, in the order given, and the lines that
+/// couldn't be matched to anything.
+pub struct ResolvedCardList {
+    cards: Vec<ListedCard>,
+    unresolved: Vec<String>,
+}
+
+impl ResolvedCardList {
+    #[must_use]
+    pub fn cards(&self) -> &[ListedCard] {
+        &self.cards
+    }
+
+    #[must_use]
+    pub fn unresolved(&self) -> &[String] {
+        &self.unresolved
+    }
+}
+
+impl_app! {
+    /// Looks every line up through the same fuzzy matching as `find_card`.
+    pub async fn resolve_card_list(&self, lines: Vec<CardListLine>) -> ResolvedCardList {
+        let matches: Vec<(CardListLine, Option<Card>)> = stream::iter(lines)
+            .map(|line| async move {
+                let card = match &line.query {
+                    Some(query) => self.match_card(query).await.map(|(card, _)| card),
+                    None => None,
+                };
+                (line, card)
+            })
+            .buffered(CONCURRENT_LOOKUPS)
+            .collect()
+            .await;
+
+        let mut resolved = ResolvedCardList {
+            cards: Vec::with_capacity(matches.len()),
+            unresolved: Vec::new(),
+        };
+        for (line, card) in matches {
+            match card {
+                Some(card) => resolved.cards.push(ListedCard {
+                    count: line.count,
+                    section: line.section,
+                    card,
+                }),
+                None => resolved.unresolved.push(line.line),
+            }
+        }
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_name() {
+        let line = parse_line("Lightning Bolt", Section::Main).unwrap();
+        assert_eq!(line.count, 1);
+        assert_eq!(line.query.as_ref().unwrap().name(), "lightning bolt");
+        assert!(line.query.as_ref().unwrap().set_code().is_none());
+    }
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(
+            parse_line("4 Lightning Bolt", Section::Main).unwrap().count,
+            4
+        );
+        assert_eq!(
+            parse_line("4x Lightning Bolt", Section::Main)
+                .unwrap()
+                .count,
+            4
+        );
+        assert_eq!(
+            parse_line("4x Lightning Bolt", Section::Main)
+                .unwrap()
+                .query
+                .unwrap()
+                .name(),
+            "lightning bolt"
+        );
+    }
+
+    #[test]
+    fn test_arena_set_and_number() {
+        let line = parse_line("1 Lightning Bolt (M11) 149", Section::Main).unwrap();
+        assert_eq!(line.query.as_ref().unwrap().name(), "lightning bolt");
+        assert_eq!(line.query.as_ref().unwrap().set_code().unwrap(), "m11");
+    }
+
+    #[test]
+    fn test_skips_noise() {
+        let lines = parse("Deck\n4 Lightning Bolt\n\n// burn\n# more burn\nSideboard\n2 Pyroblast");
+        let names: Vec<&str> = lines
+            .iter()
+            .map(|line| line.query.as_ref().unwrap().name())
+            .collect();
+        assert_eq!(names, vec!["lightning bolt", "pyroblast"]);
+    }
+
+    #[test]
+    fn test_keeps_original_line() {
+        let lines = parse("  4 Lightnig Bolt  ");
+        assert_eq!(lines[0].line, "4 Lightnig Bolt");
+    }
+
+    fn sections(text: &str) -> Vec<(&'static str, Section)> {
+        parse(text)
+            .iter()
+            .map(|line| match line.query.as_ref().unwrap().name() {
+                "lightning bolt" => ("bolt", line.section),
+                "pyroblast" => ("pyroblast", line.section),
+                _ => ("other", line.section),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_arena_sections() {
+        assert_eq!(
+            sections("Commander\n1 Krenko\n\nDeck\n4 Lightning Bolt\n\nSideboard\n2 Pyroblast"),
+            vec![
+                ("other", Section::Commander),
+                ("bolt", Section::Main),
+                ("pyroblast", Section::Sideboard),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mtgo_blank_line_starts_sideboard() {
+        assert_eq!(
+            sections("4 Lightning Bolt\n\n2 Pyroblast"),
+            vec![("bolt", Section::Main), ("pyroblast", Section::Sideboard)]
+        );
+        assert_eq!(
+            sections("4 Lightning Bolt\nSB: 2 Pyroblast"),
+            vec![("bolt", Section::Main), ("pyroblast", Section::Sideboard)]
+        );
+    }
+
+    #[test]
+    fn test_drops_maybeboard_and_about() {
+        assert_eq!(
+            sections("About\nName Burn\n\nDeck\n4 Lightning Bolt\nMAYBEBOARD:\n2 Pyroblast"),
+            vec![("bolt", Section::Main)]
+        );
+    }
+
+    #[test]
+    fn test_unreadable_count_is_kept() {
+        let lines = parse(
+            "99999999999 Island
+1 Lightning Bolt",
+        );
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line, "99999999999 Island");
+        assert!(lines[0].query.is_none());
+        assert!(lines[1].query.is_some());
+    }
+
+    #[test]
+    fn test_count_over_the_cap_is_unreadable() {
+        let lines = parse("2147483648 Delver of Secrets\n999 Island");
+        assert!(lines[0].query.is_none());
+        assert_eq!(lines[1].count, MAX_LINE_COUNT);
+    }
+}
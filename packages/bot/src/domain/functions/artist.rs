@@ -0,0 +1,337 @@
+use crate::domain::grid::{self, MAX_GRID_CARDS};
+use crate::domain::i18n::Message;
+use crate::domain::utils;
+use crate::impl_app;
+use crate::ports::drivers::client::ArtistInteraction;
+use contracts::card::Card;
+use contracts::image::Image;
+use serenity::futures::future::join_all;
+
+/// One grid's worth of illustrations to a page.
+pub const GALLERY_PAGE_SIZE: usize = MAX_GRID_CARDS;
+
+/// An artist's illustrations, oldest first, open at one page of them.
+pub struct ArtistGallery {
+    artist_name: String,
+    card_count: u32,
+    illustrations: Vec<Card>,
+    page: usize,
+}
+
+impl ArtistGallery {
+    /// `None` without any illustrations to show. A `page` past the end
+    /// opens the last one.
+    #[must_use]
+    pub fn new(
+        artist_name: String,
+        card_count: u32,
+        illustrations: Vec<Card>,
+        page: usize,
+    ) -> Option<Self> {
+        if illustrations.is_empty() {
+            return None;
+        }
+        let pages = illustrations.len().div_ceil(GALLERY_PAGE_SIZE);
+        Some(Self {
+            artist_name,
+            card_count,
+            illustrations,
+            page: page.min(pages - 1),
+        })
+    }
+
+    /// The normalised name the gallery was looked up by.
+    #[must_use]
+    pub fn artist_name(&self) -> &str {
+        &self.artist_name
+    }
+
+    /// The artist's name as printed on their cards.
+    #[must_use]
+    pub fn artist(&self) -> &str {
+        self.first_work().artist()
+    }
+
+    /// Distinct cards they've illustrated, which can be fewer than the
+    /// illustrations — some cards they've painted more than once.
+    #[must_use]
+    pub fn card_count(&self) -> u32 {
+        self.card_count
+    }
+
+    #[must_use]
+    pub fn first_work(&self) -> &Card {
+        &self.illustrations[0]
+    }
+
+    #[must_use]
+    pub fn latest_work(&self) -> &Card {
+        &self.illustrations[self.illustrations.len() - 1]
+    }
+
+    #[must_use]
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    #[must_use]
+    pub fn pages(&self) -> usize {
+        self.illustrations.len().div_ceil(GALLERY_PAGE_SIZE)
+    }
+
+    /// The illustrations on `page`, empty if there's no such page.
+    #[must_use]
+    pub fn illustrations_on(&self, page: usize) -> &[Card] {
+        self.illustrations
+            .chunks(GALLERY_PAGE_SIZE)
+            .nth(page)
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn illustrations(&self) -> &[Card] {
+        self.illustrations_on(self.page)
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.illustrations.len()
+    }
+}
+
+impl_app! {
+    pub async fn fuzzy_match_artist_name(&self, normalised_artist_name: &str) -> Option<String> {
+        let potentials = self
+            .card_store
+            .search_for_artist_name(normalised_artist_name)
+            .await?;
+        fuzzy::winkliest_match(&normalised_artist_name, potentials)
+    }
+
+    /// The page's illustrations as one grid, or `None` if any of them
+    /// can't be fetched or decoded.
+    async fn gallery_image(&self, gallery: &ArtistGallery) -> Option<Image> {
+        let images: Vec<Image> = join_all(
+            gallery
+                .illustrations()
+                .iter()
+                .map(|card| self.image_store.fetch_illustration(card)),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .inspect_err(|why| log::warn!("couldn't fetch gallery illustration: {why}"))
+        .ok()?;
+
+        // As with inline grids, decoding the scans is kept off the async
+        // workers.
+        tokio::task::spawn_blocking(move || grid::compose(&images))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn send_artist_page<I: ArtistInteraction>(&self, interaction: &I, artist_name: &str, page: usize) {
+        let illustrations = self
+            .card_store
+            .artist_illustrations(artist_name)
+            .await
+            .unwrap_or_default();
+        let card_count = self
+            .card_store
+            .artist_card_count(artist_name)
+            .await
+            .unwrap_or_default();
+        let gallery = ArtistGallery::new(artist_name.to_string(), card_count, illustrations, page);
+        let Some(gallery) = gallery else {
+            if let Err(why) = interaction
+                .reply(Message::NoIllustrations { artist: artist_name }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+
+        let image = self.gallery_image(&gallery).await;
+        if let Err(why) = interaction.send_gallery(gallery, image).await {
+            log::warn!("couldn't send artist gallery: {why}");
+        }
+    }
+
+    pub async fn artist_command<I: ArtistInteraction>(&self, interaction: &I, options: ArtistOptions) {
+        let Some(artist_name) = self
+            .fuzzy_match_artist_name(&utils::normalise_card_name(&options.name))
+            .await
+        else {
+            if let Err(why) = interaction
+                .reply(Message::ArtistNotFound { artist: &options.name }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+        self.send_artist_page(interaction, &artist_name, 0).await;
+    }
+
+    /// Turns to another page of a gallery already on screen.
+    pub async fn artist_page<I: ArtistInteraction>(&self, interaction: &I, options: ArtistPageOptions) {
+        self.send_artist_page(interaction, &options.artist_name, options.page)
+            .await;
+    }
+}
+
+pub struct ArtistOptions {
+    name: String,
+}
+
+impl ArtistOptions {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+pub struct ArtistPageOptions {
+    artist_name: String,
+    page: usize,
+}
+
+impl ArtistPageOptions {
+    pub fn new(artist_name: String, page: usize) -> Self {
+        Self { artist_name, page }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::domain::utils::raster::Raster;
+    use crate::ports::drivers::client::MockArtistInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    fn card(name: &str, year: i32) -> Card {
+        Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            Uuid::new_v4(),
+            Some(Uuid::new_v4()),
+            String::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            String::new(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(year, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn app(
+        card_store: MockCardStore,
+        image_store: MockImageStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[test]
+    fn test_gallery_pages() {
+        let illustrations = (0..12)
+            .map(|i| card(&format!("Card {i}"), 1993 + i))
+            .collect();
+        let gallery =
+            ArtistGallery::new(String::from("christopher rush"), 9, illustrations, 5).unwrap();
+        assert_eq!(
+            (gallery.page(), gallery.pages(), gallery.total()),
+            (1, 2, 12)
+        );
+        assert_eq!(gallery.illustrations().len(), 2);
+        assert_eq!(gallery.first_work().name(), "Card 0");
+        assert_eq!(gallery.latest_work().name(), "Card 11");
+        assert_eq!(gallery.artist(), "Christopher Rush");
+        assert!(ArtistGallery::new(String::new(), 0, Vec::new(), 0).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_artist_command() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search_for_artist_name()
+            .with(eq("rush"))
+            .return_const(Some(vec![String::from("christopher rush")]));
+        card_store
+            .expect_artist_illustrations()
+            .with(eq("christopher rush"))
+            .return_const(Some(vec![
+                card("Black Lotus", 1993),
+                card("Lightning Bolt", 1993),
+            ]));
+        card_store
+            .expect_artist_card_count()
+            .return_const(Some(2_u32));
+
+        let png = Raster::blank(4, 4).encode_png().unwrap();
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch_illustration()
+            .times(2)
+            .returning(move |_| Ok(Image::new(png.clone())));
+
+        let mut interaction = MockArtistInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_gallery()
+            .times(1)
+            .withf(|gallery, image| {
+                gallery.artist_name() == "christopher rush"
+                    && gallery.card_count() == 2
+                    && image.is_some()
+            })
+            .returning(|_, _| Ok(()));
+
+        app(card_store, image_store)
+            .artist_command(&interaction, ArtistOptions::new(String::from("Rush")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_unknown_artist() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search_for_artist_name()
+            .return_const(Some(Vec::new()));
+
+        let mut interaction = MockArtistInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_gallery().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Could not find artist 'Nobody'")))
+            .returning(|_| Ok(()));
+
+        app(card_store, MockImageStore::new())
+            .artist_command(&interaction, ArtistOptions::new(String::from("Nobody")))
+            .await;
+    }
+}
@@ -0,0 +1,216 @@
+use crate::domain::functions::cube::normalise_name;
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::CubeInteraction;
+use cards_sdk::GuildId;
+use contracts::card::Card;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub const PACK_SIZE: usize = 15;
+pub const MAX_PACKS: usize = 8;
+
+impl_app! {
+    pub async fn cube_pack<I: CubeInteraction>(&self, interaction: &I, options: CubePackOptions) {
+        let name = normalise_name(&options.name);
+        let packs = options.packs.clamp(1, MAX_PACKS);
+
+        let Some(cards) = self.card_store.cube_cards(options.guild_id, &name).await else {
+            if let Err(why) = interaction
+                .reply(Message::NoSuchCube { cube: &name }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+
+        let Some(packs) = deal_packs(cards, packs, &mut rand::rng()) else {
+            if let Err(why) = interaction
+                .reply(
+                    Message::CubeTooSmall {
+                        cube: &name,
+                        packs,
+                        pack_size: PACK_SIZE,
+                    }
+                    .text(interaction.locale()),
+                )
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+
+        if let Err(why) = interaction.send_packs(name, packs).await {
+            log::warn!("couldn't send cube packs: {why}");
+        }
+    }
+}
+
+/// Deals `packs` packs without replacement, as a cube draft would — no
+/// card turns up twice across the table.
+fn deal_packs<R: Rng>(mut cards: Vec<Card>, packs: usize, rng: &mut R) -> Option<Vec<Vec<Card>>> {
+    if cards.len() < packs * PACK_SIZE {
+        return None;
+    }
+
+    cards.shuffle(rng);
+    cards.truncate(packs * PACK_SIZE);
+    Some(cards.chunks(PACK_SIZE).map(<[Card]>::to_vec).collect())
+}
+
+pub struct CubePackOptions {
+    guild_id: GuildId,
+    name: String,
+    packs: usize,
+}
+
+impl CubePackOptions {
+    pub fn new(guild_id: GuildId, name: String, packs: usize) -> Self {
+        Self {
+            guild_id,
+            name,
+            packs,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockCubeInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use cards_sdk::MockCubeRepository;
+    use mockall::predicate::*;
+    use uuid::{uuid, Uuid};
+
+    fn cube(size: usize) -> Vec<Card> {
+        (0..size)
+            .map(|i| {
+                Card::new(
+                    Uuid::new_v4(),
+                    format!("Card {i}"),
+                    format!("card {i}"),
+                    Uuid::new_v4(),
+                    "https://scryfall.com/card/test".to_string(),
+                    uuid!("12345678-1234-1234-1234-123456789012"),
+                    None,
+                    "{R}".to_string(),
+                    vec!["R".to_string()],
+                    None,
+                    None,
+                    None,
+                    None,
+                    "Instant".to_string(),
+                    String::new(),
+                    None,
+                    "Christopher Rush".to_string(),
+                    "Limited Edition Alpha".to_string(),
+                    "LEA".to_string(),
+                    time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn app(
+        cards: Option<Vec<Card>>,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        let mut cube = MockCubeRepository::new();
+        cube.expect_cube_cards()
+            .times(1)
+            .with(eq(GuildId::from(42u64)), eq("vintage"))
+            .return_const(cards);
+
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::with_cube(MockCardStore::new(), cube),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[test]
+    fn test_deal_packs_has_no_repeats() {
+        let packs = deal_packs(cube(50), 3, &mut rand::rng()).unwrap();
+
+        assert_eq!(packs.len(), 3);
+        assert!(packs.iter().all(|pack| pack.len() == PACK_SIZE));
+
+        let mut ids: Vec<&Uuid> = packs.iter().flatten().map(Card::id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), 3 * PACK_SIZE);
+    }
+
+    #[test]
+    fn test_deal_packs_needs_enough_cards() {
+        assert!(deal_packs(cube(29), 2, &mut rand::rng()).is_none());
+        assert!(deal_packs(cube(30), 2, &mut rand::rng()).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cube_pack() {
+        let mut interaction = MockCubeInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_packs()
+            .times(1)
+            .withf(|name, packs| name == "vintage" && packs.len() == 2)
+            .returning(|_, _| Ok(()));
+
+        app(Some(cube(45)))
+            .cube_pack(
+                &interaction,
+                CubePackOptions::new(GuildId::from(42u64), String::from("Vintage"), 2),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_cube_too_small() {
+        let mut interaction = MockCubeInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_packs().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "'vintage' doesn't have enough cards for 1 pack(s) of 15",
+            )))
+            .returning(|_| Ok(()));
+
+        app(Some(cube(10)))
+            .cube_pack(
+                &interaction,
+                CubePackOptions::new(GuildId::from(42u64), String::from("vintage"), 1),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_missing_cube() {
+        let mut interaction = MockCubeInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "This server has no cube called 'vintage' — create it with `/cube create`",
+            )))
+            .returning(|_| Ok(()));
+
+        app(None)
+            .cube_pack(
+                &interaction,
+                CubePackOptions::new(GuildId::from(42u64), String::from("vintage"), 1),
+            )
+            .await;
+    }
+}
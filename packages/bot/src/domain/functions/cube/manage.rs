@@ -0,0 +1,397 @@
+use crate::domain::card_list;
+use crate::domain::functions::cube::normalise_name;
+use crate::domain::i18n::Message;
+use crate::domain::query::QueryParams;
+use crate::domain::utils;
+use crate::impl_app;
+use crate::ports::drivers::client::CubeInteraction;
+use cards_sdk::GuildId;
+use uuid::Uuid;
+
+/// Discord caps messages at 2000 characters, so long lists of misses are
+/// cut short.
+const UNRESOLVED_SHOWN: usize = 20;
+
+impl_app! {
+    pub async fn cube_create<I: CubeInteraction>(&self, interaction: &I, options: CubeOptions) {
+        if !allowed(interaction).await {
+            return;
+        }
+
+        let name = normalise_name(&options.name);
+        let message = match self.card_store.create_cube(options.guild_id, &name).await {
+            Some(true) => Message::CubeCreated { cube: &name },
+            Some(false) => Message::CubeExists { cube: &name },
+            None => Message::CubeNotCreated { cube: &name },
+        };
+
+        if let Err(why) = interaction.reply(message.text(interaction.locale())).await {
+            log::warn!("couldn't create interaction response: {why}");
+        }
+    }
+
+    pub async fn cube_add<I: CubeInteraction>(&self, interaction: &I, options: CubeAdd) {
+        if !allowed(interaction).await {
+            return;
+        }
+
+        let name = normalise_name(&options.name);
+        let query = QueryParams::new(None, utils::normalise_card_name(&options.card), None, None);
+
+        let locale = interaction.locale();
+        let message = match self.match_card(&query).await {
+            None => Message::NamedCardNotFound { name: &options.card }.text(locale),
+            Some((card, _)) => match self
+                .card_store
+                .add_to_cube(options.guild_id, &name, &[*card.id()])
+                .await
+            {
+                None => Message::NoSuchCube { cube: &name }.text(locale),
+                Some(0) => Message::AlreadyInCube { card: card.name(), cube: &name }.text(locale),
+                Some(_) => Message::AddedToCube { card: card.name(), cube: &name }.text(locale),
+            },
+        };
+
+        if let Err(why) = interaction.reply(message).await {
+            log::warn!("couldn't create interaction response: {why}");
+        }
+    }
+
+    pub async fn cube_import<I: CubeInteraction>(&self, interaction: &I, options: CubeImport) {
+        if !allowed(interaction).await {
+            return;
+        }
+
+        let name = normalise_name(&options.name);
+        let lines = card_list::parse(&options.list);
+        if let Err(denied) = self.rate_limit_lines(interaction.requester(), lines.len()).await {
+            if let Err(why) = interaction
+                .reply(denied.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        }
+
+        let resolved = self.resolve_card_list(lines).await;
+
+        let mut card_ids: Vec<Uuid> = resolved
+            .cards()
+            .iter()
+            .map(|listed| *listed.card().id())
+            .collect();
+        card_ids.sort_unstable();
+        card_ids.dedup();
+
+        let locale = interaction.locale();
+        let mut lines = vec![if card_ids.is_empty() {
+            Message::NoCardsInList.text(locale)
+        } else {
+            match self.card_store.add_to_cube(options.guild_id, &name, &card_ids).await {
+                None => Message::NoSuchCube { cube: &name }.text(locale),
+                Some(added) => Message::CubeImported {
+                    cube: &name,
+                    added,
+                    already: card_ids.len().saturating_sub(added),
+                }
+                .text(locale),
+            }
+        }];
+
+        let unresolved = resolved.unresolved();
+        if !unresolved.is_empty() {
+            lines.push(Message::Unresolved.text(locale));
+            lines.extend(
+                unresolved
+                    .iter()
+                    .take(UNRESOLVED_SHOWN)
+                    .map(|line| format!("- `{line}`")),
+            );
+            if unresolved.len() > UNRESOLVED_SHOWN {
+                lines.push(
+                    Message::AndMore {
+                        count: unresolved.len() - UNRESOLVED_SHOWN,
+                    }
+                    .text(locale),
+                );
+            }
+        }
+
+        if let Err(why) = interaction.reply(lines.join("\n")).await {
+            log::warn!("couldn't create interaction response: {why}");
+        }
+    }
+}
+
+/// Turns away anyone without Manage Server, telling them why.
+async fn allowed<I: CubeInteraction>(interaction: &I) -> bool {
+    if interaction.can_manage_guild() {
+        return true;
+    }
+
+    if let Err(why) = interaction
+        .reply(Message::CubeNotAllowed.text(interaction.locale()))
+        .await
+    {
+        log::warn!("couldn't create interaction response: {why}");
+    }
+    false
+}
+
+pub struct CubeOptions {
+    guild_id: GuildId,
+    name: String,
+}
+
+impl CubeOptions {
+    pub fn new(guild_id: GuildId, name: String) -> Self {
+        Self { guild_id, name }
+    }
+}
+
+pub struct CubeAdd {
+    guild_id: GuildId,
+    name: String,
+    card: String,
+}
+
+impl CubeAdd {
+    pub fn new(guild_id: GuildId, name: String, card: String) -> Self {
+        Self {
+            guild_id,
+            name,
+            card,
+        }
+    }
+}
+
+/// A pasted list to add to a cube, one card per line.
+pub struct CubeImport {
+    guild_id: GuildId,
+    name: String,
+    list: String,
+}
+
+impl CubeImport {
+    pub fn new(guild_id: GuildId, name: String, list: String) -> Self {
+        Self {
+            guild_id,
+            name,
+            list,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::domain::rate_limit::{expect_unlimited, Requester};
+    use crate::ports::drivers::client::MockCubeInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use cards_sdk::MockCubeRepository;
+    use contracts::card::Card;
+    use mockall::predicate::*;
+    use uuid::uuid;
+
+    const GUILD: u64 = 42;
+    const BOLT: Uuid = uuid!("00000000-0000-0000-0000-000000000001");
+
+    fn bolt() -> Card {
+        Card::new(
+            BOLT,
+            "Lightning Bolt".to_string(),
+            "lightning bolt".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            "Lightning Bolt deals 3 damage to any target.".to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn expect_reply(message: &str) -> MockCubeInteraction {
+        let mut interaction = MockCubeInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_can_manage_guild().return_const(true);
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(message.to_string()))
+            .returning(|_| Ok(()));
+        interaction
+    }
+
+    fn app(
+        card_store: MockCardStore,
+        cube: MockCubeRepository,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::with_cube(card_store, cube),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    fn guild() -> GuildId {
+        GuildId::from(GUILD)
+    }
+
+    #[tokio::test]
+    async fn test_create_lowercases_name() {
+        let mut cube = MockCubeRepository::new();
+        cube.expect_create_cube()
+            .times(1)
+            .with(eq(GuildId::from(GUILD)), eq("vintage"))
+            .return_const(Some(true));
+
+        app(MockCardStore::new(), cube)
+            .cube_create(
+                &expect_reply("Created cube 'vintage'"),
+                CubeOptions::new(guild(), String::from(" Vintage ")),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_create_existing() {
+        let mut cube = MockCubeRepository::new();
+        cube.expect_create_cube().times(1).return_const(Some(false));
+
+        app(MockCardStore::new(), cube)
+            .cube_create(
+                &expect_reply("This server already has a cube called 'vintage'"),
+                CubeOptions::new(guild(), String::from("vintage")),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_add_resolves_card() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search()
+            .times(1)
+            .with(eq("lightning bolt"))
+            .return_const(Some(vec![bolt()]));
+
+        let mut cube = MockCubeRepository::new();
+        cube.expect_add_to_cube()
+            .times(1)
+            .withf(|guild_id, name, card_ids| {
+                *guild_id == GuildId::from(GUILD) && name == "vintage" && card_ids == [BOLT]
+            })
+            .return_const(Some(1usize));
+
+        app(card_store, cube)
+            .cube_add(
+                &expect_reply("Added Lightning Bolt to 'vintage'"),
+                CubeAdd::new(
+                    guild(),
+                    String::from("vintage"),
+                    String::from("Lightning Bolt"),
+                ),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_add_to_missing_cube() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search()
+            .times(1)
+            .return_const(Some(vec![bolt()]));
+
+        let mut cube = MockCubeRepository::new();
+        cube.expect_add_to_cube().times(1).return_const(None);
+
+        app(card_store, cube)
+            .cube_add(
+                &expect_reply(
+                    "This server has no cube called 'vintage' — create it with `/cube create`",
+                ),
+                CubeAdd::new(
+                    guild(),
+                    String::from("vintage"),
+                    String::from("Lightning Bolt"),
+                ),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_unresolved_lines() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search()
+            .with(eq("lightning bolt"))
+            .return_const(Some(vec![bolt()]));
+        card_store
+            .expect_search()
+            .with(eq("not a card"))
+            .return_const(Some(Vec::new()));
+
+        let mut cube = MockCubeRepository::new();
+        cube.expect_add_to_cube()
+            .times(1)
+            .withf(|_, _, card_ids| card_ids == [BOLT])
+            .return_const(Some(0usize));
+
+        app(card_store, cube)
+            .cube_import(&expect_reply(
+                    "Added 0 new cards to 'vintage' (1 already in it)\nCouldn't find:\n- `1 Not a card`",
+                ), CubeImport::new(guild(), String::from("vintage"), String::from("1 Lightning Bolt\n1 Lightning Bolt\n1 Not a card")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_needs_manage_server() {
+        let mut interaction = MockCubeInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_can_manage_guild().return_const(false);
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(Message::CubeNotAllowed.text(Locale::English)))
+            .returning(|_| Ok(()));
+
+        let mut cube = MockCubeRepository::new();
+        cube.expect_create_cube().times(0);
+
+        app(MockCardStore::new(), cube)
+            .cube_create(
+                &interaction,
+                CubeOptions::new(guild(), String::from("vintage")),
+            )
+            .await;
+    }
+}
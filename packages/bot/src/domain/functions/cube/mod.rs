@@ -0,0 +1,7 @@
+pub(crate) mod draft;
+pub(crate) mod manage;
+
+/// Cube names are matched case-insensitively by storing them lowercased.
+fn normalise_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
@@ -0,0 +1,529 @@
+use crate::domain::card_list::{self, ResolvedCardList};
+use crate::domain::functions::deck::commander::CommanderReport;
+use crate::domain::functions::deck::report::DeckReport;
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::DeckInteraction;
+use contracts::card_details::CardDetails;
+use contracts::priced_card::Currency;
+use uuid::Uuid;
+
+impl_app! {
+    /// Resolves a pasted list and looks up its details, replying with why
+    /// not if either step comes up empty.
+    async fn resolve_deck<I: DeckInteraction>(
+        &self,
+        interaction: &I,
+        list: &str,
+        currency: Currency,
+    ) -> Option<(ResolvedCardList, Vec<CardDetails>)> {
+        let lines = card_list::parse(list);
+        if let Err(denied) = self.rate_limit_lines(interaction.requester(), lines.len()).await {
+            if let Err(why) = interaction
+                .reply(denied.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return None;
+        }
+
+        let resolved = self.resolve_card_list(lines).await;
+        if resolved.cards().is_empty() {
+            if let Err(why) = interaction
+                .reply(Message::NoCardsInList.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return None;
+        }
+
+        let mut card_ids: Vec<Uuid> = resolved
+            .cards()
+            .iter()
+            .map(|listed| *listed.card().id())
+            .collect();
+        card_ids.sort_unstable();
+        card_ids.dedup();
+
+        let Some(details) = self.card_store.card_details(&card_ids, currency).await else {
+            if let Err(why) = interaction
+                .reply(Message::DeckUnavailable.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return None;
+        };
+
+        Some((resolved, details))
+    }
+
+    pub async fn deck_command<I: DeckInteraction>(&self, interaction: &I, options: DeckOptions) {
+        let Some((resolved, details)) = self
+            .resolve_deck(interaction, &options.list, options.currency)
+            .await
+        else {
+            return;
+        };
+
+        let report = DeckReport::new(&resolved, &details, options.currency);
+        if let Err(why) = interaction.send_report(report).await {
+            log::warn!("couldn't send deck report: {why}");
+        }
+    }
+
+    pub async fn commander_command<I: DeckInteraction>(&self, interaction: &I, list: String) {
+        let Some((resolved, details)) = self
+            .resolve_deck(interaction, &list, Currency::default())
+            .await
+        else {
+            return;
+        };
+
+        let Some(report) = CommanderReport::new(&resolved, &details) else {
+            if let Err(why) = interaction
+                .reply(Message::CommanderNotFound.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+
+        if let Err(why) = interaction.send_commander_report(report).await {
+            log::warn!("couldn't send commander report: {why}");
+        }
+    }
+}
+
+/// A pasted deck list and which prices to total it in.
+pub struct DeckOptions {
+    list: String,
+    currency: Currency,
+}
+
+impl DeckOptions {
+    pub fn new(list: String, currency: Currency) -> Self {
+        Self { list, currency }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::domain::rate_limit::{expect_unlimited, Requester};
+    use crate::ports::drivers::client::MockDeckInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::card::Card;
+    use contracts::card_details::{Format, Legality};
+    use mockall::predicate::*;
+    use uuid::uuid;
+
+    const BOLT: Uuid = uuid!("00000000-0000-0000-0000-000000000001");
+    const LOTUS: Uuid = uuid!("00000000-0000-0000-0000-000000000002");
+    const MOUNTAIN: Uuid = uuid!("00000000-0000-0000-0000-000000000003");
+    const KRENKO: Uuid = uuid!("00000000-0000-0000-0000-000000000004");
+    const COUNTERSPELL: Uuid = uuid!("00000000-0000-0000-0000-000000000005");
+
+    fn card(id: Uuid, name: &str, type_line: &str) -> Card {
+        card_with_identity(id, name, type_line, &[])
+    }
+
+    fn card_with_identity(id: Uuid, name: &str, type_line: &str, identity: &[&str]) -> Card {
+        Card::new(
+            id,
+            name.to_string(),
+            name.to_lowercase(),
+            id,
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            String::new(),
+            identity.iter().map(ToString::to_string).collect(),
+            None,
+            None,
+            None,
+            None,
+            type_line.to_string(),
+            String::new(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn details() -> Vec<CardDetails> {
+        vec![
+            CardDetails::new(
+                BOLT,
+                1.0,
+                vec![String::from("R")],
+                Some(1.5),
+                vec![
+                    (Format::Modern, Legality::Legal),
+                    (Format::Vintage, Legality::Legal),
+                ],
+                false,
+            ),
+            CardDetails::new(
+                LOTUS,
+                0.0,
+                Vec::new(),
+                None,
+                vec![
+                    (Format::Modern, Legality::Banned),
+                    (Format::Vintage, Legality::Restricted),
+                ],
+                true,
+            ),
+            CardDetails::new(
+                MOUNTAIN,
+                0.0,
+                Vec::new(),
+                Some(0.1),
+                vec![
+                    (Format::Modern, Legality::Legal),
+                    (Format::Vintage, Legality::Legal),
+                ],
+                false,
+            ),
+        ]
+    }
+
+    fn card_store() -> MockCardStore {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search()
+            .with(eq("lightning bolt"))
+            .return_const(Some(vec![card(BOLT, "Lightning Bolt", "Instant")]));
+        card_store
+            .expect_search()
+            .with(eq("black lotus"))
+            .return_const(Some(vec![card(LOTUS, "Black Lotus", "Artifact")]));
+        card_store
+            .expect_search()
+            .with(eq("mountain"))
+            .return_const(Some(vec![card(
+                MOUNTAIN,
+                "Mountain",
+                "Basic Land — Mountain",
+            )]));
+        card_store
+            .expect_search()
+            .with(eq("not a card"))
+            .return_const(Some(Vec::new()));
+        card_store
+    }
+
+    fn app(
+        card_store: MockCardStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_reports_a_deck() {
+        let mut card_store = card_store();
+        card_store
+            .expect_card_details()
+            .times(1)
+            .withf(|ids, currency| ids == [BOLT, LOTUS, MOUNTAIN] && *currency == Currency::Eur)
+            .return_const(Some(details()));
+
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_report()
+            .times(1)
+            .withf(|report: &DeckReport| {
+                let modern = report
+                    .formats()
+                    .iter()
+                    .find(|check| check.format() == Format::Modern)
+                    .unwrap();
+                let vintage = report
+                    .formats()
+                    .iter()
+                    .find(|check| check.format() == Format::Vintage)
+                    .unwrap();
+
+                report.main() == 25
+                    && report.sideboard() == 1
+                    && report.curve()[0] == 1
+                    && report.curve()[1] == 4
+                    && report.types() == [("Land", 20), ("Instant", 4), ("Artifact", 1)]
+                    && report.colours() == [("R", 4), ("C", 1)]
+                    && report.formatted_price() == "€8.00"
+                    && report.unpriced() == ["Black Lotus"]
+                    && report.unresolved() == ["1 Not a card"]
+                    && modern.violations().len() == 1
+                    && modern.violations()[0].legality() == Legality::Banned
+                    && vintage.violations()[0].copies() == 2
+                    && !report
+                        .formats()
+                        .iter()
+                        .find(|check| check.format() == Format::Standard)
+                        .unwrap()
+                        .is_legal()
+            })
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .deck_command(
+                &interaction,
+                DeckOptions::new(
+                    String::from(
+                        "4 Lightning Bolt\n1 Black Lotus\n20 Mountain\n1 Not a card\n\n1 Black Lotus",
+                    ),
+                    Currency::Eur,
+                ),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_nothing_resolves() {
+        let mut card_store = card_store();
+        card_store.expect_card_details().times(0);
+
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_report().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Couldn't find any cards in that list")))
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .deck_command(
+                &interaction,
+                DeckOptions::new(String::from("1 Not a card"), Currency::Usd),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_throttled_list_is_not_looked_up() {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_search().times(0);
+
+        // An empty bucket, last touched as far in the future as can be, so
+        // it won't have refilled by the time it's read.
+        let mut cache = MockCache::new();
+        cache.expect_get().return_const(Some(String::from(
+            "(tokens:0.0,updated:18446744073709551615,warned:true)",
+        )));
+        cache.expect_set().returning(|_, _| Ok(()));
+
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(Message::RateLimited.text(Locale::English)))
+            .returning(|_| Ok(()));
+
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+        .deck_command(
+            &interaction,
+            DeckOptions::new(String::from("4 Lightning Bolt"), Currency::Usd),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_details_unavailable() {
+        let mut card_store = card_store();
+        card_store.expect_card_details().times(1).return_const(None);
+
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_report().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't look up that deck right now, try again later",
+            )))
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .deck_command(
+                &interaction,
+                DeckOptions::new(String::from("4 Lightning Bolt"), Currency::Usd),
+            )
+            .await;
+    }
+
+    fn commander_store() -> MockCardStore {
+        let mut card_store = card_store();
+        card_store
+            .expect_search()
+            .with(eq("krenko mob boss"))
+            .return_const(Some(vec![card_with_identity(
+                KRENKO,
+                "Krenko, Mob Boss",
+                "Legendary Creature — Goblin Warrior",
+                &["R"],
+            )]));
+        card_store
+            .expect_search()
+            .with(eq("counterspell"))
+            .return_const(Some(vec![card_with_identity(
+                COUNTERSPELL,
+                "Counterspell",
+                "Instant",
+                &["U"],
+            )]));
+
+        let commander_legal = |id, game_changer| {
+            CardDetails::new(
+                id,
+                1.0,
+                Vec::new(),
+                None,
+                vec![(Format::Commander, Legality::Legal)],
+                game_changer,
+            )
+        };
+        card_store.expect_card_details().return_const(Some(vec![
+            commander_legal(KRENKO, false),
+            commander_legal(BOLT, true),
+            commander_legal(MOUNTAIN, false),
+            commander_legal(COUNTERSPELL, false),
+            CardDetails::new(
+                LOTUS,
+                0.0,
+                Vec::new(),
+                None,
+                vec![(Format::Commander, Legality::Banned)],
+                true,
+            ),
+        ]));
+        card_store
+    }
+
+    #[tokio::test]
+    async fn test_legal_commander_deck() {
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_commander_report()
+            .times(1)
+            .withf(|report: &CommanderReport| {
+                report.is_legal()
+                    && report.commanders() == ["Krenko, Mob Boss"]
+                    && report.identity() == ["R"]
+                    && report.game_changers() == ["Lightning Bolt"]
+                    && report.bracket().name() == "3"
+            })
+            .returning(|_| Ok(()));
+
+        app(commander_store())
+            .commander_command(
+                &interaction,
+                String::from(
+                    "Commander\n1 Krenko, Mob Boss\n\nDeck\n1 Lightning Bolt\n98 Mountain",
+                ),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_commander_violations() {
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_commander_report()
+            .times(1)
+            .withf(|report: &CommanderReport| {
+                !report.is_legal()
+                    && report.total() == 95
+                    && report.off_identity() == ["Counterspell"]
+                    && report.duplicates() == [(String::from("Lightning Bolt"), 2)]
+                    && report.banned() == [String::from("Black Lotus")]
+                    && report.not_legal().is_empty()
+                    && report.bracket().name() == "3"
+            })
+            .returning(|_| Ok(()));
+
+        // No headings: the one-card sideboard after the blank line is the
+        // commander.
+        app(commander_store())
+            .commander_command(
+                &interaction,
+                String::from(
+                    "2 Lightning Bolt\n1 Counterspell\n1 Black Lotus\n90 Mountain\n\n1 Krenko, Mob Boss",
+                ),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_commander_not_found() {
+        let mut interaction = MockDeckInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_commander_report().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't tell which card is your commander — put it under a `Commander` heading",
+            )))
+            .returning(|_| Ok(()));
+
+        app(commander_store())
+            .commander_command(&interaction, String::from("1 Lightning Bolt\n99 Mountain"))
+            .await;
+    }
+}
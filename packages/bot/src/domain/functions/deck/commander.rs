@@ -0,0 +1,264 @@
+use crate::domain::card_list::{ListedCard, ResolvedCardList, Section};
+use contracts::card::Card;
+use contracts::card_details::{CardDetails, Format, Legality};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub const DECK_SIZE: u32 = 100;
+/// Partners and backgrounds let a deck have a second commander.
+const MAX_COMMANDERS: u32 = 2;
+
+/// The lowest bracket a deck's game changers allow: none keeps it in 1–2,
+/// up to three is bracket 3, and any more is bracket 4 or higher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bracket {
+    Core,
+    Upgraded,
+    Optimised,
+}
+
+impl Bracket {
+    fn from_game_changers(count: usize) -> Self {
+        match count {
+            0 => Bracket::Core,
+            1..=3 => Bracket::Upgraded,
+            _ => Bracket::Optimised,
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Bracket::Core => "1–2",
+            Bracket::Upgraded => "3",
+            Bracket::Optimised => "4+",
+        }
+    }
+}
+
+/// A Commander-specific check of a deck: size, colour identity, singleton
+/// and the banned list, plus a bracket estimate from its game changers.
+#[derive(Debug)]
+pub struct CommanderReport {
+    commanders: Vec<String>,
+    identity: Vec<String>,
+    total: u32,
+    not_commanders: Vec<String>,
+    off_identity: Vec<String>,
+    duplicates: Vec<(String, u32)>,
+    banned: Vec<String>,
+    not_legal: Vec<String>,
+    game_changers: Vec<String>,
+    unresolved: Vec<String>,
+}
+
+impl CommanderReport {
+    /// `None` if there's no telling which card is the commander. Without a
+    /// `Commander` heading, a one- or two-card sideboard is taken to be the
+    /// commanders, as MTGO-style exports put them there.
+    #[must_use]
+    pub fn new(list: &ResolvedCardList, details: &[CardDetails]) -> Option<Self> {
+        let in_section = |section: Section| -> Vec<&ListedCard> {
+            list.cards()
+                .iter()
+                .filter(|listed| listed.section() == section)
+                .collect()
+        };
+        let (commanders, deck) = match in_section(Section::Commander) {
+            commanders if !commanders.is_empty() => (commanders, in_section(Section::Main)),
+            _ => {
+                let sideboard = in_section(Section::Sideboard);
+                let count = sideboard
+                    .iter()
+                    .map(|listed| listed.count())
+                    .fold(0, u32::saturating_add);
+                if !(1..=MAX_COMMANDERS).contains(&count) {
+                    return None;
+                }
+                (sideboard, in_section(Section::Main))
+            }
+        };
+
+        let details: HashMap<&Uuid, &CardDetails> = details
+            .iter()
+            .map(|card_details| (card_details.id(), card_details))
+            .collect();
+
+        let mut identity: Vec<String> = Vec::new();
+        for listed in &commanders {
+            for colour in listed.card().colour_identity() {
+                if !identity.contains(colour) {
+                    identity.push(colour.clone());
+                }
+            }
+        }
+        identity.sort_by_key(|colour| colour_order(colour));
+
+        let mut report = Self {
+            commanders: commanders
+                .iter()
+                .map(|listed| listed.card().name().to_string())
+                .collect(),
+            total: commanders
+                .iter()
+                .chain(&deck)
+                .map(|listed| listed.count())
+                .fold(0, u32::saturating_add),
+            not_commanders: commanders
+                .iter()
+                .map(|listed| listed.card())
+                .filter(|card| !can_be_commander(card))
+                .map(|card| card.name().to_string())
+                .collect(),
+            identity,
+            off_identity: Vec::new(),
+            duplicates: Vec::new(),
+            banned: Vec::new(),
+            not_legal: Vec::new(),
+            game_changers: Vec::new(),
+            unresolved: list.unresolved().to_vec(),
+        };
+
+        let mut copies: Vec<(&Card, u32)> = Vec::new();
+        for listed in commanders.iter().chain(&deck) {
+            let card = listed.card();
+            match copies
+                .iter_mut()
+                .find(|(seen, _)| seen.oracle_id() == card.oracle_id())
+            {
+                Some((_, count)) => *count = count.saturating_add(listed.count()),
+                None => copies.push((card, listed.count())),
+            }
+        }
+
+        for (card, count) in copies {
+            let name = card.name().to_string();
+            if !card
+                .colour_identity()
+                .iter()
+                .all(|colour| report.identity.contains(colour))
+            {
+                report.off_identity.push(name.clone());
+            }
+            if copy_limit(card).is_some_and(|limit| count > limit) {
+                report.duplicates.push((name.clone(), count));
+            }
+            let Some(card_details) = details.get(card.id()) else {
+                continue;
+            };
+            match card_details.legality(Format::Commander) {
+                Legality::Legal | Legality::Restricted => {}
+                Legality::Banned => report.banned.push(name.clone()),
+                Legality::NotLegal => report.not_legal.push(name.clone()),
+            }
+            if card_details.game_changer() {
+                report.game_changers.push(name);
+            }
+        }
+
+        Some(report)
+    }
+
+    #[must_use]
+    pub fn commanders(&self) -> &[String] {
+        &self.commanders
+    }
+
+    /// The commanders' combined colour identity, in WUBRG order.
+    #[must_use]
+    pub fn identity(&self) -> &[String] {
+        &self.identity
+    }
+
+    /// Cards in the deck, commanders included.
+    #[must_use]
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// Commanders that are neither legendary creatures nor say they can be
+    /// one.
+    #[must_use]
+    pub fn not_commanders(&self) -> &[String] {
+        &self.not_commanders
+    }
+
+    #[must_use]
+    pub fn off_identity(&self) -> &[String] {
+        &self.off_identity
+    }
+
+    /// Cards over their copy limit, with how many copies there are.
+    #[must_use]
+    pub fn duplicates(&self) -> &[(String, u32)] {
+        &self.duplicates
+    }
+
+    /// Cards on the Commander banned list.
+    #[must_use]
+    pub fn banned(&self) -> &[String] {
+        &self.banned
+    }
+
+    /// Cards that were never legal in Commander to begin with, like
+    /// un-cards and test cards.
+    #[must_use]
+    pub fn not_legal(&self) -> &[String] {
+        &self.not_legal
+    }
+
+    #[must_use]
+    pub fn game_changers(&self) -> &[String] {
+        &self.game_changers
+    }
+
+    #[must_use]
+    pub fn bracket(&self) -> Bracket {
+        Bracket::from_game_changers(self.game_changers.len())
+    }
+
+    #[must_use]
+    pub fn unresolved(&self) -> &[String] {
+        &self.unresolved
+    }
+
+    #[must_use]
+    pub fn is_legal(&self) -> bool {
+        self.total == DECK_SIZE
+            && self.not_commanders.is_empty()
+            && self.off_identity.is_empty()
+            && self.duplicates.is_empty()
+            && self.banned.is_empty()
+            && self.not_legal.is_empty()
+    }
+}
+
+fn colour_order(colour: &str) -> usize {
+    ["W", "U", "B", "R", "G"]
+        .iter()
+        .position(|symbol| *symbol == colour)
+        .unwrap_or(usize::MAX)
+}
+
+fn can_be_commander(card: &Card) -> bool {
+    let front = card.type_line().split(" // ").next().unwrap_or_default();
+    (front.contains("Legendary") && front.contains("Creature"))
+        || card.oracle_text().contains("can be your commander")
+}
+
+/// How many copies the singleton rule allows, or `None` for no limit —
+/// basic lands and cards like Relentless Rats that say so themselves.
+fn copy_limit(card: &Card) -> Option<u32> {
+    let text = card.oracle_text();
+    if card.type_line().starts_with("Basic")
+        || text.contains("A deck can have any number of cards named")
+    {
+        None
+    } else if text.contains("A deck can have up to seven cards named") {
+        Some(7)
+    } else if text.contains("A deck can have up to nine cards named") {
+        Some(9)
+    } else {
+        Some(1)
+    }
+}
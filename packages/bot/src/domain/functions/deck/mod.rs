@@ -0,0 +1,3 @@
+pub(crate) mod analyse;
+pub mod commander;
+pub mod report;
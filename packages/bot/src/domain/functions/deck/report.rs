@@ -0,0 +1,297 @@
+use crate::domain::card_list::{ListedCard, ResolvedCardList, Section};
+use contracts::card_details::{CardDetails, Format, Legality};
+use contracts::priced_card::Currency;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// The formats a deck is checked against.
+pub const REPORTED_FORMATS: [Format; 7] = [
+    Format::Standard,
+    Format::Pioneer,
+    Format::Modern,
+    Format::Legacy,
+    Format::Vintage,
+    Format::Pauper,
+    Format::Commander,
+];
+
+/// Mana values 0 to 6 get a bar each and everything above shares the last.
+pub const CURVE_SIZE: usize = 8;
+const CURVE_STEPS: [f64; CURVE_SIZE] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+
+/// Checked in this order, so an artifact creature counts as a creature and
+/// Dryad Arbor as a land.
+const TYPES: [&str; 8] = [
+    "Land",
+    "Creature",
+    "Planeswalker",
+    "Battle",
+    "Instant",
+    "Sorcery",
+    "Artifact",
+    "Enchantment",
+];
+const OTHER_TYPE: &str = "Other";
+
+const COLOURS: [&str; 5] = ["W", "U", "B", "R", "G"];
+const COLOURLESS: &str = "C";
+
+/// A card that keeps a deck out of a format.
+#[derive(Debug)]
+pub struct Violation {
+    name: String,
+    legality: Legality,
+    copies: u32,
+}
+
+impl Violation {
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn legality(&self) -> Legality {
+        self.legality
+    }
+
+    #[must_use]
+    pub fn copies(&self) -> u32 {
+        self.copies
+    }
+}
+
+/// Whether every card in a deck, sideboard included, is allowed in one
+/// format.
+#[derive(Debug)]
+pub struct FormatCheck {
+    format: Format,
+    violations: Vec<Violation>,
+}
+
+impl FormatCheck {
+    /// Copies are totalled per card across sections and printings, so four
+    /// of a restricted card split between main and side still count.
+    fn new(format: Format, cards: &[ListedCard], details: &HashMap<&Uuid, &CardDetails>) -> Self {
+        let mut copies: Vec<(&Uuid, &str, u32, Legality)> = Vec::new();
+        for listed in cards {
+            let Some(card_details) = details.get(listed.card().id()) else {
+                continue;
+            };
+            let oracle_id = listed.card().oracle_id();
+            match copies.iter_mut().find(|(id, ..)| *id == oracle_id) {
+                Some((_, _, count, _)) => *count = count.saturating_add(listed.count()),
+                None => copies.push((
+                    oracle_id,
+                    listed.card().name(),
+                    listed.count(),
+                    card_details.legality(format),
+                )),
+            }
+        }
+
+        let violations = copies
+            .into_iter()
+            .filter(|(_, _, count, legality)| match legality {
+                Legality::Legal => false,
+                Legality::Restricted => *count > 1,
+                Legality::NotLegal | Legality::Banned => true,
+            })
+            .map(|(_, name, copies, legality)| Violation {
+                name: name.to_string(),
+                legality,
+                copies,
+            })
+            .collect();
+
+        Self { format, violations }
+    }
+
+    #[must_use]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    #[must_use]
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    #[must_use]
+    pub fn is_legal(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Everything `/deck` reports about a list. The curve, colours and types
+/// cover the main deck and commander; price and legality include the
+/// sideboard too.
+#[derive(Debug)]
+pub struct DeckReport {
+    main: u32,
+    sideboard: u32,
+    commander: u32,
+    curve: [u32; CURVE_SIZE],
+    colours: Vec<(&'static str, u32)>,
+    types: Vec<(&'static str, u32)>,
+    price: f64,
+    currency: Currency,
+    unpriced: Vec<String>,
+    formats: Vec<FormatCheck>,
+    unresolved: Vec<String>,
+}
+
+impl DeckReport {
+    #[must_use]
+    pub fn new(list: &ResolvedCardList, details: &[CardDetails], currency: Currency) -> Self {
+        let details: HashMap<&Uuid, &CardDetails> = details
+            .iter()
+            .map(|card_details| (card_details.id(), card_details))
+            .collect();
+
+        let mut report = Self {
+            main: 0,
+            sideboard: 0,
+            commander: 0,
+            curve: [0; CURVE_SIZE],
+            colours: Vec::new(),
+            types: Vec::new(),
+            price: 0.0,
+            currency,
+            unpriced: Vec::new(),
+            formats: Vec::new(),
+            unresolved: list.unresolved().to_vec(),
+        };
+        let mut colours = [0u32; COLOURS.len() + 1];
+        let mut types = [0u32; TYPES.len() + 1];
+
+        for listed in list.cards() {
+            let count = listed.count();
+            match listed.section() {
+                Section::Main => report.main = report.main.saturating_add(count),
+                Section::Sideboard => report.sideboard = report.sideboard.saturating_add(count),
+                Section::Commander => report.commander = report.commander.saturating_add(count),
+            }
+
+            let card = listed.card();
+            let Some(card_details) = details.get(card.id()) else {
+                continue;
+            };
+            match card_details.price() {
+                Some(price) => report.price += price * f64::from(count),
+                None => {
+                    if !report.unpriced.iter().any(|name| name == card.name()) {
+                        report.unpriced.push(card.name().to_string());
+                    }
+                }
+            }
+
+            if listed.section() == Section::Sideboard {
+                continue;
+            }
+
+            let type_index = type_index(card.type_line());
+            types[type_index] = types[type_index].saturating_add(count);
+            if TYPES.get(type_index) == Some(&"Land") {
+                continue;
+            }
+
+            let step = CURVE_STEPS
+                .iter()
+                .rposition(|step| card_details.cmc() >= *step)
+                .unwrap_or_default();
+            report.curve[step] = report.curve[step].saturating_add(count);
+
+            if card_details.colours().is_empty() {
+                colours[COLOURS.len()] = colours[COLOURS.len()].saturating_add(count);
+            }
+            for colour in card_details.colours() {
+                if let Some(index) = COLOURS.iter().position(|symbol| symbol == colour) {
+                    colours[index] = colours[index].saturating_add(count);
+                }
+            }
+        }
+
+        report.colours = COLOURS
+            .into_iter()
+            .chain([COLOURLESS])
+            .zip(colours)
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        report.types = TYPES
+            .into_iter()
+            .chain([OTHER_TYPE])
+            .zip(types)
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        report.formats = REPORTED_FORMATS
+            .into_iter()
+            .map(|format| FormatCheck::new(format, list.cards(), &details))
+            .collect();
+
+        report
+    }
+
+    #[must_use]
+    pub fn main(&self) -> u32 {
+        self.main
+    }
+
+    #[must_use]
+    pub fn sideboard(&self) -> u32 {
+        self.sideboard
+    }
+
+    #[must_use]
+    pub fn commander(&self) -> u32 {
+        self.commander
+    }
+
+    /// Copies at each mana value, lands excluded; the last entry is 7+.
+    #[must_use]
+    pub fn curve(&self) -> &[u32; CURVE_SIZE] {
+        &self.curve
+    }
+
+    /// Copies of nonland cards per colour symbol, `C` for colourless. A
+    /// multicoloured card counts towards each of its colours.
+    #[must_use]
+    pub fn colours(&self) -> &[(&'static str, u32)] {
+        &self.colours
+    }
+
+    #[must_use]
+    pub fn types(&self) -> &[(&'static str, u32)] {
+        &self.types
+    }
+
+    #[must_use]
+    pub fn formatted_price(&self) -> String {
+        self.currency.format(self.price)
+    }
+
+    /// Cards with no price in the chosen currency, left out of the total.
+    #[must_use]
+    pub fn unpriced(&self) -> &[String] {
+        &self.unpriced
+    }
+
+    #[must_use]
+    pub fn formats(&self) -> &[FormatCheck] {
+        &self.formats
+    }
+
+    #[must_use]
+    pub fn unresolved(&self) -> &[String] {
+        &self.unresolved
+    }
+}
+
+fn type_index(type_line: &str) -> usize {
+    let front = type_line.split(" // ").next().unwrap_or(type_line);
+    let types = front.split(" — ").next().unwrap_or(front);
+    TYPES
+        .iter()
+        .position(|card_type| types.split_whitespace().any(|word| word == *card_type))
+        .unwrap_or(TYPES.len())
+}
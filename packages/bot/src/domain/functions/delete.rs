@@ -0,0 +1,129 @@
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::DeleteInteraction;
+
+impl_app! {
+    /// Deletes a reply for whoever asked for it, or for a moderator.
+    pub async fn delete_reply<I: DeleteInteraction>(&self, interaction: &I, requester: u64) {
+        if interaction.user_id() != requester && !interaction.can_manage_messages() {
+            if let Err(why) = interaction
+                .reply_ephemeral(Message::DeleteNotAllowed.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction: {why}");
+            }
+            return;
+        }
+
+        if let Err(why) = interaction.delete_reply().await {
+            log::warn!("couldn't delete reply: {why}");
+            return;
+        }
+
+        if let Some(source) = interaction.source() {
+            self.forget_inline_reply(&source, interaction.reply_id()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockDeleteInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use mockall::predicate::*;
+
+    fn app(
+        cache: MockCache,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(MockCardStore::new()),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_requester_can_delete() {
+        let mut interaction = MockDeleteInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_user_id().return_const(1_u64);
+        interaction.expect_can_manage_messages().return_const(false);
+        interaction
+            .expect_delete_reply()
+            .times(1)
+            .returning(|| Ok(()));
+        interaction.expect_reply_ephemeral().times(0);
+        interaction.expect_source().return_const(None);
+
+        app(MockCache::new()).delete_reply(&interaction, 1).await;
+    }
+
+    #[tokio::test]
+    async fn test_moderator_can_delete() {
+        let mut interaction = MockDeleteInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_user_id().return_const(2_u64);
+        interaction.expect_can_manage_messages().return_const(true);
+        interaction
+            .expect_delete_reply()
+            .times(1)
+            .returning(|| Ok(()));
+        interaction.expect_source().return_const(None);
+
+        app(MockCache::new()).delete_reply(&interaction, 1).await;
+    }
+
+    #[tokio::test]
+    async fn test_anyone_else_is_turned_away() {
+        let mut interaction = MockDeleteInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_user_id().return_const(2_u64);
+        interaction.expect_can_manage_messages().return_const(false);
+        interaction.expect_delete_reply().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        app(MockCache::new()).delete_reply(&interaction, 1).await;
+    }
+
+    #[tokio::test]
+    async fn test_deleted_inline_reply_is_forgotten() {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .with(eq(String::from("inline-replies:1:2")))
+            .return_const(Some(String::from(
+                r#"(content:"[[bolt]]",replies:[10,11])"#,
+            )));
+        cache
+            .expect_set()
+            .times(1)
+            .with(
+                eq(String::from("inline-replies:1:2")),
+                eq(String::from(r#"(content:"[[bolt]]",replies:[11])"#)),
+            )
+            .returning(|_, _| Ok(()));
+
+        let mut interaction = MockDeleteInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_user_id().return_const(1_u64);
+        interaction
+            .expect_delete_reply()
+            .times(1)
+            .returning(|| Ok(()));
+        interaction
+            .expect_source()
+            .return_const(Some(String::from("1:2")));
+        interaction.expect_reply_id().return_const(10_u64);
+
+        app(cache).delete_reply(&interaction, 1).await;
+    }
+}
@@ -1,7 +1,10 @@
 use crate::domain::functions::game::state;
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
 use crate::domain::utils::normalise_card_name;
 use crate::impl_app;
 use crate::ports::drivers::client::GameInteraction;
+use cards_sdk::{Game, UserId};
 use fuzzy;
 use named_lock;
 
@@ -18,7 +21,7 @@ impl_app! {
 
         let Some(mut game_state) = state::fetch(interaction.id(), &self.cache).await else {
             if let Err(why) = interaction
-                .reply(String::from("No game found in this channel :("))
+                .reply(Message::NoGameFound.text(interaction.locale()))
                 .await
             {
                 log::warn!("couldn't create interaction: {why}");
@@ -34,9 +37,14 @@ impl_app! {
         {
             let Ok(images) = self.image_store.fetch(game_state.card()).await else {
                 log::warn!("couldn't fetch image");
+                metrics::IMAGE_FETCH_FAILURES.inc();
                 return;
             };
 
+            self.card_store
+                .record_result(UserId::from(interaction.user_id()), Game::Guess, true)
+                .await;
+            metrics::record_game_won(Game::Guess, Some(&game_state.difficulty().to_string()));
             if let Err(why) = interaction.send_win_message(game_state, images).await {
                 log::warn!("couldn't send win message: {why}");
             }
@@ -45,10 +53,14 @@ impl_app! {
         } else if game_state.number_of_guesses() >= game_state.max_guesses() {
             let Ok(images) = self.image_store.fetch(game_state.card()).await else {
                 log::warn!("couldn't fetch image");
+                metrics::IMAGE_FETCH_FAILURES.inc();
                 return;
             };
 
             state::delete(interaction.id(), &self.cache).await;
+            self.card_store
+                .record_result(UserId::from(interaction.user_id()), Game::Guess, false)
+                .await;
             if let Err(why) = interaction.game_failed_message(game_state, images).await {
                 log::warn!("couldn't send game failed message: {why}");
             }
@@ -56,6 +68,7 @@ impl_app! {
             state::add(&game_state, interaction.id(), &self.cache).await;
             let Ok(images) = self.image_store.fetch_illustration(game_state.card()).await else {
                 log::warn!("couldn't fetch illustration");
+                metrics::IMAGE_FETCH_FAILURES.inc();
                 return;
             };
             if let Err(why) = interaction
@@ -83,11 +96,13 @@ mod tests {
     use super::*;
     use crate::domain::app::App;
     use crate::domain::functions::game::state::{Difficulty, GameState};
+    use crate::domain::i18n::Locale;
     use crate::ports::drivers::client::MockGameInteraction;
     use crate::ports::services::cache::MockCache;
     use crate::ports::services::card_store::{MockCardStore, TestCardStore};
     use crate::ports::services::image_store::MockImageStore;
     use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use cards_sdk::MockStatsRepository;
     use contracts::card::Card;
     use contracts::image::Image;
     use mockall::predicate::*;
@@ -122,6 +137,18 @@ mod tests {
         Image::new(vec![1, 2, 3, 4])
     }
 
+    const USER_ID: u64 = 42;
+
+    fn expect_result(won: bool) -> MockStatsRepository {
+        let mut stats = MockStatsRepository::new();
+        stats
+            .expect_record_result()
+            .times(1)
+            .with(eq(UserId::from(USER_ID)), eq(Game::Guess), eq(won))
+            .return_const(None);
+        stats
+    }
+
     #[tokio::test]
     async fn test_guess_correct_wins_game() {
         let card = create_test_card();
@@ -154,6 +181,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_send_win_message()
             .times(1)
@@ -164,7 +192,7 @@ mod tests {
 
         let app = App::new(
             image_store,
-            TestCardStore::new(card_store),
+            TestCardStore::with_stats(card_store, expect_result(true)),
             cache,
             MockSpoilerSubscription::new(),
         );
@@ -204,6 +232,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_send_win_message()
             .times(1)
@@ -211,7 +240,7 @@ mod tests {
 
         let app = App::new(
             image_store,
-            TestCardStore::new(card_store),
+            TestCardStore::with_stats(card_store, expect_result(true)),
             cache,
             MockSpoilerSubscription::new(),
         );
@@ -254,6 +283,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_send_guess_wrong_message()
             .times(1)
@@ -308,6 +338,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_game_failed_message()
             .times(1)
@@ -316,7 +347,7 @@ mod tests {
 
         let app = App::new(
             image_store,
-            TestCardStore::new(card_store),
+            TestCardStore::with_stats(card_store, expect_result(false)),
             cache,
             MockSpoilerSubscription::new(),
         );
@@ -340,7 +371,9 @@ mod tests {
         let card_store = MockCardStore::new();
 
         let mut interaction = MockGameInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_reply()
             .times(1)
@@ -389,6 +422,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_send_win_message()
             .times(1)
@@ -396,7 +430,7 @@ mod tests {
 
         let app = App::new(
             image_store,
-            TestCardStore::new(card_store),
+            TestCardStore::with_stats(card_store, expect_result(true)),
             cache,
             MockSpoilerSubscription::new(),
         );
@@ -437,6 +471,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_user_id().return_const(USER_ID);
         interaction
             .expect_send_win_message()
             .times(1)
@@ -444,7 +479,7 @@ mod tests {
 
         let app = App::new(
             image_store,
-            TestCardStore::new(card_store),
+            TestCardStore::with_stats(card_store, expect_result(true)),
             cache,
             MockSpoilerSubscription::new(),
         );
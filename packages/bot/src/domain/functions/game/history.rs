@@ -0,0 +1,163 @@
+use crate::ports::services::cache::Cache;
+use std::env;
+use std::sync::LazyLock;
+use uuid::Uuid;
+
+const DEFAULT_HISTORY_SIZE: usize = 50;
+
+/// How many recent game cards each guild remembers, read once from
+/// `GAME_HISTORY_SIZE` — `0` disables the history entirely.
+pub static HISTORY_SIZE: LazyLock<usize> = LazyLock::new(|| {
+    env::var("GAME_HISTORY_SIZE")
+        .ok()
+        .and_then(|size| size.parse().ok())
+        .unwrap_or(DEFAULT_HISTORY_SIZE)
+});
+
+/// Cache key for a guild's history. DMs have no guild, so they fall back to
+/// the channel id and get a history of their own.
+#[must_use]
+pub fn key(guild_id: Option<String>, channel_id: String) -> String {
+    format!("game-history:{}", guild_id.unwrap_or(channel_id))
+}
+
+/// The `oracle_id`s of the most recent game cards, oldest first.
+pub async fn fetch<C: Cache + Send + Sync>(key: String, cache: &C) -> Vec<Uuid> {
+    let Some(history_string) = cache.get(key).await else {
+        return Vec::new();
+    };
+
+    match ron::from_str::<Vec<Uuid>>(&history_string) {
+        Ok(history) => history,
+        Err(why) => {
+            log::warn!("Couldn't parse game history: {why}");
+            Vec::new()
+        }
+    }
+}
+
+/// Appends `oracle_id` to `history`, dropping the oldest entries beyond
+/// `size`, and writes the result back.
+pub async fn push<C: Cache + Send + Sync>(
+    mut history: Vec<Uuid>,
+    oracle_id: Uuid,
+    size: usize,
+    key: String,
+    cache: &C,
+) {
+    if size == 0 {
+        return;
+    }
+
+    history.push(oracle_id);
+    if history.len() > size {
+        history.drain(..history.len() - size);
+    }
+
+    let ron_string = match ron::to_string(&history) {
+        Ok(ron_string) => ron_string,
+        Err(err) => {
+            log::warn!("Error converting game history to string: {err}");
+            return;
+        }
+    };
+
+    if let Err(why) = cache.set(key, ron_string).await {
+        log::warn!("Error while trying to set value in redis: {why}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::services::cache::MockCache;
+    use mockall::predicate::eq;
+    use uuid::uuid;
+
+    const FIRST: Uuid = uuid!("00000000-0000-0000-0000-000000000001");
+    const SECOND: Uuid = uuid!("00000000-0000-0000-0000-000000000002");
+    const THIRD: Uuid = uuid!("00000000-0000-0000-0000-000000000003");
+
+    #[test]
+    fn test_key_uses_guild() {
+        assert_eq!(
+            key(Some("guild".to_string()), "channel".to_string()),
+            "game-history:guild"
+        );
+    }
+
+    #[test]
+    fn test_key_falls_back_to_channel() {
+        assert_eq!(key(None, "channel".to_string()), "game-history:channel");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_missing_history() {
+        let mut cache = MockCache::new();
+        cache.expect_get().times(1).return_const(None);
+
+        assert!(fetch("game-history:guild".to_string(), &cache)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_invalid_history() {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .times(1)
+            .return_const(Some("not valid ron".to_string()));
+
+        assert!(fetch("game-history:guild".to_string(), &cache)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_push_trims_oldest() {
+        let expected = ron::to_string(&vec![SECOND, THIRD]).unwrap();
+
+        let mut cache = MockCache::new();
+        cache
+            .expect_set()
+            .times(1)
+            .with(eq("game-history:guild".to_string()), eq(expected))
+            .returning(|_, _| Ok(()));
+
+        push(
+            vec![FIRST, SECOND],
+            THIRD,
+            2,
+            "game-history:guild".to_string(),
+            &cache,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_history() {
+        let stored = ron::to_string(&vec![FIRST, SECOND]).unwrap();
+
+        let mut cache = MockCache::new();
+        cache.expect_get().times(1).return_const(Some(stored));
+
+        let history = fetch("game-history:guild".to_string(), &cache).await;
+        assert_eq!(history, vec![FIRST, SECOND]);
+    }
+
+    #[tokio::test]
+    async fn test_push_disabled() {
+        let mut cache = MockCache::new();
+        cache.expect_set().times(0);
+
+        push(
+            Vec::new(),
+            FIRST,
+            0,
+            "game-history:guild".to_string(),
+            &cache,
+        )
+        .await;
+    }
+}
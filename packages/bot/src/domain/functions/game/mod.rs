@@ -1,4 +1,5 @@
 pub(crate) mod give_up;
 pub(crate) mod guess;
+pub mod history;
 pub(crate) mod play;
 pub mod state;
@@ -1,42 +1,49 @@
+use crate::domain::functions::game::history;
 use crate::domain::functions::game::state;
 use crate::domain::functions::game::state::{Difficulty, GameState};
-use crate::domain::utils;
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
 use crate::impl_app;
 use crate::ports::drivers::client::GameInteraction;
-
-const SET_ABBR_CHAR_LIMIT: usize = 5;
+use cards_sdk::Game;
 
 impl_app! {
     pub async fn play_command<I: GameInteraction>(&self, interaction: &I, options: PlayOptions) {
         let PlayOptions { set, difficulty } = options;
-        let random_card = if let Some(set_name) = set {
-            let matched_set = if set_name.chars().count() < SET_ABBR_CHAR_LIMIT {
-                self.set_from_abbreviation(&set_name).await
-            } else {
-                self.fuzzy_match_set_name(&utils::normalise_card_name(&set_name))
-                    .await
-            };
+        let history_key = history::key(interaction.guild_id(), interaction.id());
+        let recent = history::fetch(history_key.clone(), &self.cache).await;
 
-            let Some(matched_set) = matched_set else {
+        let random_card = if let Some(set_name) = set {
+            let Some(matched_set) = self.resolve_set(&set_name).await else {
                 if let Err(why) = interaction
-                    .reply(format!("Could not find set '{set_name}'"))
+                    .reply(Message::SetNotFound { set_name: &set_name }.text(interaction.locale()))
                     .await
                 {
                     log::error!("couldn't create interaction response: {why:?}");
                 }
                 return;
             };
-            self.card_store.random_card_from_set(&matched_set).await
+            self.card_store.random_card_from_set(&matched_set, &recent).await
         } else {
-            self.card_store.random_card().await
+            self.card_store.random_card(&recent).await
         };
 
         if let Some(card) = random_card {
+            history::push(
+                recent,
+                *card.oracle_id(),
+                *history::HISTORY_SIZE,
+                history_key,
+                &self.cache,
+            )
+            .await;
             let game_state = GameState::from(card, difficulty);
             state::add(&game_state, interaction.id(), &self.cache).await;
+            metrics::record_game_started(Game::Guess, Some(&game_state.difficulty().to_string()));
 
             let Ok(images) = self.image_store.fetch_illustration(game_state.card()).await else {
                 log::warn!("failed to get image");
+                metrics::IMAGE_FETCH_FAILURES.inc();
                 return;
             };
 
@@ -64,6 +71,7 @@ impl PlayOptions {
 mod tests {
     use super::*;
     use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
     use crate::ports::drivers::client::MockGameInteraction;
     use crate::ports::services::cache::MockCache;
     use crate::ports::services::card_store::{MockCardStore, TestCardStore};
@@ -110,7 +118,8 @@ mod tests {
         let images = create_test_images();
 
         let mut cache = MockCache::new();
-        cache.expect_set().times(1).returning(|_, _| Ok(()));
+        cache.expect_get().times(1).return_const(None);
+        cache.expect_set().times(2).returning(|_, _| Ok(()));
 
         let mut image_store = MockImageStore::new();
         image_store
@@ -126,6 +135,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_guild_id().return_const(None);
         interaction
             .expect_send_new_game_message()
             .times(1)
@@ -152,7 +162,8 @@ mod tests {
         let images = create_test_images();
 
         let mut cache = MockCache::new();
-        cache.expect_set().times(1).returning(|_, _| Ok(()));
+        cache.expect_get().times(1).return_const(None);
+        cache.expect_set().times(2).returning(|_, _| Ok(()));
 
         let mut image_store = MockImageStore::new();
         image_store
@@ -169,11 +180,12 @@ mod tests {
         card_store
             .expect_random_card_from_set()
             .times(1)
-            .with(eq("Limited Edition Alpha"))
+            .with(eq("Limited Edition Alpha"), always())
             .return_const(Some(card.clone()));
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_guild_id().return_const(None);
         interaction
             .expect_send_new_game_message()
             .times(1)
@@ -197,7 +209,8 @@ mod tests {
         let images = create_test_images();
 
         let mut cache = MockCache::new();
-        cache.expect_set().times(1).returning(|_, _| Ok(()));
+        cache.expect_get().times(1).return_const(None);
+        cache.expect_set().times(2).returning(|_, _| Ok(()));
 
         let mut image_store = MockImageStore::new();
         image_store
@@ -214,11 +227,12 @@ mod tests {
         card_store
             .expect_random_card_from_set()
             .times(1)
-            .with(eq("Limited Edition Alpha"))
+            .with(eq("Limited Edition Alpha"), always())
             .return_const(Some(card.clone()));
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_guild_id().return_const(None);
         interaction
             .expect_send_new_game_message()
             .times(1)
@@ -239,7 +253,8 @@ mod tests {
     async fn test_play_set_not_found() {
         let channel_id = "test_channel_notfound".to_string();
 
-        let cache = MockCache::new();
+        let mut cache = MockCache::new();
+        cache.expect_get().times(1).return_const(None);
         let image_store = MockImageStore::new();
 
         let mut card_store = MockCardStore::new();
@@ -250,7 +265,9 @@ mod tests {
             .return_const(None);
 
         let mut interaction = MockGameInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_guild_id().return_const(None);
         interaction
             .expect_reply()
             .times(1)
@@ -275,7 +292,8 @@ mod tests {
         let images = create_test_images();
 
         let mut cache = MockCache::new();
-        cache.expect_set().times(1).returning(|_, _| Ok(()));
+        cache.expect_get().times(1).return_const(None);
+        cache.expect_set().times(2).returning(|_, _| Ok(()));
 
         let mut image_store = MockImageStore::new();
         image_store
@@ -291,6 +309,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_guild_id().return_const(None);
         interaction
             .expect_send_new_game_message()
             .times(1)
@@ -317,7 +336,8 @@ mod tests {
         let images = create_test_images();
 
         let mut cache = MockCache::new();
-        cache.expect_set().times(1).returning(|_, _| Ok(()));
+        cache.expect_get().times(1).return_const(None);
+        cache.expect_set().times(2).returning(|_, _| Ok(()));
 
         let mut image_store = MockImageStore::new();
         image_store
@@ -339,6 +359,7 @@ mod tests {
 
         let mut interaction = MockGameInteraction::new();
         interaction.expect_id().return_const(channel_id.clone());
+        interaction.expect_guild_id().return_const(None);
         interaction
             .expect_send_new_game_message()
             .times(1)
@@ -355,6 +376,68 @@ mod tests {
         app.play_command(&interaction, options).await;
     }
 
+    #[tokio::test]
+    async fn test_play_excludes_and_records_guild_history() {
+        let card = create_test_card();
+        let recent = vec![uuid!("00000000-0000-0000-0000-000000000001")];
+        let stored = ron::to_string(&recent).unwrap();
+        let mut updated = recent.clone();
+        updated.push(*card.oracle_id());
+        let updated = ron::to_string(&updated).unwrap();
+
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .times(1)
+            .with(eq(String::from("game-history:test_guild")))
+            .return_const(Some(stored));
+        cache
+            .expect_set()
+            .times(1)
+            .with(eq(String::from("game-history:test_guild")), eq(updated))
+            .returning(|_, _| Ok(()));
+        cache
+            .expect_set()
+            .times(1)
+            .with(eq(String::from("test_channel")), always())
+            .returning(|_, _| Ok(()));
+
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch_illustration()
+            .times(1)
+            .return_const(Ok(create_test_images()));
+
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_card()
+            .times(1)
+            .withf(move |exclude: &[uuid::Uuid]| exclude == recent.as_slice())
+            .return_const(Some(card.clone()));
+
+        let mut interaction = MockGameInteraction::new();
+        interaction
+            .expect_id()
+            .return_const(String::from("test_channel"));
+        interaction
+            .expect_guild_id()
+            .return_const(Some(String::from("test_guild")));
+        interaction
+            .expect_send_new_game_message()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.play_command(&interaction, PlayOptions::new(None, Difficulty::Easy))
+            .await;
+    }
+
     #[test]
     fn test_play_options_creation() {
         let options = PlayOptions::new(Some("LEA".to_string()), Difficulty::Easy);
@@ -3,7 +3,7 @@ use contracts::card::Card;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub enum Difficulty {
     Easy,
     Medium,
@@ -1,7 +1,57 @@
 use crate::ports::drivers::client::MessageInteraction;
 
-pub async fn run<I: MessageInteraction>(interaction: &I, text: &str) {
-    if let Err(why) = interaction.reply(text.into()).await {
-        log::error!("couldn't create interaction response: {why:?}");
+pub async fn run<I: MessageInteraction>(interaction: &I, pages: &[String]) {
+    let Some((first, rest)) = pages.split_first() else {
+        return;
     };
+    if let Err(why) = interaction.reply(first.clone()).await {
+        log::error!("couldn't create interaction response: {why:?}");
+        return;
+    }
+    for page in rest {
+        if let Err(why) = interaction.follow_up(page.clone()).await {
+            log::error!("couldn't send help follow-up: {why:?}");
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::drivers::client::{MessageInteractionError, MockMessageInteraction};
+    use mockall::predicate::eq;
+    use mockall::Sequence;
+
+    #[tokio::test]
+    async fn test_sends_every_page_in_order() {
+        let mut sequence = Sequence::new();
+        let mut interaction = MockMessageInteraction::new();
+        interaction
+            .expect_reply()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .with(eq(String::from("one")))
+            .returning(|_| Ok(()));
+        interaction
+            .expect_follow_up()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .with(eq(String::from("two")))
+            .returning(|_| Ok(()));
+
+        run(&interaction, &[String::from("one"), String::from("two")]).await;
+    }
+
+    #[tokio::test]
+    async fn test_stops_when_reply_fails() {
+        let mut interaction = MockMessageInteraction::new();
+        interaction
+            .expect_reply()
+            .times(1)
+            .returning(|_| Err(MessageInteractionError::new(String::from("gone"))));
+        interaction.expect_follow_up().times(0);
+
+        run(&interaction, &[String::from("one"), String::from("two")]).await;
+    }
 }
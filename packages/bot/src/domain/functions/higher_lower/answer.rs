@@ -0,0 +1,402 @@
+use crate::domain::functions::higher_lower::state;
+use crate::domain::functions::higher_lower::state::{Pick, Reveal};
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
+use crate::impl_app;
+use crate::ports::drivers::client::HigherLowerInteraction;
+use cards_sdk::{Game, UserId};
+use uuid::Uuid;
+
+impl_app! {
+    pub async fn higher_lower_answer<I: HigherLowerInteraction>(&self, interaction: &I, options: HigherLowerAnswer) {
+        let HigherLowerAnswer { owner, round, pick } = options;
+        if interaction.user_id() != owner {
+            reply(interaction, Message::NotYourGame).await;
+            return;
+        }
+
+        let key = state::key(owner);
+        let lock = named_lock::LOCKS.get(&key).await;
+        let _guard = lock.lock().await;
+
+        let Some(higher_lower_state) = state::fetch(key.clone(), &self.cache)
+            .await
+            .filter(|higher_lower_state| higher_lower_state.round() == &round)
+        else {
+            reply(interaction, Message::RoundOver).await;
+            return;
+        };
+
+        let reveal = Reveal::new(&higher_lower_state, pick);
+        if !reveal.correct() {
+            let stats = self
+                .card_store
+                .record_result(UserId::from(owner), Game::HigherLower, false)
+                .await;
+            state::delete(key, &self.cache).await;
+
+            let (Ok(left_image), Ok(right_image)) = (
+                self.image_store.fetch(reveal.left().card()).await,
+                self.image_store.fetch(reveal.right().card()).await,
+            ) else {
+                log::warn!("couldn't fetch image");
+                reply(interaction, Message::CardImagesUnavailable).await;
+                return;
+            };
+
+            if let Err(why) = interaction
+                .send_game_over(reveal, stats, (left_image, right_image))
+                .await
+            {
+                log::warn!("couldn't send higher lower result: {why}");
+            }
+            return;
+        }
+
+        // Ask for two so there's a fallback if one is the card being kept.
+        let kept = higher_lower_state.right();
+        let challenger = self
+            .card_store
+            .random_priced_cards(kept.currency(), 2)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .find(|candidate| candidate.card().oracle_id() != kept.card().oracle_id());
+        let Some(challenger) = challenger else {
+            log::warn!("Failed to get another priced card");
+            self.card_store
+                .record_result(UserId::from(owner), Game::HigherLower, true)
+                .await;
+            metrics::record_game_won(Game::HigherLower, None);
+            state::delete(key, &self.cache).await;
+            reply(interaction, Message::RunOutOfCards).await;
+            return;
+        };
+
+        let higher_lower_state = higher_lower_state.next(challenger);
+        let (Ok(left_image), Ok(right_image)) = (
+            self.image_store.fetch(higher_lower_state.left().card()).await,
+            self.image_store.fetch(higher_lower_state.right().card()).await,
+        ) else {
+            log::warn!("couldn't fetch image");
+            reply(interaction, Message::CardImagesUnavailable).await;
+            return;
+        };
+
+        // Only counted once the next round is ready, so pressing again after
+        // a failure retries the round rather than counting it twice.
+        self.card_store
+            .record_result(UserId::from(owner), Game::HigherLower, true)
+            .await;
+        metrics::record_game_won(Game::HigherLower, None);
+        state::add(&higher_lower_state, key, &self.cache).await;
+
+        if let Err(why) = interaction
+            .send_round(higher_lower_state, (left_image, right_image), Some(reveal))
+            .await
+        {
+            log::warn!("couldn't send higher lower round: {why}");
+        }
+    }
+}
+
+/// Answers only the player who pressed the button.
+async fn reply<I: HigherLowerInteraction>(interaction: &I, message: Message<'_>) {
+    if let Err(why) = interaction
+        .reply_ephemeral(message.text(interaction.locale()))
+        .await
+    {
+        log::warn!("couldn't create interaction: {why}");
+    }
+}
+
+/// The button a player pressed: whose run it belongs to, which round, and
+/// which card they called as the pricier one.
+pub struct HigherLowerAnswer {
+    owner: u64,
+    round: Uuid,
+    pick: Pick,
+}
+
+impl HigherLowerAnswer {
+    pub fn new(owner: u64, round: Uuid, pick: Pick) -> Self {
+        Self { owner, round, pick }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::functions::higher_lower::state::HigherLowerState;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockHigherLowerInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::{ImageRetrievalError, MockImageStore};
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use cards_sdk::{GameStats, MockStatsRepository};
+    use contracts::card::Card;
+    use contracts::image::Image;
+    use contracts::priced_card::{Currency, PricedCard};
+    use mockall::predicate::*;
+    use uuid::uuid;
+
+    const USER_ID: u64 = 7;
+
+    fn priced(name: &str, price: f64) -> PricedCard {
+        let card = Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            Some(uuid!("12345678-1234-1234-1234-123456789013")),
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            "Deals 3 damage.".to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        PricedCard::new(card, price, Currency::Usd)
+    }
+
+    fn create_state() -> HigherLowerState {
+        HigherLowerState::new(priced("Shock", 0.1), priced("Black Lotus", 20000.0))
+    }
+
+    fn mock_cache(state: &HigherLowerState) -> MockCache {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .times(1)
+            .with(eq(String::from("higher-lower:7")))
+            .return_const(Some(ron::to_string(state).unwrap()));
+        cache
+    }
+
+    fn mock_interaction() -> MockHigherLowerInteraction {
+        let mut interaction = MockHigherLowerInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_user_id().return_const(USER_ID);
+        interaction
+    }
+
+    fn mock_image_store() -> MockImageStore {
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .times(2)
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+        image_store
+    }
+
+    fn expect_result(won: bool) -> MockStatsRepository {
+        let mut stats = MockStatsRepository::new();
+        stats
+            .expect_record_result()
+            .times(1)
+            .with(eq(UserId::from(USER_ID)), eq(Game::HigherLower), eq(won))
+            .return_const(Some(GameStats {
+                played: 3,
+                won: 2,
+                streak: i32::from(won),
+                best_streak: 2,
+            }));
+        stats
+    }
+
+    #[tokio::test]
+    async fn test_correct_pick_continues_the_run() {
+        let state = create_state();
+        let round = *state.round();
+
+        let mut cache = mock_cache(&state);
+        cache
+            .expect_set()
+            .times(1)
+            .withf(|key, _| key == "higher-lower:7")
+            .returning(|_, _| Ok(()));
+        cache.expect_delete().times(0);
+
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_priced_cards()
+            .times(1)
+            .with(eq(Currency::Usd), eq(2))
+            .return_const(Some(vec![priced("Opt", 0.2)]));
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_send_round()
+            .times(1)
+            .withf(|state: &HigherLowerState, _, last: &Option<Reveal>| {
+                state.left().card().name() == "Black Lotus"
+                    && state.right().card().name() == "Opt"
+                    && state.streak() == 1
+                    && last.as_ref().is_some_and(Reveal::correct)
+            })
+            .returning(|_, _, _| Ok(()));
+
+        let app = App::new(
+            mock_image_store(),
+            TestCardStore::with_stats(card_store, expect_result(true)),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_answer(
+            &interaction,
+            HigherLowerAnswer::new(USER_ID, round, Pick::Right),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_image_failure_leaves_the_round_to_retry() {
+        let state = create_state();
+        let round = *state.round();
+
+        let mut cache = mock_cache(&state);
+        cache.expect_set().times(0);
+        cache.expect_delete().times(0);
+
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_priced_cards()
+            .times(1)
+            .return_const(Some(vec![priced("Opt", 0.2)]));
+        let mut stats_repository = MockStatsRepository::new();
+        stats_repository.expect_record_result().times(0);
+
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .returning(|_| Err(ImageRetrievalError::new(String::from("missing"))));
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_round().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't fetch the card images, try again :(",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            image_store,
+            TestCardStore::with_stats(card_store, stats_repository),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_answer(
+            &interaction,
+            HigherLowerAnswer::new(USER_ID, round, Pick::Right),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_wrong_pick_ends_the_run() {
+        let state = create_state();
+        let round = *state.round();
+
+        let mut cache = mock_cache(&state);
+        cache
+            .expect_delete()
+            .times(1)
+            .with(eq(String::from("higher-lower:7")))
+            .returning(|_| Ok(()));
+        cache.expect_set().times(0);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_round().times(0);
+        interaction
+            .expect_send_game_over()
+            .times(1)
+            .withf(|reveal: &Reveal, stats: &Option<GameStats>, _| {
+                !reveal.correct() && reveal.pick() == Pick::Left && stats.is_some()
+            })
+            .returning(|_, _, _| Ok(()));
+
+        let app = App::new(
+            mock_image_store(),
+            TestCardStore::with_stats(MockCardStore::new(), expect_result(false)),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_answer(
+            &interaction,
+            HigherLowerAnswer::new(USER_ID, round, Pick::Left),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_someone_elses_game() {
+        let mut cache = MockCache::new();
+        cache.expect_get().times(0);
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "This isn't your game, start your own with `/higherlower`!",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(MockCardStore::new()),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_answer(
+            &interaction,
+            HigherLowerAnswer::new(USER_ID + 1, Uuid::new_v4(), Pick::Left),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_stale_round_is_rejected() {
+        let state = create_state();
+        let cache = mock_cache(&state);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_round().times(0);
+        interaction.expect_send_game_over().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from("This round is already over.")))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(MockCardStore::new()),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_answer(
+            &interaction,
+            HigherLowerAnswer::new(USER_ID, Uuid::new_v4(), Pick::Left),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod answer;
+pub(crate) mod play;
+pub mod state;
@@ -0,0 +1,230 @@
+use crate::domain::functions::higher_lower::state;
+use crate::domain::functions::higher_lower::state::HigherLowerState;
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
+use crate::impl_app;
+use crate::ports::drivers::client::HigherLowerInteraction;
+use cards_sdk::Game;
+use contracts::priced_card::Currency;
+
+impl_app! {
+    pub async fn higher_lower_command<I: HigherLowerInteraction>(&self, interaction: &I, options: HigherLowerOptions) {
+        let mut cards = self
+            .card_store
+            .random_priced_cards(options.currency, 2)
+            .await
+            .unwrap_or_default();
+        if cards.len() < 2 {
+            log::warn!("Not enough priced cards for higher lower");
+            if let Err(why) = interaction
+                .reply_ephemeral(Message::NoPricedCards.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction: {why}");
+            }
+            return;
+        }
+        let right = cards.remove(1);
+        let left = cards.remove(0);
+
+        let (Ok(left_image), Ok(right_image)) = (
+            self.image_store.fetch(left.card()).await,
+            self.image_store.fetch(right.card()).await,
+        ) else {
+            log::warn!("couldn't fetch image");
+            if let Err(why) = interaction
+                .reply_ephemeral(Message::CardImagesUnavailable.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction: {why}");
+            }
+            return;
+        };
+
+        // Starting a new run abandons whatever run the player had going.
+        let higher_lower_state = HigherLowerState::new(left, right);
+        state::add(&higher_lower_state, state::key(interaction.user_id()), &self.cache).await;
+        metrics::record_game_started(Game::HigherLower, None);
+
+        if let Err(why) = interaction
+            .send_round(higher_lower_state, (left_image, right_image), None)
+            .await
+        {
+            log::warn!("couldn't send higher lower round: {why}");
+        }
+    }
+}
+
+pub struct HigherLowerOptions {
+    currency: Currency,
+}
+
+impl HigherLowerOptions {
+    pub fn new(currency: Currency) -> Self {
+        Self { currency }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockHigherLowerInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::{ImageRetrievalError, MockImageStore};
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::card::Card;
+    use contracts::image::Image;
+    use contracts::priced_card::PricedCard;
+    use mockall::predicate::*;
+    use uuid::{uuid, Uuid};
+
+    fn priced(name: &str, price: f64) -> PricedCard {
+        let card = Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            Some(uuid!("12345678-1234-1234-1234-123456789013")),
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            "Deals 3 damage.".to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        PricedCard::new(card, price, Currency::Eur)
+    }
+
+    fn mock_interaction() -> MockHigherLowerInteraction {
+        let mut interaction = MockHigherLowerInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_user_id().return_const(7u64);
+        interaction
+    }
+
+    #[tokio::test]
+    async fn test_starts_a_run() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_priced_cards()
+            .times(1)
+            .with(eq(Currency::Eur), eq(2))
+            .return_const(Some(vec![priced("Shock", 0.1), priced("Opt", 0.2)]));
+
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .times(2)
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+
+        let mut cache = MockCache::new();
+        cache
+            .expect_set()
+            .times(1)
+            .withf(|key, _| key == "higher-lower:7")
+            .returning(|_, _| Ok(()));
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_send_round()
+            .times(1)
+            .withf(|state: &HigherLowerState, _, last| {
+                state.left().card().name() == "Shock"
+                    && state.right().card().name() == "Opt"
+                    && state.streak() == 0
+                    && last.is_none()
+            })
+            .returning(|_, _, _| Ok(()));
+
+        let app = App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_command(&interaction, HigherLowerOptions::new(Currency::Eur))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_not_enough_priced_cards() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_priced_cards()
+            .times(1)
+            .return_const(Some(vec![priced("Shock", 0.1)]));
+
+        let mut cache = MockCache::new();
+        cache.expect_set().times(0);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_round().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't find any priced cards right now :(",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_command(&interaction, HigherLowerOptions::new(Currency::Eur))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_image_failure_says_so() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_priced_cards()
+            .times(1)
+            .return_const(Some(vec![priced("Shock", 0.1), priced("Opt", 0.2)]));
+
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .returning(|_| Err(ImageRetrievalError::new(String::from("missing"))));
+
+        let mut cache = MockCache::new();
+        cache.expect_set().times(0);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_round().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't fetch the card images, try again :(",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.higher_lower_command(&interaction, HigherLowerOptions::new(Currency::Eur))
+            .await;
+    }
+}
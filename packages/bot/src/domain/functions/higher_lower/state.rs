@@ -0,0 +1,259 @@
+use crate::ports::services::cache::Cache;
+use contracts::priced_card::PricedCard;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Which of the two cards the player thinks costs more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pick {
+    Left,
+    Right,
+}
+
+/// One player's run: the pair currently on screen and how many they've
+/// called correctly so far. Each pair gets a fresh `round` id so a press on
+/// an older message can't answer the current one.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HigherLowerState {
+    round: Uuid,
+    left: PricedCard,
+    right: PricedCard,
+    streak: usize,
+}
+
+impl HigherLowerState {
+    #[must_use]
+    pub fn new(left: PricedCard, right: PricedCard) -> Self {
+        Self {
+            round: Uuid::new_v4(),
+            left,
+            right,
+            streak: 0,
+        }
+    }
+
+    /// After a correct call the right-hand card slides over to the left and
+    /// `right` becomes the new challenger.
+    #[must_use]
+    pub fn next(self, right: PricedCard) -> Self {
+        Self {
+            round: Uuid::new_v4(),
+            left: self.right,
+            right,
+            streak: self.streak + 1,
+        }
+    }
+
+    #[must_use]
+    pub fn round(&self) -> &Uuid {
+        &self.round
+    }
+
+    #[must_use]
+    pub fn left(&self) -> &PricedCard {
+        &self.left
+    }
+
+    #[must_use]
+    pub fn right(&self) -> &PricedCard {
+        &self.right
+    }
+
+    #[must_use]
+    pub fn streak(&self) -> usize {
+        self.streak
+    }
+
+    /// A tie counts in the player's favour whichever way they called it.
+    #[must_use]
+    pub fn is_correct(&self, pick: Pick) -> bool {
+        match pick {
+            Pick::Left => self.left.price() >= self.right.price(),
+            Pick::Right => self.right.price() >= self.left.price(),
+        }
+    }
+}
+
+/// The prices behind a pair once the player has made their call.
+#[derive(Debug)]
+pub struct Reveal {
+    left: PricedCard,
+    right: PricedCard,
+    pick: Pick,
+    correct: bool,
+    streak: usize,
+}
+
+impl Reveal {
+    /// `streak` is the run length *after* this call: one longer on a
+    /// correct pick, unchanged (and final) on a wrong one.
+    #[must_use]
+    pub fn new(state: &HigherLowerState, pick: Pick) -> Self {
+        let correct = state.is_correct(pick);
+        Self {
+            left: state.left.clone(),
+            right: state.right.clone(),
+            pick,
+            correct,
+            streak: state.streak + usize::from(correct),
+        }
+    }
+
+    #[must_use]
+    pub fn left(&self) -> &PricedCard {
+        &self.left
+    }
+
+    #[must_use]
+    pub fn right(&self) -> &PricedCard {
+        &self.right
+    }
+
+    #[must_use]
+    pub fn pick(&self) -> Pick {
+        self.pick
+    }
+
+    #[must_use]
+    pub fn correct(&self) -> bool {
+        self.correct
+    }
+
+    #[must_use]
+    pub fn streak(&self) -> usize {
+        self.streak
+    }
+}
+
+/// Runs are per player rather than per channel, so several people can play
+/// side by side.
+#[must_use]
+pub fn key(user_id: u64) -> String {
+    format!("higher-lower:{user_id}")
+}
+
+pub async fn fetch<C: Cache + Send + Sync>(key: String, cache: &C) -> Option<HigherLowerState> {
+    let state_string = cache.get(key).await?;
+
+    match ron::from_str::<HigherLowerState>(&state_string) {
+        Ok(state) => Some(state),
+        Err(why) => {
+            log::warn!("Couldn't parse higher lower state: {why}");
+            None
+        }
+    }
+}
+
+pub async fn delete<C: Cache + Send + Sync>(key: String, cache: &C) {
+    if let Err(why) = cache.delete(key).await {
+        log::warn!("Error deleting key from redis the response: {why:?}");
+    };
+}
+
+pub async fn add<C: Cache + Send + Sync>(state: &HigherLowerState, key: String, cache: &C) {
+    let ron_string = match ron::to_string(state) {
+        Ok(ron_string) => ron_string,
+        Err(err) => {
+            log::warn!("Error converting higher lower state to string: {err}");
+            return;
+        }
+    };
+
+    if let Err(why) = cache.set(key, ron_string).await {
+        log::warn!("Error while trying to set value in redis: {why}");
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::services::cache::MockCache;
+    use contracts::card::Card;
+    use contracts::priced_card::Currency;
+    use uuid::uuid;
+
+    fn priced(name: &str, price: f64) -> PricedCard {
+        let card = Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            Some(uuid!("12345678-1234-1234-1234-123456789013")),
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            "Deals 3 damage.".to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        PricedCard::new(card, price, Currency::Usd)
+    }
+
+    #[test]
+    fn test_is_correct() {
+        let state = HigherLowerState::new(priced("Black Lotus", 20000.0), priced("Shock", 0.1));
+
+        assert!(state.is_correct(Pick::Left));
+        assert!(!state.is_correct(Pick::Right));
+    }
+
+    #[test]
+    fn test_tie_is_correct_either_way() {
+        let state = HigherLowerState::new(priced("Shock", 0.1), priced("Opt", 0.1));
+
+        assert!(state.is_correct(Pick::Left));
+        assert!(state.is_correct(Pick::Right));
+    }
+
+    #[test]
+    fn test_next_slides_right_to_left() {
+        let state = HigherLowerState::new(priced("Shock", 0.1), priced("Opt", 0.2));
+        let round = *state.round();
+        let next = state.next(priced("Fireball", 0.5));
+
+        assert_eq!(next.left().card().name(), "Opt");
+        assert_eq!(next.right().card().name(), "Fireball");
+        assert_eq!(next.streak(), 1);
+        assert_ne!(next.round(), &round);
+    }
+
+    #[test]
+    fn test_reveal_streak() {
+        let state = HigherLowerState::new(priced("Shock", 0.1), priced("Opt", 0.2));
+
+        let right = Reveal::new(&state, Pick::Right);
+        assert!(right.correct());
+        assert_eq!(right.streak(), 1);
+
+        let wrong = Reveal::new(&state, Pick::Left);
+        assert!(!wrong.correct());
+        assert_eq!(wrong.streak(), 0);
+    }
+
+    #[test]
+    fn test_key_is_per_user() {
+        assert_eq!(key(42), "higher-lower:42");
+    }
+
+    #[tokio::test]
+    async fn test_round_trips_through_cache() {
+        let state = HigherLowerState::new(priced("Shock", 0.1), priced("Opt", 0.2));
+        let ron_string = ron::to_string(&state).unwrap();
+
+        let mut cache = MockCache::new();
+        cache.expect_get().times(1).return_const(Some(ron_string));
+
+        let fetched = fetch(key(42), &cache).await.unwrap();
+        assert_eq!(fetched.round(), state.round());
+        assert_eq!(fetched.right(), state.right());
+    }
+}
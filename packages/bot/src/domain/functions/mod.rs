@@ -1,3 +1,14 @@
+pub mod artist;
+pub mod cube;
+pub mod deck;
+pub mod delete;
 pub mod game;
 pub mod help;
+pub mod higher_lower;
+pub mod oracle;
+pub mod pack;
+pub mod proxies;
+pub mod rules;
+pub mod set;
 pub mod spoilers;
+pub mod trivia;
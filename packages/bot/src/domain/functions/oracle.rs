@@ -0,0 +1,266 @@
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::OracleInteraction;
+use contracts::card::Card;
+
+/// Discord's limit on the options in a select menu.
+pub const RESULTS_PER_PAGE: usize = 25;
+/// Ten pages' worth; past that the search wants narrowing down.
+const MAX_RESULTS: i64 = 250;
+
+/// The cards whose rules text matched a search, open at one page of them.
+pub struct OracleResults {
+    text: String,
+    cards: Vec<Card>,
+    page: usize,
+}
+
+impl OracleResults {
+    /// A `page` past the end opens the last one.
+    #[must_use]
+    pub fn new(text: String, cards: Vec<Card>, page: usize) -> Self {
+        let pages = cards.len().div_ceil(RESULTS_PER_PAGE).max(1);
+        Self {
+            text,
+            cards,
+            page: page.min(pages - 1),
+        }
+    }
+
+    /// What was searched for.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    #[must_use]
+    pub fn pages(&self) -> usize {
+        self.cards.len().div_ceil(RESULTS_PER_PAGE).max(1)
+    }
+
+    /// The cards on `page`, empty if there's no such page.
+    #[must_use]
+    pub fn cards_on(&self, page: usize) -> &[Card] {
+        self.cards
+            .chunks(RESULTS_PER_PAGE)
+            .nth(page)
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn cards(&self) -> &[Card] {
+        self.cards_on(self.page)
+    }
+
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Whether there were more matches than could be shown.
+    #[must_use]
+    pub fn capped(&self) -> bool {
+        i64::try_from(self.cards.len()).is_ok_and(|total| total >= MAX_RESULTS)
+    }
+}
+
+impl_app! {
+    async fn send_oracle_page<I: OracleInteraction>(&self, interaction: &I, text: String, page: usize) {
+        let text = text.trim().to_string();
+        if text.trim_matches('"').trim().is_empty() {
+            if let Err(why) = interaction
+                .reply(Message::NoOracleText.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        }
+
+        let cards = self
+            .card_store
+            .search_oracle(&text, MAX_RESULTS)
+            .await
+            .unwrap_or_default();
+        if cards.is_empty() {
+            if let Err(why) = interaction
+                .reply(Message::NoOracleMatches { text: &text }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        }
+
+        if let Err(why) = interaction
+            .send_results(OracleResults::new(text, cards, page))
+            .await
+        {
+            log::warn!("couldn't send oracle results: {why}");
+        }
+    }
+
+    pub async fn oracle_command<I: OracleInteraction>(&self, interaction: &I, options: OracleOptions) {
+        self.send_oracle_page(interaction, options.text, 0).await;
+    }
+
+    /// Turns to another page of results already on screen.
+    pub async fn oracle_page<I: OracleInteraction>(&self, interaction: &I, options: OraclePageOptions) {
+        self.send_oracle_page(interaction, options.text, options.page).await;
+    }
+}
+
+pub struct OracleOptions {
+    text: String,
+}
+
+impl OracleOptions {
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+}
+
+pub struct OraclePageOptions {
+    text: String,
+    page: usize,
+}
+
+impl OraclePageOptions {
+    pub fn new(text: String, page: usize) -> Self {
+        Self { text, page }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockOracleInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use mockall::predicate::eq;
+    use uuid::{uuid, Uuid};
+
+    fn card(name: &str) -> Card {
+        Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            String::from("{U}"),
+            vec![String::from("U")],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            String::from("Draw two cards."),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn app(
+        card_store: MockCardStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[test]
+    fn test_pages() {
+        let cards = (0..30).map(|i| card(&format!("Card {i:02}"))).collect();
+        let results = OracleResults::new(String::from("draw"), cards, 1);
+        assert_eq!(
+            (results.page(), results.pages(), results.total()),
+            (1, 2, 30)
+        );
+        assert_eq!(results.cards().len(), 5);
+        assert_eq!(results.cards()[0].name(), "Card 25");
+        assert!(results.cards_on(2).is_empty());
+        assert!(!results.capped());
+    }
+
+    #[test]
+    fn test_page_past_the_end_opens_the_last() {
+        let results = OracleResults::new(String::from("draw"), vec![card("Divination")], 4);
+        assert_eq!((results.page(), results.pages()), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_oracle_command() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search_oracle()
+            .with(eq("\"draw two cards\""), eq(MAX_RESULTS))
+            .return_const(Some(vec![card("Divination")]));
+
+        let mut interaction = MockOracleInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_results()
+            .times(1)
+            .withf(|results| results.text() == "\"draw two cards\"" && results.total() == 1)
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .oracle_command(
+                &interaction,
+                OracleOptions::new(String::from(" \"draw two cards\" ")),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_no_matches() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search_oracle()
+            .return_const(Some(Vec::new()));
+
+        let mut interaction = MockOracleInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_results().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Couldn't find any cards that say 'gleep'")))
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .oracle_command(&interaction, OracleOptions::new(String::from("gleep")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_blank_search() {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_search_oracle().times(0);
+
+        let mut interaction = MockOracleInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_reply().times(1).returning(|_| Ok(()));
+
+        app(card_store)
+            .oracle_command(&interaction, OracleOptions::new(String::from("\"\"")))
+            .await;
+    }
+}
@@ -0,0 +1,382 @@
+use contracts::set_card::{Rarity, SetCard};
+use rand::seq::IndexedRandom;
+use rand::Rng;
+use std::collections::BTreeMap;
+
+const COMMONS: usize = 10;
+const UNCOMMONS: usize = 3;
+/// Roughly one rare slot in eight is upgraded to a mythic.
+const MYTHIC_CHANCE: f64 = 1.0 / 8.0;
+/// Roughly one pack in three has a foil, which takes the place of a common.
+const FOIL_CHANCE: f64 = 1.0 / 3.0;
+pub const SEALED_PACKS: usize = 6;
+
+/// One card in an opened pack.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackCard {
+    card: SetCard,
+    foil: bool,
+}
+
+impl PackCard {
+    #[must_use]
+    pub fn card(&self) -> &SetCard {
+        &self.card
+    }
+
+    #[must_use]
+    pub fn foil(&self) -> bool {
+        self.foil
+    }
+}
+
+/// A set's cards split up by the booster slot they can fill. Special and
+/// bonus rarities never show up in a regular slot, so they're dropped.
+pub struct SetPool {
+    set_name: String,
+    commons: Vec<SetCard>,
+    uncommons: Vec<SetCard>,
+    rares: Vec<SetCard>,
+    mythics: Vec<SetCard>,
+    basics: Vec<SetCard>,
+}
+
+impl SetPool {
+    #[must_use]
+    pub fn new(cards: Vec<SetCard>) -> Self {
+        let set_name = cards
+            .first()
+            .map(|card| card.card().set_name().to_string())
+            .unwrap_or_default();
+        let mut pool = Self {
+            set_name,
+            commons: Vec::new(),
+            uncommons: Vec::new(),
+            rares: Vec::new(),
+            mythics: Vec::new(),
+            basics: Vec::new(),
+        };
+
+        for card in cards {
+            if card.is_basic_land() {
+                pool.basics.push(card);
+                continue;
+            }
+            match card.rarity() {
+                Rarity::Common => pool.commons.push(card),
+                Rarity::Uncommon => pool.uncommons.push(card),
+                Rarity::Rare => pool.rares.push(card),
+                Rarity::Mythic => pool.mythics.push(card),
+                Rarity::Special | Rarity::Bonus => {}
+            }
+        }
+
+        pool
+    }
+
+    /// Whether there are enough distinct cards to fill every slot — small
+    /// promo and token sets can't make a pack.
+    #[must_use]
+    pub fn can_open(&self) -> bool {
+        self.commons.len() > COMMONS
+            && self.uncommons.len() >= UNCOMMONS
+            && !(self.rares.is_empty() && self.mythics.is_empty())
+    }
+
+    /// Builds a pack in the order it'd be revealed: the rare (or mythic),
+    /// the uncommons, the commons, any foil, then the land slot. Sets
+    /// without basics fill the land slot with another common instead.
+    /// Callers must check `can_open` first.
+    pub fn open<R: Rng>(&self, rng: &mut R) -> Booster {
+        let mut cards = Vec::with_capacity(COMMONS + UNCOMMONS + 2);
+
+        let rare_slot = if self.mythics.is_empty()
+            || (!self.rares.is_empty() && !rng.random_bool(MYTHIC_CHANCE))
+        {
+            &self.rares
+        } else {
+            &self.mythics
+        };
+        cards.extend(rare_slot.choose(rng).cloned());
+        cards.extend(self.uncommons.choose_multiple(rng, UNCOMMONS).cloned());
+
+        let foil = rng.random_bool(FOIL_CHANCE);
+        let commons = COMMONS + usize::from(self.basics.is_empty()) - usize::from(foil);
+        cards.extend(self.commons.choose_multiple(rng, commons).cloned());
+
+        let mut cards: Vec<PackCard> = cards
+            .into_iter()
+            .map(|card| PackCard { card, foil: false })
+            .collect();
+
+        if foil {
+            let foils: Vec<&SetCard> = self
+                .commons
+                .iter()
+                .chain(&self.uncommons)
+                .chain(&self.rares)
+                .chain(&self.mythics)
+                .collect();
+            if let Some(card) = foils.choose(rng) {
+                cards.push(PackCard {
+                    card: (*card).clone(),
+                    foil: true,
+                });
+            }
+        }
+
+        if let Some(basic) = self.basics.choose(rng) {
+            cards.push(PackCard {
+                card: basic.clone(),
+                foil: false,
+            });
+        }
+
+        Booster {
+            set_name: self.set_name.clone(),
+            cards,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Booster {
+    set_name: String,
+    cards: Vec<PackCard>,
+}
+
+impl Booster {
+    #[must_use]
+    pub fn set_name(&self) -> &str {
+        &self.set_name
+    }
+
+    #[must_use]
+    pub fn cards(&self) -> &[PackCard] {
+        &self.cards
+    }
+
+    /// The pack's headline card: its rare or mythic slot.
+    #[must_use]
+    pub fn rare(&self) -> Option<&SetCard> {
+        self.cards.first().map(PackCard::card)
+    }
+}
+
+/// Six boosters' worth of cards for a sealed event.
+#[derive(Debug)]
+pub struct SealedPool {
+    packs: Vec<Booster>,
+}
+
+impl SealedPool {
+    pub fn open<R: Rng>(pool: &SetPool, rng: &mut R) -> Self {
+        Self {
+            packs: (0..SEALED_PACKS).map(|_| pool.open(rng)).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn set_name(&self) -> &str {
+        self.packs.first().map_or("", Booster::set_name)
+    }
+
+    #[must_use]
+    pub fn packs(&self) -> &[Booster] {
+        &self.packs
+    }
+
+    /// Every rare and mythic opened, foils included, rarest first.
+    #[must_use]
+    pub fn rares(&self) -> Vec<&PackCard> {
+        let mut rares: Vec<&PackCard> = self
+            .packs
+            .iter()
+            .flat_map(Booster::cards)
+            .filter(|card| matches!(card.card().rarity(), Rarity::Rare | Rarity::Mythic))
+            .collect();
+        rares.sort_by_key(|card| std::cmp::Reverse(card.card().rarity()));
+        rares
+    }
+
+    /// The pool as a plain `<count> <name>` list, alphabetical, in the
+    /// format deck builders import. Basic lands are left out since events
+    /// hand those out freely.
+    #[must_use]
+    pub fn export(&self) -> String {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for card in self.packs.iter().flat_map(Booster::cards) {
+            if !card.card().is_basic_land() {
+                *counts.entry(card.card().card().name()).or_default() += 1;
+            }
+        }
+
+        counts
+            .into_iter()
+            .map(|(name, count)| format!("{count} {name}"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contracts::card::Card;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use uuid::{uuid, Uuid};
+
+    fn set_card(name: &str, type_line: &str, rarity: Rarity) -> SetCard {
+        let card = Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            String::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+            type_line.to_string(),
+            String::new(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        SetCard::new(card, rarity)
+    }
+
+    fn set(count: usize, rarity: Rarity) -> Vec<SetCard> {
+        (0..count)
+            .map(|i| set_card(&format!("{rarity:?} {i}"), "Instant", rarity))
+            .collect()
+    }
+
+    fn full_set(with_basics: bool) -> Vec<SetCard> {
+        let mut cards = set(20, Rarity::Common);
+        cards.extend(set(10, Rarity::Uncommon));
+        cards.extend(set(5, Rarity::Rare));
+        cards.extend(set(2, Rarity::Mythic));
+        cards.extend(set(2, Rarity::Special));
+        if with_basics {
+            cards.push(set_card("Forest", "Basic Land — Forest", Rarity::Common));
+        }
+        cards
+    }
+
+    #[test]
+    fn test_pack_has_fifteen_cards() {
+        let pool = SetPool::new(full_set(true));
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..50 {
+            let booster = pool.open(&mut rng);
+            assert_eq!(booster.cards().len(), 15);
+            assert!(matches!(
+                booster.rare().unwrap().rarity(),
+                Rarity::Rare | Rarity::Mythic
+            ));
+            assert!(booster.cards().last().unwrap().card().is_basic_land());
+            assert!(booster
+                .cards()
+                .iter()
+                .all(|card| card.card().rarity() != Rarity::Special));
+        }
+    }
+
+    #[test]
+    fn test_no_basics_fills_with_common() {
+        let pool = SetPool::new(full_set(false));
+        let mut rng = StdRng::seed_from_u64(2);
+
+        for _ in 0..50 {
+            let booster = pool.open(&mut rng);
+            assert_eq!(booster.cards().len(), 15);
+            assert!(booster
+                .cards()
+                .iter()
+                .all(|card| !card.card().is_basic_land()));
+        }
+    }
+
+    #[test]
+    fn test_slots_are_distinct() {
+        let pool = SetPool::new(full_set(true));
+        let booster = pool.open(&mut StdRng::seed_from_u64(3));
+
+        let mut names: Vec<&str> = booster
+            .cards()
+            .iter()
+            .filter(|card| !card.foil())
+            .map(|card| card.card().card().name())
+            .collect();
+        let before = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), before);
+    }
+
+    #[test]
+    fn test_mythics_and_foils_turn_up() {
+        let pool = SetPool::new(full_set(true));
+        let mut rng = StdRng::seed_from_u64(4);
+        let boosters: Vec<Booster> = (0..200).map(|_| pool.open(&mut rng)).collect();
+
+        assert!(boosters
+            .iter()
+            .any(|booster| booster.rare().unwrap().rarity() == Rarity::Mythic));
+        assert!(boosters
+            .iter()
+            .any(|booster| booster.cards().iter().any(PackCard::foil)));
+    }
+
+    #[test]
+    fn test_can_open() {
+        assert!(SetPool::new(full_set(false)).can_open());
+        assert!(!SetPool::new(set(20, Rarity::Common)).can_open());
+        assert!(!SetPool::new(Vec::new()).can_open());
+    }
+
+    #[test]
+    fn test_sealed_pool() {
+        let pool = SetPool::new(full_set(true));
+        let sealed = SealedPool::open(&pool, &mut StdRng::seed_from_u64(5));
+
+        assert_eq!(sealed.packs().len(), SEALED_PACKS);
+        assert_eq!(sealed.set_name(), "Limited Edition Alpha");
+        assert!(sealed.rares().len() >= SEALED_PACKS);
+
+        let export = sealed.export();
+        let total: usize = export
+            .lines()
+            .map(|line| line.split_once(' ').unwrap().0.parse::<usize>().unwrap())
+            .sum();
+        assert_eq!(total, SEALED_PACKS * 14);
+        assert!(!export.contains("Forest"));
+    }
+
+    #[test]
+    fn test_export_counts_duplicates() {
+        let pool = SetPool::new(full_set(false));
+        let sealed = SealedPool {
+            packs: vec![pool.open(&mut StdRng::seed_from_u64(6))],
+        };
+        let doubled = SealedPool {
+            packs: vec![
+                pool.open(&mut StdRng::seed_from_u64(6)),
+                pool.open(&mut StdRng::seed_from_u64(6)),
+            ],
+        };
+
+        let first_line = sealed.export().lines().next().unwrap().to_string();
+        let (_, name) = first_line.split_once(' ').unwrap();
+        assert!(doubled.export().contains(&format!("2 {name}")));
+    }
+}
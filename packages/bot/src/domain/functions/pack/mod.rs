@@ -0,0 +1,2 @@
+pub mod booster;
+pub(crate) mod open;
@@ -0,0 +1,246 @@
+use crate::domain::functions::pack::booster::{SealedPool, SetPool};
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::PackInteraction;
+
+impl_app! {
+    /// Resolves `set` and gathers its cards, replying with why not if there
+    /// aren't enough to open a pack.
+    async fn set_pool<I: PackInteraction>(&self, interaction: &I, set: &str) -> Option<SetPool> {
+        let Some(set_name) = self.resolve_set(set).await else {
+            if let Err(why) = interaction
+                .reply(Message::SetNotFound { set_name: set }.text(interaction.locale()))
+                .await {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return None;
+        };
+
+        let pool = SetPool::new(self.card_store.set_cards(&set_name).await.unwrap_or_default());
+        if !pool.can_open() {
+            if let Err(why) = interaction
+                .reply(Message::SetTooSmall { set_name: &set_name }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return None;
+        }
+
+        Some(pool)
+    }
+
+    pub async fn pack_command<I: PackInteraction>(&self, interaction: &I, options: PackOptions) {
+        let Some(pool) = self.set_pool(interaction, &options.set).await else {
+            return;
+        };
+
+        let booster = pool.open(&mut rand::rng());
+        let image = match booster.rare() {
+            Some(rare) => self.image_store.fetch(rare.card()).await.ok(),
+            None => None,
+        };
+
+        if let Err(why) = interaction.send_pack(booster, image).await {
+            log::warn!("couldn't send booster: {why}");
+        }
+    }
+
+    pub async fn sealed_command<I: PackInteraction>(&self, interaction: &I, options: PackOptions) {
+        let Some(pool) = self.set_pool(interaction, &options.set).await else {
+            return;
+        };
+
+        let sealed = SealedPool::open(&pool, &mut rand::rng());
+        if let Err(why) = interaction.send_sealed(sealed).await {
+            log::warn!("couldn't send sealed pool: {why}");
+        }
+    }
+}
+
+pub struct PackOptions {
+    set: String,
+}
+
+impl PackOptions {
+    pub fn new(set: String) -> Self {
+        Self { set }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::functions::pack::booster::{Booster, SEALED_PACKS};
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockPackInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::card::Card;
+    use contracts::image::Image;
+    use contracts::set_card::{Rarity, SetCard};
+    use mockall::predicate::*;
+    use uuid::{uuid, Uuid};
+
+    fn set_card(name: &str, rarity: Rarity) -> SetCard {
+        let card = Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            String::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            String::new(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        SetCard::new(card, rarity)
+    }
+
+    fn set_cards() -> Vec<SetCard> {
+        let mut cards: Vec<SetCard> = (0..15)
+            .map(|i| set_card(&format!("Common {i}"), Rarity::Common))
+            .collect();
+        cards.extend((0..5).map(|i| set_card(&format!("Uncommon {i}"), Rarity::Uncommon)));
+        cards.push(set_card("Black Lotus", Rarity::Rare));
+        cards
+    }
+
+    fn card_store(cards: Vec<SetCard>) -> MockCardStore {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_set_name_from_abbreviation()
+            .times(1)
+            .with(eq("lea"))
+            .return_const(Some(String::from("limited edition alpha")));
+        card_store
+            .expect_set_cards()
+            .times(1)
+            .with(eq("limited edition alpha"))
+            .return_const(Some(cards));
+        card_store
+    }
+
+    #[tokio::test]
+    async fn test_pack() {
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .times(1)
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+
+        let mut interaction = MockPackInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_pack()
+            .times(1)
+            .withf(|booster: &Booster, image| {
+                booster.cards().len() == 15
+                    && booster.rare().unwrap().card().name() == "Black Lotus"
+                    && image.is_some()
+            })
+            .returning(|_, _| Ok(()));
+
+        let app = App::new(
+            image_store,
+            TestCardStore::new(card_store(set_cards())),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.pack_command(&interaction, PackOptions::new(String::from("lea")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_sealed() {
+        let mut interaction = MockPackInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_sealed()
+            .times(1)
+            .withf(|sealed: &SealedPool| {
+                sealed.packs().len() == SEALED_PACKS
+                    && sealed.rares().len() >= SEALED_PACKS
+                    && sealed.export().contains("Black Lotus")
+            })
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store(set_cards())),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.sealed_command(&interaction, PackOptions::new(String::from("lea")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_set_too_small() {
+        let mut interaction = MockPackInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_pack().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "'limited edition alpha' doesn't have enough cards to make a booster",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store(vec![set_card("Black Lotus", Rarity::Rare)])),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.pack_command(&interaction, PackOptions::new(String::from("lea")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_unknown_set() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_set_name_from_abbreviation()
+            .times(1)
+            .return_const(None);
+        card_store.expect_set_cards().times(0);
+
+        let mut interaction = MockPackInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_sealed().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Could not find set 'zzz'")))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.sealed_command(&interaction, PackOptions::new(String::from("zzz")))
+            .await;
+    }
+}
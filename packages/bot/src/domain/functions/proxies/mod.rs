@@ -0,0 +1,2 @@
+pub mod pdf;
+pub(crate) mod print;
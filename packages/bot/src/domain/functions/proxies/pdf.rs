@@ -0,0 +1,270 @@
+use crate::domain::utils::raster::Raster;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::io::Write;
+
+/// Points per millimetre; PDF measures everything in points (1/72 inch).
+const PT_PER_MM: f64 = 72.0 / 25.4;
+/// A standard Magic card is 63 × 88 mm.
+const CARD_WIDTH: f64 = 63.0 * PT_PER_MM;
+const CARD_HEIGHT: f64 = 88.0 * PT_PER_MM;
+pub const CARDS_PER_ROW: usize = 3;
+pub const CARDS_PER_PAGE: usize = CARDS_PER_ROW * CARDS_PER_ROW;
+/// Cut marks are hairlines, running from the grid out to the page edge.
+const CUT_LINE_WIDTH: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Paper {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl Paper {
+    /// Width and height in points.
+    fn size(self) -> (f64, f64) {
+        match self {
+            Paper::A4 => (210.0 * PT_PER_MM, 297.0 * PT_PER_MM),
+            Paper::Letter => (612.0, 792.0),
+        }
+    }
+
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Paper::A4 => "A4",
+            Paper::Letter => "Letter",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "A4" => Some(Paper::A4),
+            "Letter" => Some(Paper::Letter),
+            _ => None,
+        }
+    }
+}
+
+/// A card image ready to embed: flattened onto white, halved in size (the
+/// stored scans print at around 300 DPI, which makes for PDFs too big to
+/// upload) and deflated.
+pub struct ProxyImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl ProxyImage {
+    /// `None` if `bytes` isn't a PNG that can be decoded.
+    #[must_use]
+    pub fn from_png(bytes: &[u8]) -> Option<Self> {
+        let raster = Raster::decode_png(bytes)?.halve();
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(&raster.flatten()).ok()?;
+        Some(Self {
+            width: u32::try_from(raster.width()).ok()?,
+            height: u32::try_from(raster.height()).ok()?,
+            pixels: encoder.finish().ok()?,
+        })
+    }
+}
+
+/// Appends numbered objects and remembers where each starts, for the
+/// cross-reference table at the end.
+struct PdfWriter {
+    bytes: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl PdfWriter {
+    fn new() -> Self {
+        Self {
+            bytes: b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec(),
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Objects must be written in order, starting at 1.
+    fn object(&mut self, dictionary: &str) {
+        self.offsets.push(self.bytes.len());
+        let id = self.offsets.len();
+        self.bytes
+            .extend_from_slice(format!("{id} 0 obj\n{dictionary}\nendobj\n").as_bytes());
+    }
+
+    fn stream(&mut self, dictionary: &str, data: &[u8]) {
+        self.offsets.push(self.bytes.len());
+        let id = self.offsets.len();
+        let length = data.len();
+        self.bytes.extend_from_slice(
+            format!("{id} 0 obj\n<< {dictionary} /Length {length} >>\nstream\n").as_bytes(),
+        );
+        self.bytes.extend_from_slice(data);
+        self.bytes.extend_from_slice(b"\nendstream\nendobj\n");
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let xref = self.bytes.len();
+        let count = self.offsets.len() + 1;
+        let mut table = vec![format!("xref\n0 {count}\n0000000000 65535 f ")];
+        table.extend(
+            self.offsets
+                .iter()
+                .map(|offset| format!("{offset:010} 00000 n ")),
+        );
+        table.push(format!(
+            "trailer\n<< /Size {count} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n"
+        ));
+        self.bytes.extend_from_slice(table.join("\n").as_bytes());
+        self.bytes
+    }
+}
+
+/// Lays `cards` out nine to a page at true size, each entry an index into
+/// `images`, so repeated copies share one embedded image.
+#[must_use]
+pub fn render(images: &[ProxyImage], cards: &[usize], paper: Paper, cut_lines: bool) -> Vec<u8> {
+    let pages: Vec<&[usize]> = cards.chunks(CARDS_PER_PAGE).collect();
+    let (page_width, page_height) = paper.size();
+    let grid = f64::from(u32::try_from(CARDS_PER_ROW).unwrap_or(3));
+    let left = (page_width - CARD_WIDTH * grid) / 2.0;
+    let bottom = (page_height - CARD_HEIGHT * grid) / 2.0;
+
+    // 1 is the catalog and 2 the page tree, then the images, then a page
+    // and its contents for each page.
+    let first_image = 3;
+    let first_page = first_image + images.len();
+    let page_ids: Vec<String> = (0..pages.len())
+        .map(|page| format!("{} 0 R", first_page + page * 2))
+        .collect();
+
+    let mut pdf = PdfWriter::new();
+    pdf.object("<< /Type /Catalog /Pages 2 0 R >>");
+    pdf.object(&format!(
+        "<< /Type /Pages /Kids [{}] /Count {} >>",
+        page_ids.join(" "),
+        pages.len()
+    ));
+
+    for image in images {
+        pdf.stream(
+            &format!(
+                "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode",
+                image.width, image.height
+            ),
+            &image.pixels,
+        );
+    }
+
+    let resources: String = (0..images.len())
+        .map(|index| format!("/Im{index} {} 0 R", first_image + index))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    for (page, cards) in pages.iter().enumerate() {
+        let mut content = Vec::new();
+        for (slot, image) in cards.iter().enumerate() {
+            let column = f64::from(u32::try_from(slot % CARDS_PER_ROW).unwrap_or_default());
+            let row = f64::from(u32::try_from(slot / CARDS_PER_ROW).unwrap_or_default());
+            let x = left + column * CARD_WIDTH;
+            let y = bottom + (grid - 1.0 - row) * CARD_HEIGHT;
+            content.push(format!(
+                "q {CARD_WIDTH:.3} 0 0 {CARD_HEIGHT:.3} {x:.3} {y:.3} cm /Im{image} Do Q"
+            ));
+        }
+
+        if cut_lines {
+            content.push(format!("{CUT_LINE_WIDTH} w 0.5 G"));
+            let top = bottom + CARD_HEIGHT * grid;
+            let right = left + CARD_WIDTH * grid;
+            for step in 0..=CARDS_PER_ROW {
+                let step = f64::from(u32::try_from(step).unwrap_or_default());
+                let x = left + step * CARD_WIDTH;
+                let y = bottom + step * CARD_HEIGHT;
+                content.push(format!("{x:.3} 0 m {x:.3} {bottom:.3} l S"));
+                content.push(format!("{x:.3} {top:.3} m {x:.3} {page_height:.3} l S"));
+                content.push(format!("0 {y:.3} m {left:.3} {y:.3} l S"));
+                content.push(format!("{right:.3} {y:.3} m {page_width:.3} {y:.3} l S"));
+            }
+        }
+
+        pdf.object(&format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {page_width:.3} {page_height:.3}] /Resources << /XObject << {resources} >> >> /Contents {} 0 R >>",
+            first_page + page * 2 + 1
+        ));
+        pdf.stream("", content.join("\n").as_bytes());
+    }
+
+    pdf.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32, colour: png::ColorType, pixel: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(colour);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        let data: Vec<u8> = pixel
+            .iter()
+            .copied()
+            .cycle()
+            .take(pixel.len() * usize::try_from(width * height).unwrap())
+            .collect();
+        writer.write_image_data(&data).unwrap();
+        writer.finish().unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_decodes_rgba_png() {
+        let image =
+            ProxyImage::from_png(&png(4, 6, png::ColorType::Rgba, &[255, 0, 0, 255])).unwrap();
+        assert_eq!((image.width, image.height), (2, 3));
+        assert!(ProxyImage::from_png(b"not a png").is_none());
+    }
+
+    #[test]
+    fn test_render_pages() {
+        let image = ProxyImage::from_png(&png(4, 4, png::ColorType::Rgb, &[0, 0, 255])).unwrap();
+        let pdf = render(&[image], &[0; 10], Paper::Letter, true);
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/Count 2"));
+        assert!(text.contains("/MediaBox [0 0 612.000 792.000]"));
+        assert_eq!(text.matches("/Im0 Do").count(), 10);
+        assert!(text.contains(" l S"));
+    }
+
+    #[test]
+    fn test_cut_lines_are_optional() {
+        let image = ProxyImage::from_png(&png(4, 4, png::ColorType::Rgb, &[0, 0, 255])).unwrap();
+        let text = String::from_utf8_lossy(&render(&[image], &[0], Paper::A4, false)).to_string();
+        assert!(!text.contains(" l S"));
+        assert!(text.contains("/MediaBox [0 0 595.276 841.890]"));
+    }
+
+    #[test]
+    fn test_xref_offsets_point_at_objects() {
+        let image = ProxyImage::from_png(&png(2, 2, png::ColorType::Rgb, &[0, 0, 255])).unwrap();
+        let pdf = render(&[image], &[0], Paper::A4, false);
+        let text = String::from_utf8_lossy(&pdf);
+        let xref = text.find("xref\n").unwrap();
+        let offsets: Vec<usize> = text[xref..]
+            .lines()
+            .filter(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+
+        for (index, offset) in offsets.iter().enumerate() {
+            assert!(pdf[*offset..].starts_with(format!("{} 0 obj", index + 1).as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use crate::domain::card_list;
+use crate::domain::functions::proxies::pdf::{self, Paper, ProxyImage};
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::ProxyInteraction;
+use contracts::card::Card;
+
+/// Eight pages' worth; much more and the PDF won't fit in an upload.
+pub const MAX_COPIES: u32 = 72;
+/// Discord's upload limit for servers without boosts.
+const UPLOAD_LIMIT: usize = 10 * 1024 * 1024;
+
+impl_app! {
+    async fn reply_proxies<I: ProxyInteraction>(&self, interaction: &I, message: Message<'_>) {
+        if let Err(why) = interaction.reply(message.text(interaction.locale())).await {
+            log::warn!("couldn't create interaction response: {why}");
+        }
+    }
+
+    pub async fn proxies_command<I: ProxyInteraction>(&self, interaction: &I, options: ProxyOptions) {
+        let lines = card_list::parse(&options.list);
+        if let Err(denied) = self.rate_limit_lines(interaction.requester(), lines.len()).await {
+            self.reply_proxies(interaction, denied).await;
+            return;
+        }
+
+        let resolved = self.resolve_card_list(lines).await;
+        if resolved.cards().is_empty() {
+            self.reply_proxies(interaction, Message::NoCardsInList).await;
+            return;
+        }
+
+        // Double-faced cards print their back face alongside the front, so
+        // the backs can be sleeved behind them.
+        let mut faces: Vec<(Card, u32)> = Vec::new();
+        for listed in resolved.cards() {
+            faces.push((listed.card().clone(), listed.count()));
+            if let Some(back_id) = listed.card().back_id() {
+                match self.card_store.fetch_card_by_id(back_id).await {
+                    Some(back) => faces.push((back, listed.count())),
+                    None => log::warn!("couldn't find the back of {}", listed.card().name()),
+                }
+            }
+        }
+
+        // Saturating, so a huge count can't wrap round to something small
+        // enough to get past the limit.
+        let copies = faces
+            .iter()
+            .map(|(_, count)| *count)
+            .fold(0, u32::saturating_add);
+        if copies > MAX_COPIES {
+            self.reply_proxies(
+                interaction,
+                Message::TooManyProxies {
+                    copies,
+                    max: MAX_COPIES,
+                },
+            )
+            .await;
+            return;
+        }
+
+        let mut skipped = resolved.unresolved().to_vec();
+        let mut images = Vec::with_capacity(faces.len());
+        for (card, count) in faces {
+            match self.image_store.fetch(&card).await {
+                Ok(image) => images.push((card, count, image)),
+                Err(why) => {
+                    log::warn!("no image to print for {}: {why}", card.name());
+                    skipped.push(card.name().to_string());
+                }
+            }
+        }
+
+        // Decoding and compressing the scans takes a while, so it's kept off
+        // the async workers.
+        let paper = options.paper;
+        let cut_lines = options.cut_lines;
+        let rendered = tokio::task::spawn_blocking(move || {
+            let mut proxies = Vec::with_capacity(images.len());
+            let mut layout = Vec::new();
+            let mut undecodable = Vec::new();
+            for (card, count, image) in images {
+                let Some(proxy) = ProxyImage::from_png(image.bytes()) else {
+                    undecodable.push(card.name().to_string());
+                    continue;
+                };
+                let count = usize::try_from(count).unwrap_or_default();
+                layout.extend(std::iter::repeat_n(proxies.len(), count));
+                proxies.push(proxy);
+            }
+            (pdf::render(&proxies, &layout, paper, cut_lines), layout.len(), undecodable)
+        })
+        .await;
+
+        let (pdf, printed, undecodable) = match rendered {
+            Ok(rendered) => rendered,
+            Err(why) => {
+                log::warn!("couldn't render proxies: {why}");
+                self.reply_proxies(interaction, Message::ProxiesUnavailable).await;
+                return;
+            }
+        };
+        skipped.extend(undecodable);
+
+        if printed == 0 {
+            self.reply_proxies(interaction, Message::NoProxyImages).await;
+            return;
+        }
+        if pdf.len() > UPLOAD_LIMIT {
+            self.reply_proxies(
+                interaction,
+                Message::ProxiesTooBig { printed },
+            )
+            .await;
+            return;
+        }
+
+        if let Err(why) = interaction.send_pdf(pdf, skipped).await {
+            log::warn!("couldn't send proxies: {why}");
+        }
+    }
+}
+
+/// A pasted card list and how to lay it out.
+pub struct ProxyOptions {
+    list: String,
+    paper: Paper,
+    cut_lines: bool,
+}
+
+impl ProxyOptions {
+    pub fn new(list: String, paper: Paper, cut_lines: bool) -> Self {
+        Self {
+            list,
+            paper,
+            cut_lines,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::domain::rate_limit::{expect_unlimited, Requester};
+    use crate::ports::drivers::client::MockProxyInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::{ImageRetrievalError, MockImageStore};
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::image::Image;
+    use mockall::predicate::*;
+    use uuid::{uuid, Uuid};
+
+    const BOLT: Uuid = uuid!("00000000-0000-0000-0000-000000000001");
+    const DELVER: Uuid = uuid!("00000000-0000-0000-0000-000000000002");
+    const INSECTILE: Uuid = uuid!("00000000-0000-0000-0000-000000000003");
+    const LOTUS: Uuid = uuid!("00000000-0000-0000-0000-000000000004");
+
+    fn card(id: Uuid, name: &str, back_id: Option<Uuid>) -> Card {
+        Card::new(
+            id,
+            name.to_string(),
+            name.to_lowercase(),
+            id,
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            String::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+            String::from("Instant"),
+            String::new(),
+            back_id,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn png() -> Image {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, 2, 2);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[255; 12]).unwrap();
+        writer.finish().unwrap();
+        Image::new(bytes)
+    }
+
+    fn card_store() -> MockCardStore {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search()
+            .with(eq("lightning bolt"))
+            .return_const(Some(vec![card(BOLT, "Lightning Bolt", None)]));
+        card_store
+            .expect_search()
+            .with(eq("delver of secrets"))
+            .return_const(Some(vec![card(
+                DELVER,
+                "Delver of Secrets",
+                Some(INSECTILE),
+            )]));
+        card_store
+            .expect_search()
+            .with(eq("black lotus"))
+            .return_const(Some(vec![card(LOTUS, "Black Lotus", None)]));
+        card_store
+            .expect_search()
+            .with(eq("not a card"))
+            .return_const(Some(Vec::new()));
+        card_store
+            .expect_fetch_card_by_id()
+            .with(eq(INSECTILE))
+            .return_const(Some(card(INSECTILE, "Insectile Aberration", None)));
+        card_store
+    }
+
+    fn image_store() -> MockImageStore {
+        let mut image_store = MockImageStore::new();
+        image_store.expect_fetch().returning(|card| {
+            if card.id() == &LOTUS {
+                Err(ImageRetrievalError::new(String::from("missing")))
+            } else {
+                Ok(png())
+            }
+        });
+        image_store
+    }
+
+    fn app(
+        image_store: MockImageStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
+        App::new(
+            image_store,
+            TestCardStore::new(card_store()),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_prints_fronts_and_backs() {
+        let mut interaction = MockProxyInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_pdf()
+            .times(1)
+            .withf(|pdf, skipped| {
+                let text = String::from_utf8_lossy(pdf);
+                pdf.starts_with(b"%PDF")
+                    && text.contains("/Count 2")
+                    && text.matches(" Do Q").count() == 10
+                    && text.matches("/Subtype /Image").count() == 3
+                    && *skipped == ["1 Not a card", "Black Lotus"]
+            })
+            .returning(|_, _| Ok(()));
+
+        app(image_store())
+            .proxies_command(
+                &interaction,
+                ProxyOptions::new(
+                    String::from(
+                        "4 Lightning Bolt\n3 Delver of Secrets\n1 Black Lotus\n1 Not a card",
+                    ),
+                    Paper::A4,
+                    true,
+                ),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_too_many_copies() {
+        let mut interaction = MockProxyInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_pdf().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "That's 80 cards to print; the most I can fit in one PDF is 72",
+            )))
+            .returning(|_| Ok(()));
+
+        app(MockImageStore::new())
+            .proxies_command(
+                &interaction,
+                ProxyOptions::new(String::from("40 Delver of Secrets"), Paper::Letter, false),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_no_images() {
+        let mut interaction = MockProxyInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_pdf().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't find images for any of those cards",
+            )))
+            .returning(|_| Ok(()));
+
+        app(image_store())
+            .proxies_command(
+                &interaction,
+                ProxyOptions::new(String::from("4 Black Lotus"), Paper::A4, false),
+            )
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_nothing_resolves() {
+        let mut interaction = MockProxyInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Couldn't find any cards in that list")))
+            .returning(|_| Ok(()));
+
+        app(MockImageStore::new())
+            .proxies_command(
+                &interaction,
+                ProxyOptions::new(String::from("1 Not a card"), Paper::A4, true),
+            )
+            .await;
+    }
+}
@@ -0,0 +1,225 @@
+use crate::domain::i18n::Message;
+use crate::domain::utils;
+use crate::impl_app;
+use crate::ports::drivers::client::RulesInteraction;
+use contracts::rule_section::RuleSection;
+
+/// A rule of the Comprehensive Rules and the rules directly under it.
+pub struct RuleEntry {
+    section: RuleSection,
+    subsections: Vec<RuleSection>,
+}
+
+impl RuleEntry {
+    /// Takes sections as `rule_sections` returns them, the rule first.
+    #[must_use]
+    pub fn new(mut sections: Vec<RuleSection>) -> Option<Self> {
+        if sections.is_empty() {
+            return None;
+        }
+        let section = sections.remove(0);
+        Some(Self {
+            section,
+            subsections: sections,
+        })
+    }
+
+    #[must_use]
+    pub fn section(&self) -> &RuleSection {
+        &self.section
+    }
+
+    #[must_use]
+    pub fn subsections(&self) -> &[RuleSection] {
+        &self.subsections
+    }
+}
+
+/// `query` as a rule number — `7`, `702`, `702.19` or `702.19b` — if it
+/// is one. Case and a trailing dot don't matter.
+fn rule_number(query: &str) -> Option<String> {
+    let number = query.trim().trim_end_matches('.').to_lowercase();
+    let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    let valid = match number.split_once('.') {
+        None => all_digits(&number) && (number.len() == 1 || number.len() == 3),
+        Some((chapter, rule)) => {
+            let rule = rule
+                .strip_suffix(|c: char| c.is_ascii_lowercase())
+                .unwrap_or(rule);
+            chapter.len() == 3 && all_digits(chapter) && all_digits(rule)
+        }
+    };
+    valid.then_some(number)
+}
+
+impl_app! {
+    async fn fuzzy_match_rule_title(&self, normalised_title: &str) -> Option<String> {
+        let potentials = self
+            .card_store
+            .search_for_rule_title(normalised_title)
+            .await?;
+        fuzzy::winkliest_match(&normalised_title, potentials)
+    }
+
+    /// Looks a rule up by number, or else by the keyword or heading it's
+    /// named for.
+    async fn find_rule(&self, query: &str) -> Option<RuleEntry> {
+        let number = if let Some(number) = rule_number(query) {
+            number
+        } else {
+            let title = self
+                .fuzzy_match_rule_title(&utils::normalise_card_name(query))
+                .await?;
+            self.card_store.rule_number(&title).await?
+        };
+        RuleEntry::new(self.card_store.rule_sections(&number).await?)
+    }
+
+    pub async fn cr_command<I: RulesInteraction>(&self, interaction: &I, options: CrOptions) {
+        let Some(entry) = self.find_rule(&options.query).await else {
+            if let Err(why) = interaction
+                .reply(Message::RuleNotFound { query: &options.query }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+
+        if let Err(why) = interaction.send_rule(entry).await {
+            log::warn!("couldn't send rule: {why}");
+        }
+    }
+}
+
+pub struct CrOptions {
+    query: String,
+}
+
+impl CrOptions {
+    pub fn new(query: String) -> Self {
+        Self { query }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockRulesInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use mockall::predicate::eq;
+
+    fn trample() -> Vec<RuleSection> {
+        vec![
+            RuleSection::new(
+                String::from("702.19"),
+                Some(String::from("Trample")),
+                String::from("Trample"),
+            ),
+            RuleSection::new(
+                String::from("702.19a"),
+                None,
+                String::from("Trample is a static ability that modifies the rules for assigning combat damage."),
+            ),
+        ]
+    }
+
+    fn app(
+        card_store: MockCardStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[test]
+    fn test_rule_number() {
+        assert_eq!(rule_number("702.19b").as_deref(), Some("702.19b"));
+        assert_eq!(rule_number(" 702.19B. ").as_deref(), Some("702.19b"));
+        assert_eq!(rule_number("702").as_deref(), Some("702"));
+        assert_eq!(rule_number("7").as_deref(), Some("7"));
+        assert_eq!(rule_number("70"), None);
+        assert_eq!(rule_number("702.ab"), None);
+        assert_eq!(rule_number("trample"), None);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_by_number() {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_search_for_rule_title().times(0);
+        card_store
+            .expect_rule_sections()
+            .with(eq("702.19"))
+            .return_const(Some(trample()));
+
+        let mut interaction = MockRulesInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_rule()
+            .times(1)
+            .withf(|entry| entry.section().number() == "702.19" && entry.subsections().len() == 1)
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .cr_command(&interaction, CrOptions::new(String::from("702.19")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_lookup_by_keyword() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_search_for_rule_title()
+            .with(eq("tramlpe"))
+            .return_const(Some(vec![String::from("trample"), String::from("tap")]));
+        card_store
+            .expect_rule_number()
+            .with(eq("trample"))
+            .return_const(Some(String::from("702.19")));
+        card_store
+            .expect_rule_sections()
+            .with(eq("702.19"))
+            .return_const(Some(trample()));
+
+        let mut interaction = MockRulesInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_rule()
+            .times(1)
+            .withf(|entry| entry.section().title() == Some("Trample"))
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .cr_command(&interaction, CrOptions::new(String::from("Tramlpe")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_unknown_rule() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_rule_sections()
+            .return_const(Some(Vec::new()));
+
+        let mut interaction = MockRulesInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_rule().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Could not find a rule for '999.99'")))
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .cr_command(&interaction, CrOptions::new(String::from("999.99")))
+            .await;
+    }
+}
@@ -0,0 +1,312 @@
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::SetInteraction;
+use contracts::set_card::{Rarity, SetCard};
+use contracts::set_details::SetDetails;
+
+/// Discord's limit on the options in a select menu.
+pub const CARDS_PER_PAGE: usize = 25;
+
+/// Mythics first, down to commons, with the odds and ends after.
+fn scarcity(rarity: Rarity) -> u8 {
+    match rarity {
+        Rarity::Mythic => 0,
+        Rarity::Rare => 1,
+        Rarity::Uncommon => 2,
+        Rarity::Common => 3,
+        Rarity::Special => 4,
+        Rarity::Bonus => 5,
+    }
+}
+
+/// A set and its cards, rarest first, open at one page of them.
+pub struct SetPage {
+    details: SetDetails,
+    cards: Vec<SetCard>,
+    page: usize,
+}
+
+impl SetPage {
+    /// A `page` past the end opens the last one.
+    #[must_use]
+    pub fn new(details: SetDetails, mut cards: Vec<SetCard>, page: usize) -> Self {
+        cards.sort_by(|a, b| {
+            scarcity(a.rarity())
+                .cmp(&scarcity(b.rarity()))
+                .then_with(|| a.card().name().cmp(b.card().name()))
+        });
+        let pages = cards.len().div_ceil(CARDS_PER_PAGE).max(1);
+        Self {
+            details,
+            cards,
+            page: page.min(pages - 1),
+        }
+    }
+
+    #[must_use]
+    pub fn details(&self) -> &SetDetails {
+        &self.details
+    }
+
+    #[must_use]
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    #[must_use]
+    pub fn pages(&self) -> usize {
+        self.cards.len().div_ceil(CARDS_PER_PAGE).max(1)
+    }
+
+    /// The cards on `page`, empty if there's no such page.
+    #[must_use]
+    pub fn cards_on(&self, page: usize) -> &[SetCard] {
+        self.cards
+            .chunks(CARDS_PER_PAGE)
+            .nth(page)
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn cards(&self) -> &[SetCard] {
+        self.cards_on(self.page)
+    }
+
+    /// Distinct cards found in the set, which can be fewer than its card
+    /// count — that counts every printing.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.cards.len()
+    }
+}
+
+impl_app! {
+    async fn send_set_page<I: SetInteraction>(&self, interaction: &I, set_name: &str, page: usize) {
+        let Some(details) = self.card_store.set_details(set_name).await else {
+            if let Err(why) = interaction
+                .reply(Message::SetNotFound { set_name }.text(interaction.locale()))
+                .await {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+        let cards = self.card_store.set_cards(set_name).await.unwrap_or_default();
+
+        if let Err(why) = interaction.send_set(SetPage::new(details, cards, page)).await {
+            log::warn!("couldn't send set: {why}");
+        }
+    }
+
+    pub async fn set_command<I: SetInteraction>(&self, interaction: &I, options: SetOptions) {
+        let Some(set_name) = self.resolve_set(&options.set).await else {
+            if let Err(why) = interaction
+                .reply(Message::SetNotFound { set_name: &options.set }.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+        self.send_set_page(interaction, &set_name, 0).await;
+    }
+
+    /// Turns to another page of a set already on screen.
+    pub async fn set_page<I: SetInteraction>(&self, interaction: &I, options: SetPageOptions) {
+        let Some(set_name) = self.set_from_abbreviation(&options.abbreviation).await else {
+            if let Err(why) = interaction
+                .reply(
+                    Message::SetNotFound {
+                        set_name: &options.abbreviation,
+                    }
+                    .text(interaction.locale()),
+                )
+                .await
+            {
+                log::warn!("couldn't create interaction response: {why}");
+            }
+            return;
+        };
+        self.send_set_page(interaction, &set_name, options.page).await;
+    }
+}
+
+pub struct SetOptions {
+    set: String,
+}
+
+impl SetOptions {
+    pub fn new(set: String) -> Self {
+        Self { set }
+    }
+}
+
+pub struct SetPageOptions {
+    abbreviation: String,
+    page: usize,
+}
+
+impl SetPageOptions {
+    pub fn new(abbreviation: String, page: usize) -> Self {
+        Self { abbreviation, page }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockSetInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::card::Card;
+    use mockall::predicate::eq;
+    use uuid::{uuid, Uuid};
+
+    fn set_card(name: &str, rarity: Rarity) -> SetCard {
+        let card = Card::new(
+            Uuid::new_v4(),
+            name.to_string(),
+            name.to_lowercase(),
+            Uuid::new_v4(),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            String::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            String::new(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        SetCard::new(card, rarity)
+    }
+
+    fn details() -> SetDetails {
+        SetDetails::new(
+            String::from("Limited Edition Alpha"),
+            String::from("lea"),
+            Some(String::from("core")),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).ok(),
+            Some(295),
+            Some(String::from("Core Set")),
+            None,
+        )
+    }
+
+    fn names(cards: &[SetCard]) -> Vec<&str> {
+        cards.iter().map(|card| card.card().name()).collect()
+    }
+
+    fn app(
+        card_store: MockCardStore,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    #[test]
+    fn test_sorts_rarest_first() {
+        let page = SetPage::new(
+            details(),
+            vec![
+                set_card("Giant Growth", Rarity::Common),
+                set_card("Black Lotus", Rarity::Rare),
+                set_card("Ancestral Recall", Rarity::Rare),
+                set_card("Plains", Rarity::Special),
+                set_card("Counterspell", Rarity::Uncommon),
+            ],
+            0,
+        );
+        assert_eq!(
+            names(page.cards()),
+            vec![
+                "Ancestral Recall",
+                "Black Lotus",
+                "Counterspell",
+                "Giant Growth",
+                "Plains"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pages() {
+        let cards = (0..30)
+            .map(|i| set_card(&format!("Common {i:02}"), Rarity::Common))
+            .collect();
+        let page = SetPage::new(details(), cards, 1);
+        assert_eq!((page.page(), page.pages(), page.total()), (1, 2, 30));
+        assert_eq!(page.cards().len(), 5);
+        assert_eq!(page.cards()[0].card().name(), "Common 25");
+        assert!(page.cards_on(2).is_empty());
+    }
+
+    #[test]
+    fn test_page_past_the_end_opens_the_last() {
+        let page = SetPage::new(details(), Vec::new(), 3);
+        assert_eq!((page.page(), page.pages()), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_set_command() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_set_name_from_abbreviation()
+            .with(eq("lea"))
+            .return_const(Some(String::from("limited edition alpha")));
+        card_store
+            .expect_set_details()
+            .with(eq("limited edition alpha"))
+            .return_const(Some(details()));
+        card_store
+            .expect_set_cards()
+            .return_const(Some(vec![set_card("Black Lotus", Rarity::Rare)]));
+
+        let mut interaction = MockSetInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_send_set()
+            .times(1)
+            .withf(|page| page.details().abbreviation() == "lea" && page.total() == 1)
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .set_command(&interaction, SetOptions::new(String::from("lea")))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_unknown_set() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_set_name_from_abbreviation()
+            .return_const(None);
+
+        let mut interaction = MockSetInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction.expect_send_set().times(0);
+        interaction
+            .expect_reply()
+            .times(1)
+            .with(eq(String::from("Could not find set 'xyz'")))
+            .returning(|_| Ok(()));
+
+        app(card_store)
+            .set_command(&interaction, SetOptions::new(String::from("xyz")))
+            .await;
+    }
+}
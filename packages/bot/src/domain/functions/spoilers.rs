@@ -1,3 +1,4 @@
+use crate::domain::i18n::Message;
 use crate::ports::drivers::client::MessageInteraction;
 use crate::ports::services::spoiler_subscription::{SpoilerSubscription, Subscription};
 use cards_sdk::{ChannelId, GuildId, SpoilerQueue};
@@ -39,10 +40,12 @@ pub async fn subscribe<S, Sub, I>(
         .is_some()
     {
         let _ = interaction
-            .reply_ephemeral(format!(
-                "Spoiler notifications are already enabled for <#{}>.",
-                u64::from(channel_id)
-            ))
+            .reply_ephemeral(
+                Message::SpoilersAlreadyEnabled {
+                    channel: u64::from(channel_id),
+                }
+                .text(interaction.locale()),
+            )
             .await;
         return;
     }
@@ -53,21 +56,24 @@ pub async fn subscribe<S, Sub, I>(
                 .create_subscription(guild_id, channel_id, id, &token)
                 .await;
             let _ = interaction
-                .reply_ephemeral(format!(
-                    "Spoiler notifications enabled for <#{}>. This feature is in beta — \
-                     if anything looks wrong, please let us know here: {ISSUES_URL}",
-                    u64::from(channel_id)
-                ))
+                .reply_ephemeral(
+                    Message::SpoilersEnabled {
+                        channel: u64::from(channel_id),
+                        issues_url: ISSUES_URL,
+                    }
+                    .text(interaction.locale()),
+                )
                 .await;
         }
         Err(e) => {
             log::warn!("Failed to create spoiler webhook for guild {guild_id}: {e}");
             let _ = interaction
-                .reply_ephemeral(format!(
-                    "Couldn't create a webhook in that channel — I'm missing permissions. \
-                     Ask a server admin to re-authorize me here to grant what's missing: {}",
-                    reauthorize_url()
-                ))
+                .reply_ephemeral(
+                    Message::SpoilersMissingPermissions {
+                        reauthorize_url: &reauthorize_url(),
+                    }
+                    .text(interaction.locale()),
+                )
                 .await;
         }
     }
@@ -86,10 +92,12 @@ pub async fn unsubscribe<S, Sub, I>(
 {
     let Some(sub_id) = storage.subscription_id(guild_id, channel_id).await else {
         let _ = interaction
-            .reply_ephemeral(format!(
-                "Spoiler notifications disabled for <#{}>.",
-                u64::from(channel_id)
-            ))
+            .reply_ephemeral(
+                Message::SpoilersDisabled {
+                    channel: u64::from(channel_id),
+                }
+                .text(interaction.locale()),
+            )
             .await;
         return;
     };
@@ -114,26 +122,31 @@ pub async fn unsubscribe<S, Sub, I>(
                 "Failed to delete Discord webhook {sub_id} for guild {guild_id} channel {channel_id}, keeping subscription: {e}"
             );
             let _ = interaction
-                .reply_ephemeral(format!(
-                    "Couldn't reach Discord to remove the webhook for <#{}> — nothing changed, please try again shortly.",
-                    u64::from(channel_id)
-                ))
+                .reply_ephemeral(
+                    Message::SpoilersUnreachable {
+                        channel: u64::from(channel_id),
+                    }
+                    .text(interaction.locale()),
+                )
                 .await;
             return;
         }
     }
 
     let _ = interaction
-        .reply_ephemeral(format!(
-            "Spoiler notifications disabled for <#{}>.",
-            u64::from(channel_id)
-        ))
+        .reply_ephemeral(
+            Message::SpoilersDisabled {
+                channel: u64::from(channel_id),
+            }
+            .text(interaction.locale()),
+        )
         .await;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::i18n::Locale;
     use crate::ports::drivers::client::MockMessageInteraction;
     use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
     use cards_sdk::{MockSpoilerQueue, SubscriptionId};
@@ -144,6 +157,7 @@ mod tests {
         let mut sub = MockSpoilerSubscription::new();
         let mut storage = MockSpoilerQueue::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage
             .expect_subscription_id()
@@ -189,6 +203,7 @@ mod tests {
         let sub = MockSpoilerSubscription::new();
         let mut storage = MockSpoilerQueue::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage
             .expect_subscription_id()
@@ -222,6 +237,7 @@ mod tests {
         let mut sub = MockSpoilerSubscription::new();
         let mut storage = MockSpoilerQueue::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage.expect_subscription_id().times(1).return_const(None);
         sub.expect_create_subscription().times(1).returning(|_| {
@@ -248,6 +264,7 @@ mod tests {
         let mut storage = MockSpoilerQueue::new();
         let mut sub = MockSpoilerSubscription::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage
             .expect_subscription_id()
@@ -283,6 +300,7 @@ mod tests {
         let mut storage = MockSpoilerQueue::new();
         let sub = MockSpoilerSubscription::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage
             .expect_subscription_id()
@@ -311,6 +329,7 @@ mod tests {
         let mut storage = MockSpoilerQueue::new();
         let mut sub = MockSpoilerSubscription::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage
             .expect_subscription_id()
@@ -354,6 +373,7 @@ mod tests {
         let mut storage = MockSpoilerQueue::new();
         let mut sub = MockSpoilerSubscription::new();
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
 
         storage
             .expect_subscription_id()
@@ -0,0 +1,279 @@
+use crate::domain::functions::trivia::state;
+use crate::domain::functions::trivia::state::TriviaOutcome;
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
+use crate::impl_app;
+use crate::ports::drivers::client::TriviaInteraction;
+use cards_sdk::{Game, UserId};
+use uuid::Uuid;
+
+impl_app! {
+    pub async fn trivia_answer<I: TriviaInteraction>(&self, interaction: &I, options: TriviaAnswer) {
+        let TriviaAnswer { round, choice } = options;
+        let key = state::key(&interaction.id());
+        let lock = named_lock::LOCKS.get(&key).await;
+        let _guard = lock.lock().await;
+
+        // A newer `/trivia` in the same channel replaces the round, so the
+        // round id guards against answering an old question's buttons.
+        let Some(trivia_state) = state::fetch(key.clone(), &self.cache)
+            .await
+            .filter(|trivia_state| trivia_state.card().id() == &round)
+        else {
+            if let Err(why) = interaction
+                .reply_ephemeral(Message::QuestionAnswered.text(interaction.locale()))
+                .await
+            {
+                log::warn!("couldn't create interaction: {why}");
+            }
+            return;
+        };
+
+        state::delete(key, &self.cache).await;
+
+        let won = trivia_state.is_correct(choice);
+        let stats = self
+            .card_store
+            .record_result(UserId::from(interaction.user_id()), Game::Trivia, won)
+            .await;
+        if won {
+            metrics::record_game_won(Game::Trivia, None);
+        }
+
+        let Ok(image) = self.image_store.fetch(trivia_state.card()).await else {
+            log::warn!("couldn't fetch image");
+            return;
+        };
+
+        if let Err(why) = interaction
+            .send_outcome(TriviaOutcome::new(trivia_state, choice, stats), image)
+            .await
+        {
+            log::warn!("couldn't send trivia outcome: {why}");
+        }
+    }
+}
+
+/// The button a player pressed: which round it belongs to (the answer's
+/// card id) and which of the choices it was.
+pub struct TriviaAnswer {
+    round: Uuid,
+    choice: usize,
+}
+
+impl TriviaAnswer {
+    pub fn new(round: Uuid, choice: usize) -> Self {
+        Self { round, choice }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::functions::trivia::state::TriviaState;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockTriviaInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use cards_sdk::{GameStats, MockStatsRepository};
+    use contracts::card::Card;
+    use contracts::image::Image;
+    use mockall::predicate::*;
+    use uuid::uuid;
+
+    const USER_ID: u64 = 7;
+    const ROUND: Uuid = uuid!("12345678-1234-1234-1234-123456789012");
+
+    fn create_test_card() -> Card {
+        Card::new(
+            ROUND,
+            "Lightning Bolt".to_string(),
+            "lightning bolt".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            Some(uuid!("12345678-1234-1234-1234-123456789013")),
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            "Lightning Bolt deals 3 damage to any target.".to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn create_state() -> TriviaState {
+        TriviaState::new(
+            create_test_card(),
+            vec![
+                "Shock".to_string(),
+                "Chain Lightning".to_string(),
+                "Lightning Strike".to_string(),
+            ],
+            &mut rand::rng(),
+        )
+    }
+
+    fn mock_cache(state: &TriviaState) -> MockCache {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .times(1)
+            .with(eq(String::from("trivia:test_channel")))
+            .return_const(Some(ron::to_string(state).unwrap()));
+        cache
+    }
+
+    fn mock_interaction() -> MockTriviaInteraction {
+        let mut interaction = MockTriviaInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_id()
+            .return_const(String::from("test_channel"));
+        interaction.expect_user_id().return_const(USER_ID);
+        interaction
+    }
+
+    fn mock_image_store() -> MockImageStore {
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .times(1)
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+        image_store
+    }
+
+    fn expect_result(won: bool) -> MockStatsRepository {
+        let mut stats = MockStatsRepository::new();
+        stats
+            .expect_record_result()
+            .times(1)
+            .with(eq(UserId::from(USER_ID)), eq(Game::Trivia), eq(won))
+            .return_const(Some(GameStats {
+                played: 3,
+                won: 2,
+                streak: i32::from(won),
+                best_streak: 2,
+            }));
+        stats
+    }
+
+    #[tokio::test]
+    async fn test_correct_answer_wins() {
+        let state = create_state();
+        let choice = state.answer();
+
+        let mut cache = mock_cache(&state);
+        cache
+            .expect_delete()
+            .times(1)
+            .with(eq(String::from("trivia:test_channel")))
+            .returning(|_| Ok(()));
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_send_outcome()
+            .times(1)
+            .withf(|outcome: &TriviaOutcome, _| {
+                outcome.won() && outcome.stats().is_some_and(|stats| stats.streak == 1)
+            })
+            .returning(|_, _| Ok(()));
+
+        let app = App::new(
+            mock_image_store(),
+            TestCardStore::with_stats(MockCardStore::new(), expect_result(true)),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_answer(&interaction, TriviaAnswer::new(ROUND, choice))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_wrong_answer_loses() {
+        let state = create_state();
+        let choice = (state.answer() + 1) % state.choices().len();
+
+        let mut cache = mock_cache(&state);
+        cache.expect_delete().times(1).returning(|_| Ok(()));
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_send_outcome()
+            .times(1)
+            .withf(move |outcome: &TriviaOutcome, _| !outcome.won() && outcome.choice() == choice)
+            .returning(|_, _| Ok(()));
+
+        let app = App::new(
+            mock_image_store(),
+            TestCardStore::with_stats(MockCardStore::new(), expect_result(false)),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_answer(&interaction, TriviaAnswer::new(ROUND, choice))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_already_answered() {
+        let mut cache = MockCache::new();
+        cache.expect_get().times(1).return_const(None);
+        cache.expect_delete().times(0);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_outcome().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from("This question has already been answered.")))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(MockCardStore::new()),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_answer(&interaction, TriviaAnswer::new(ROUND, 0))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_stale_round_is_rejected() {
+        let state = create_state();
+        let cache = mock_cache(&state);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_outcome().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(MockCardStore::new()),
+            cache,
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_answer(
+            &interaction,
+            TriviaAnswer::new(uuid!("00000000-0000-0000-0000-0000000000ff"), 0),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,351 @@
+use crate::domain::functions::game::history;
+use crate::domain::functions::trivia::state;
+use crate::domain::functions::trivia::state::{Clue, TriviaState};
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
+use crate::impl_app;
+use crate::ports::drivers::client::TriviaInteraction;
+use cards_sdk::Game;
+use contracts::card::Card;
+use rand::seq::SliceRandom;
+
+const DECOYS: usize = 3;
+const DECOY_POOL: i64 = 12;
+
+impl_app! {
+    pub async fn trivia_command<I: TriviaInteraction>(&self, interaction: &I) {
+        let history_key = history::key(interaction.guild_id(), interaction.id());
+        let recent = history::fetch(history_key.clone(), &self.cache).await;
+
+        let Some(card) = self.card_store.random_card(&recent).await else {
+            log::warn!("Failed to get random card");
+            no_question(interaction).await;
+            return;
+        };
+        history::push(
+            recent,
+            *card.oracle_id(),
+            *history::HISTORY_SIZE,
+            history_key,
+            &self.cache,
+        )
+        .await;
+
+        let mut candidates = self.card_store.similar_cards(&card).await.unwrap_or_default();
+        if decoy_names(&card, &candidates).len() < DECOYS {
+            candidates.extend(
+                self.card_store
+                    .cards_like(&card, DECOY_POOL)
+                    .await
+                    .unwrap_or_default(),
+            );
+        }
+
+        let Some(decoys) = pick_decoys(&card, &candidates) else {
+            log::warn!("Not enough decoys for {}", card.name());
+            no_question(interaction).await;
+            return;
+        };
+
+        let trivia_state = TriviaState::new(card, decoys, &mut rand::rng());
+        let image = match trivia_state.clue() {
+            Clue::Illustration => {
+                let illustration = self.image_store.fetch_illustration(trivia_state.card()).await;
+                let Ok(image) = illustration else {
+                    log::warn!("couldn't fetch illustration");
+                    no_question(interaction).await;
+                    return;
+                };
+                Some(image)
+            }
+            Clue::RulesText => None,
+        };
+
+        state::add(&trivia_state, state::key(&interaction.id()), &self.cache).await;
+        metrics::record_game_started(Game::Trivia, None);
+
+        if let Err(why) = interaction.send_question(trivia_state, image).await {
+            log::warn!("couldn't send trivia question: {why}");
+        }
+    }
+}
+
+/// `/trivia` is deferred, so every way of not asking a question has to say
+/// so, or the player is left with Discord's "thinking…" for good.
+async fn no_question<I: TriviaInteraction>(interaction: &I) {
+    if let Err(why) = interaction
+        .reply_ephemeral(Message::NoQuestion.text(interaction.locale()))
+        .await
+    {
+        log::warn!("couldn't create interaction: {why}");
+    }
+}
+
+/// Candidate names in the order found, minus duplicates and the answer.
+fn decoy_names(card: &Card, candidates: &[Card]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        let name = candidate.name();
+        if name != card.name() && !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+fn pick_decoys(card: &Card, candidates: &[Card]) -> Option<Vec<String>> {
+    let mut names = decoy_names(card, candidates);
+    if names.len() < DECOYS {
+        return None;
+    }
+
+    names.shuffle(&mut rand::rng());
+    names.truncate(DECOYS);
+    Some(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockTriviaInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use contracts::image::Image;
+    use mockall::predicate::*;
+    use uuid::{uuid, Uuid};
+
+    fn make_test_card(name: &str, oracle_id: Uuid) -> Card {
+        Card::new(
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            name.to_string(),
+            name.to_lowercase(),
+            oracle_id,
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            Some(uuid!("12345678-1234-1234-1234-123456789013")),
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            format!("{name} deals 3 damage to any target."),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn answer() -> Card {
+        make_test_card(
+            "Lightning Bolt",
+            uuid!("00000000-0000-0000-0000-000000000001"),
+        )
+    }
+
+    fn decoy(name: &str) -> Card {
+        make_test_card(name, Uuid::new_v4())
+    }
+
+    fn mock_cache() -> MockCache {
+        let mut cache = MockCache::new();
+        cache.expect_get().return_const(None);
+        cache.expect_set().returning(|_, _| Ok(()));
+        cache
+    }
+
+    fn mock_interaction() -> MockTriviaInteraction {
+        let mut interaction = MockTriviaInteraction::new();
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+            .expect_id()
+            .return_const(String::from("test_channel"));
+        interaction.expect_guild_id().return_const(None);
+        interaction
+    }
+
+    fn mock_image_store() -> MockImageStore {
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch_illustration()
+            .times(0..=1)
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+        image_store
+    }
+
+    #[test]
+    fn test_decoy_names_skip_duplicates_and_answer() {
+        let candidates = vec![
+            decoy("Shock"),
+            decoy("Lightning Bolt"),
+            decoy("Shock"),
+            decoy("Chain Lightning"),
+        ];
+
+        assert_eq!(
+            decoy_names(&answer(), &candidates),
+            vec!["Shock".to_string(), "Chain Lightning".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_pick_decoys_needs_three() {
+        let candidates = vec![decoy("Shock"), decoy("Chain Lightning")];
+        assert!(pick_decoys(&answer(), &candidates).is_none());
+    }
+
+    #[test]
+    fn test_pick_decoys_takes_three() {
+        let candidates = vec![
+            decoy("Shock"),
+            decoy("Chain Lightning"),
+            decoy("Lightning Strike"),
+            decoy("Fireblast"),
+        ];
+        assert_eq!(pick_decoys(&answer(), &candidates).unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_trivia_from_similar_cards() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_card()
+            .times(1)
+            .return_const(Some(answer()));
+        card_store
+            .expect_similar_cards()
+            .times(1)
+            .return_const(Some(vec![
+                decoy("Shock"),
+                decoy("Chain Lightning"),
+                decoy("Lightning Strike"),
+            ]));
+        card_store.expect_cards_like().times(0);
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_send_question()
+            .times(1)
+            .withf(|state: &TriviaState, image: &Option<Image>| {
+                state.choices().len() == 4
+                    && state.choices()[state.answer()] == "Lightning Bolt"
+                    && (state.clue() == Clue::Illustration) == image.is_some()
+            })
+            .returning(|_, _| Ok(()));
+
+        let app = App::new(
+            mock_image_store(),
+            TestCardStore::new(card_store),
+            mock_cache(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_command(&interaction).await;
+    }
+
+    #[tokio::test]
+    async fn test_trivia_falls_back_to_same_set_and_colour() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_card()
+            .times(1)
+            .return_const(Some(answer()));
+        card_store
+            .expect_similar_cards()
+            .times(1)
+            .return_const(Some(vec![decoy("Shock")]));
+        card_store
+            .expect_cards_like()
+            .times(1)
+            .with(eq(answer()), eq(DECOY_POOL))
+            .return_const(Some(vec![decoy("Fireball"), decoy("Disintegrate")]));
+
+        let mut interaction = mock_interaction();
+        interaction
+            .expect_send_question()
+            .times(1)
+            .withf(|state: &TriviaState, _| {
+                ["Shock", "Fireball", "Disintegrate"]
+                    .iter()
+                    .all(|name| state.choices().iter().any(|choice| choice == name))
+            })
+            .returning(|_, _| Ok(()));
+
+        let app = App::new(
+            mock_image_store(),
+            TestCardStore::new(card_store),
+            mock_cache(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_command(&interaction).await;
+    }
+
+    #[tokio::test]
+    async fn test_trivia_not_enough_decoys() {
+        let mut card_store = MockCardStore::new();
+        card_store
+            .expect_random_card()
+            .times(1)
+            .return_const(Some(answer()));
+        card_store
+            .expect_similar_cards()
+            .times(1)
+            .return_const(None);
+        card_store
+            .expect_cards_like()
+            .times(1)
+            .return_const(Some(vec![decoy("Shock")]));
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_question().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't come up with a question this time, try again :(",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            mock_cache(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_command(&interaction).await;
+    }
+
+    #[tokio::test]
+    async fn test_trivia_without_a_card_says_so() {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_random_card().times(1).return_const(None);
+
+        let mut interaction = mock_interaction();
+        interaction.expect_send_question().times(0);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "Couldn't come up with a question this time, try again :(",
+            )))
+            .returning(|_| Ok(()));
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            mock_cache(),
+            MockSpoilerSubscription::new(),
+        );
+
+        app.trivia_command(&interaction).await;
+    }
+}
@@ -0,0 +1,313 @@
+use crate::ports::services::cache::Cache;
+use cards_sdk::GameStats;
+use contracts::card::Card;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// What the players are shown to identify the card by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Clue {
+    Illustration,
+    RulesText,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TriviaState {
+    card: Card,
+    choices: Vec<String>,
+    answer: usize,
+    clue: Clue,
+}
+
+impl TriviaState {
+    /// Shuffles the card's name in amongst `decoys`, and picks a clue —
+    /// always the rules text for a card without an illustration to show.
+    #[must_use]
+    pub fn new<R: Rng>(card: Card, decoys: Vec<String>, rng: &mut R) -> Self {
+        let mut choices = decoys;
+        choices.push(card.name().to_string());
+        choices.shuffle(rng);
+        let answer = choices
+            .iter()
+            .position(|choice| choice == card.name())
+            .unwrap_or_default();
+
+        let clue = if card.illustration_id().is_some() && rng.random_bool(0.5) {
+            Clue::Illustration
+        } else {
+            Clue::RulesText
+        };
+
+        Self {
+            card,
+            choices,
+            answer,
+            clue,
+        }
+    }
+
+    #[must_use]
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    #[must_use]
+    pub fn choices(&self) -> &[String] {
+        &self.choices
+    }
+
+    #[must_use]
+    pub fn answer(&self) -> usize {
+        self.answer
+    }
+
+    #[must_use]
+    pub fn clue(&self) -> Clue {
+        self.clue
+    }
+
+    #[must_use]
+    pub fn is_correct(&self, choice: usize) -> bool {
+        choice == self.answer
+    }
+
+    /// The card's rules text with its own name swapped for `~`, as printed
+    /// on older cards — otherwise the clue would give the answer away.
+    /// Legendary names are also redacted by their short form ("Urza" for
+    /// "Urza, Lord High Artificer").
+    #[must_use]
+    pub fn redacted_rules_text(&self) -> String {
+        let name = self.card.name();
+        let mut text = self.card.oracle_text().replace(name, "~");
+        if let Some((short_name, _)) = name.split_once(", ") {
+            text = text.replace(short_name, "~");
+        }
+        text
+    }
+}
+
+/// A finished round: who picked what, and their updated trivia stats (if
+/// they could be recorded).
+pub struct TriviaOutcome {
+    state: TriviaState,
+    choice: usize,
+    stats: Option<GameStats>,
+}
+
+impl TriviaOutcome {
+    #[must_use]
+    pub fn new(state: TriviaState, choice: usize, game_stats: Option<GameStats>) -> Self {
+        Self {
+            state,
+            choice,
+            stats: game_stats,
+        }
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &TriviaState {
+        &self.state
+    }
+
+    #[must_use]
+    pub fn choice(&self) -> usize {
+        self.choice
+    }
+
+    #[must_use]
+    pub fn won(&self) -> bool {
+        self.state.is_correct(self.choice)
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> Option<&GameStats> {
+        self.stats.as_ref()
+    }
+}
+
+/// Trivia rounds share the channel with the guessing game, so their cache
+/// entries are namespaced to keep the two from clobbering each other.
+#[must_use]
+pub fn key(channel_id: &str) -> String {
+    format!("trivia:{channel_id}")
+}
+
+pub async fn fetch<C: Cache + Send + Sync>(key: String, cache: &C) -> Option<TriviaState> {
+    let state_string = cache.get(key).await?;
+
+    match ron::from_str::<TriviaState>(&state_string) {
+        Ok(state) => Some(state),
+        Err(why) => {
+            log::warn!("Couldn't parse trivia state: {why}");
+            None
+        }
+    }
+}
+
+pub async fn delete<C: Cache + Send + Sync>(key: String, cache: &C) {
+    if let Err(why) = cache.delete(key).await {
+        log::warn!("Error deleting key from redis the response: {why:?}");
+    };
+}
+
+pub async fn add<C: Cache + Send + Sync>(state: &TriviaState, key: String, cache: &C) {
+    let ron_string = match ron::to_string(state) {
+        Ok(ron_string) => ron_string,
+        Err(err) => {
+            log::warn!("Error converting trivia state to string: {err}");
+            return;
+        }
+    };
+
+    if let Err(why) = cache.set(key, ron_string).await {
+        log::warn!("Error while trying to set value in redis: {why}");
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::services::cache::MockCache;
+    use mockall::predicate::eq;
+    use uuid::uuid;
+
+    fn create_test_card(name: &str, oracle_text: &str) -> Card {
+        Card::new(
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            name.to_string(),
+            name.to_lowercase(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            Some(uuid!("12345678-1234-1234-1234-123456789013")),
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            oracle_text.to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn decoys() -> Vec<String> {
+        vec![
+            "Chain Lightning".to_string(),
+            "Lightning Strike".to_string(),
+            "Shock".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_answer_points_at_the_card() {
+        let card = create_test_card("Lightning Bolt", "Deals 3 damage.");
+        for _ in 0..20 {
+            let state = TriviaState::new(card.clone(), decoys(), &mut rand::rng());
+            assert_eq!(state.choices().len(), 4);
+            assert_eq!(state.choices()[state.answer()], "Lightning Bolt");
+            assert!(state.is_correct(state.answer()));
+        }
+    }
+
+    #[test]
+    fn test_no_illustration_uses_rules_text() {
+        let card = Card::new(
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            "Lightning Bolt".to_string(),
+            "lightning bolt".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            "https://scryfall.com/card/test".to_string(),
+            uuid!("12345678-1234-1234-1234-123456789012"),
+            None,
+            "{R}".to_string(),
+            vec!["R".to_string()],
+            None,
+            None,
+            None,
+            None,
+            "Instant".to_string(),
+            "Deals 3 damage.".to_string(),
+            None,
+            "Christopher Rush".to_string(),
+            "Limited Edition Alpha".to_string(),
+            "LEA".to_string(),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        );
+        for _ in 0..20 {
+            let state = TriviaState::new(card.clone(), decoys(), &mut rand::rng());
+            assert_eq!(state.clue(), Clue::RulesText);
+        }
+    }
+
+    #[test]
+    fn test_redacts_card_name() {
+        let card = create_test_card(
+            "Urza, Lord High Artificer",
+            "When Urza, Lord High Artificer enters, create a Construct. Urza gets +1/+1.",
+        );
+        let state = TriviaState::new(card, decoys(), &mut rand::rng());
+
+        assert_eq!(
+            state.redacted_rules_text(),
+            "When ~ enters, create a Construct. ~ gets +1/+1."
+        );
+    }
+
+    #[test]
+    fn test_outcome_won() {
+        let card = create_test_card("Lightning Bolt", "Deals 3 damage.");
+        let state = TriviaState::new(card, decoys(), &mut rand::rng());
+        let answer = state.answer();
+        let outcome = TriviaOutcome::new(state, answer, None);
+
+        assert!(outcome.won());
+        assert!(outcome.stats().is_none());
+    }
+
+    #[test]
+    fn test_outcome_lost() {
+        let card = create_test_card("Lightning Bolt", "Deals 3 damage.");
+        let state = TriviaState::new(card, decoys(), &mut rand::rng());
+        let wrong = (state.answer() + 1) % state.choices().len();
+        let outcome = TriviaOutcome::new(state, wrong, None);
+
+        assert!(!outcome.won());
+    }
+
+    #[test]
+    fn test_key_is_namespaced() {
+        assert_eq!(key("channel"), "trivia:channel");
+    }
+
+    #[tokio::test]
+    async fn test_round_trips_through_cache() {
+        let card = create_test_card("Lightning Bolt", "Deals 3 damage.");
+        let state = TriviaState::new(card, decoys(), &mut rand::rng());
+        let ron_string = ron::to_string(&state).unwrap();
+
+        let mut cache = MockCache::new();
+        cache
+            .expect_set()
+            .times(1)
+            .with(eq(String::from("trivia:channel")), eq(ron_string.clone()))
+            .returning(|_, _| Ok(()));
+        cache
+            .expect_get()
+            .times(1)
+            .with(eq(String::from("trivia:channel")))
+            .return_const(Some(ron_string));
+
+        add(&state, key("channel"), &cache).await;
+        let fetched = fetch(key("channel"), &cache).await.unwrap();
+
+        assert_eq!(fetched.answer(), state.answer());
+        assert_eq!(fetched.choices(), state.choices());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::domain::utils::raster::Raster;
+use contracts::card::Card;
+use contracts::image::Image;
+use std::env;
+use std::sync::LazyLock;
+
+const DEFAULT_GRID_THRESHOLD: usize = 3;
+/// Cards per row of a grid.
+pub const GRID_COLUMNS: usize = 5;
+/// Two rows' worth; messages with more results get several grids.
+pub const MAX_GRID_CARDS: usize = 10;
+/// Transparent space between cards, in pixels.
+const GAP: usize = 8;
+
+/// How many cards a message needs to find before they're sent as one grid
+/// instead of a message each, read once from `INLINE_GRID_THRESHOLD` — `0`
+/// always sends them separately.
+pub static GRID_THRESHOLD: LazyLock<usize> = LazyLock::new(|| {
+    env::var("INLINE_GRID_THRESHOLD")
+        .ok()
+        .and_then(|threshold| threshold.parse().ok())
+        .unwrap_or(DEFAULT_GRID_THRESHOLD)
+});
+
+/// Several inline results stitched into one image, with the cards in the
+/// same order so any of them can be opened in full.
+#[derive(Debug)]
+pub struct CardGrid {
+    image: Image,
+    cards: Vec<Card>,
+    missing: usize,
+}
+
+impl CardGrid {
+    #[must_use]
+    pub fn new(image: Image, cards: Vec<Card>, missing: usize) -> Self {
+        Self {
+            image,
+            cards,
+            missing,
+        }
+    }
+
+    #[must_use]
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    #[must_use]
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// How many of the message's queries found nothing.
+    #[must_use]
+    pub fn missing(&self) -> usize {
+        self.missing
+    }
+}
+
+/// Lays the card images out left to right, `GRID_COLUMNS` to a row, at
+/// half size so a full grid stays well under the upload limit. `None` if
+/// any of them can't be decoded.
+#[must_use]
+pub fn compose(images: &[Image]) -> Option<Image> {
+    let cards: Vec<Raster> = images
+        .iter()
+        .map(|image| Raster::decode_png(image.bytes()).map(|raster| raster.halve()))
+        .collect::<Option<_>>()?;
+
+    let cell_width = cards.iter().map(Raster::width).max()?;
+    let cell_height = cards.iter().map(Raster::height).max()?;
+    let columns = cards.len().min(GRID_COLUMNS);
+    let rows = cards.len().div_ceil(GRID_COLUMNS);
+
+    let mut grid = Raster::blank(
+        columns * cell_width + (columns - 1) * GAP,
+        rows * cell_height + (rows - 1) * GAP,
+    );
+    for (index, card) in cards.iter().enumerate() {
+        let x = (index % GRID_COLUMNS) * (cell_width + GAP);
+        let y = (index / GRID_COLUMNS) * (cell_height + GAP);
+        grid.paste(card, x, y);
+    }
+
+    grid.encode_png().map(Image::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: usize, height: usize) -> Image {
+        Image::new(Raster::blank(width, height).encode_png().unwrap())
+    }
+
+    #[test]
+    fn test_wraps_after_five_cards() {
+        let images: Vec<Image> = (0..6).map(|_| png(10, 14)).collect();
+        let grid = Raster::decode_png(compose(&images).unwrap().bytes()).unwrap();
+        assert_eq!(grid.width(), 5 * 5 + 4 * GAP);
+        assert_eq!(grid.height(), 2 * 7 + GAP);
+    }
+
+    #[test]
+    fn test_single_row() {
+        let images = vec![png(10, 14), png(10, 14)];
+        let grid = Raster::decode_png(compose(&images).unwrap().bytes()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2 * 5 + GAP, 7));
+    }
+
+    #[test]
+    fn test_undecodable_image() {
+        assert!(compose(&[png(10, 14), Image::new(b"not a png".to_vec())]).is_none());
+        assert!(compose(&[]).is_none());
+    }
+}
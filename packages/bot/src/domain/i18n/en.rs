@@ -0,0 +1,211 @@
+use crate::domain::i18n::Message;
+use contracts::card_details::Legality;
+
+fn plural(count: usize, one: &str, many: &str) -> String {
+    if count > 1 {
+        many.to_string()
+    } else {
+        one.to_string()
+    }
+}
+
+// One arm per message; splitting it up would only scatter the wording.
+#[allow(clippy::too_many_lines)]
+pub(super) fn text(message: &Message) -> String {
+    match message {
+        Message::CardNotFound => String::from("Could not find card :("),
+        Message::QueryNotMatched => String::from("Failed to find card :("),
+        Message::PrintNotFound => String::from("Could not find that print :("),
+        Message::RateLimited => String::from("You're searching too fast — give it a few seconds and try again."),
+        Message::TooManySearches { max } => {
+            format!("That's too many cards for one message; ask for {max} at most.")
+        }
+        Message::ListTooLong { max } => {
+            format!("That list is too long; paste {max} lines at most.")
+        }
+        Message::NoGameFound => String::from("No game found in this channel :("),
+        Message::SetNotFound { set_name } => format!("Could not find set '{set_name}'"),
+        Message::NewGame {
+            difficulty,
+            set_name: None,
+        } => format!("Difficulty is set to `{difficulty}`."),
+        Message::NewGame {
+            difficulty,
+            set_name: Some(set_name),
+        } => format!("Difficulty is set to `{difficulty}`. This card is from `{set_name}`"),
+        Message::WrongGuess { guess, remaining } => format!(
+            "'{guess}' was not the correct card. You have {remaining} {} remaining",
+            plural(*remaining, "guess", "guesses")
+        ),
+        Message::GameWon { player, guesses } => format!(
+            "{player} has won after {guesses} {}!",
+            plural(*guesses, "guess", "guesses")
+        ),
+        Message::GameLost { guesses } => format!(
+            "You have all failed after {guesses} {}!",
+            plural(*guesses, "guess", "guesses")
+        ),
+        Message::SpoilersAlreadyEnabled { channel } => {
+            format!("Spoiler notifications are already enabled for <#{channel}>.")
+        }
+        Message::SpoilersEnabled {
+            channel,
+            issues_url,
+        } => format!(
+            "Spoiler notifications enabled for <#{channel}>. This feature is in beta — \
+             if anything looks wrong, please let us know here: {issues_url}"
+        ),
+        Message::SpoilersMissingPermissions { reauthorize_url } => format!(
+            "Couldn't create a webhook in that channel — I'm missing permissions. \
+             Ask a server admin to re-authorize me here to grant what's missing: {reauthorize_url}"
+        ),
+        Message::SpoilersDisabled { channel } => {
+            format!("Spoiler notifications disabled for <#{channel}>.")
+        }
+        Message::SpoilersUnreachable { channel } => format!(
+            "Couldn't reach Discord to remove the webhook for <#{channel}> — nothing changed, please try again shortly."
+        ),
+        Message::QuestionAnswered => String::from("This question has already been answered."),
+        Message::NoQuestion => String::from("Couldn't come up with a question this time, try again :("),
+        Message::NotYourGame => String::from("This isn't your game, start your own with `/higherlower`!"),
+        Message::RoundOver => String::from("This round is already over."),
+        Message::RunOutOfCards => String::from("Couldn't find another priced card, so your run ends here :("),
+        Message::NoPricedCards => String::from("Couldn't find any priced cards right now :("),
+        Message::CardImagesUnavailable => String::from("Couldn't fetch the card images, try again :("),
+        Message::CubeCreated { cube } => format!("Created cube '{cube}'"),
+        Message::CubeExists { cube } => format!("This server already has a cube called '{cube}'"),
+        Message::CubeNotCreated { cube } => format!("Couldn't create cube '{cube}', try again later"),
+        Message::NoSuchCube { cube } => {
+            format!("This server has no cube called '{cube}' — create it with `/cube create`")
+        }
+        Message::CubeNotAllowed => String::from("Only someone who can manage this server can change its cubes."),
+        Message::NamedCardNotFound { name } => format!("Could not find card '{name}'"),
+        Message::AlreadyInCube { card, cube } => format!("{card} is already in '{cube}'"),
+        Message::AddedToCube { card, cube } => format!("Added {card} to '{cube}'"),
+        Message::CubeImported { cube, added, already } => {
+            format!("Added {added} new cards to '{cube}' ({already} already in it)")
+        }
+        Message::CubeTooSmall { cube, packs, pack_size } => {
+            format!("'{cube}' doesn't have enough cards for {packs} pack(s) of {pack_size}")
+        }
+        Message::NoCardsInList => String::from("Couldn't find any cards in that list"),
+        Message::Unresolved => String::from("Couldn't find:"),
+        Message::AndMore { count } => format!("...and {count} more"),
+        Message::DeckUnavailable => String::from("Couldn't look up that deck right now, try again later"),
+        Message::CommanderNotFound => String::from(
+            "Couldn't tell which card is your commander — put it under a `Commander` heading",
+        ),
+        Message::SetTooSmall { set_name } => {
+            format!("'{set_name}' doesn't have enough cards to make a booster")
+        }
+        Message::NoIllustrations { artist } => {
+            format!("Could not find any illustrations by '{artist}'")
+        }
+        Message::ArtistNotFound { artist } => format!("Could not find artist '{artist}'"),
+        Message::NoOracleText => {
+            String::from("Give me some rules text to look for, like \"draw two cards\"")
+        }
+        Message::NoOracleMatches { text } => format!("Couldn't find any cards that say '{text}'"),
+        Message::RuleNotFound { query } => format!("Could not find a rule for '{query}'"),
+        Message::TooManyProxies { copies, max } => {
+            format!("That's {copies} cards to print; the most I can fit in one PDF is {max}")
+        }
+        Message::ProxiesUnavailable => String::from("Couldn't make that PDF right now, try again later"),
+        Message::NoProxyImages => String::from("Couldn't find images for any of those cards"),
+        Message::ProxiesTooBig { printed } => {
+            format!("That PDF is too big to upload; try printing fewer than {printed} cards at once")
+        }
+        Message::DeleteNotAllowed => String::from(
+            "Only whoever asked for this card, or someone who can manage messages, can delete it.",
+        ),
+        Message::OpenCard => String::from("Open a card..."),
+        Message::TurnToPage => String::from("Turn to page..."),
+        Message::PageOf { page, pages } => format!("Page {page} of {pages}"),
+        Message::PageRange { page, from, to } => format!("Page {page} ({from}–{to})"),
+        Message::CardCount { count } => format!("{count} cards"),
+        Message::CardsListed { count } => format!("{count} cards listed"),
+        Message::SetReleased { date } => format!("Released {date}"),
+        Message::SetType => String::from("Type"),
+        Message::SetBlock => String::from("Block"),
+        Message::SetParent => String::from("Part of"),
+        Message::ArtistSummary {
+            cards,
+            illustrations,
+        } => format!("Illustrated {cards} cards across {illustrations} illustrations"),
+        Message::FirstWork => String::from("First work"),
+        Message::LatestWork => String::from("Latest work"),
+        Message::CardsThatSay { text } => format!("Cards that say \"{text}\""),
+        Message::BestMatchesOnly => {
+            String::from("the best matches only, narrow it down to see more")
+        }
+        Message::SelectPrint => String::from("Select a print..."),
+        Message::SimilarCards => String::from("Similar cards..."),
+        Message::FunctionallySimilar => String::from("Functionally similar..."),
+        Message::WhichCard => String::from("Which card is this?"),
+        Message::GotIt { player } => format!("{player} got it!"),
+        Message::PickedWrong {
+            player,
+            picked,
+            answer,
+        } => format!("{player} picked {}... it was {answer}!", picked.unwrap_or("nothing")),
+        Message::TriviaRecord { stats } => format!(
+            "🔥 Streak {} (best {}) · {}/{} correct",
+            stats.streak, stats.best_streak, stats.won, stats.played
+        ),
+        Message::WhichCostsMore { streak } => format!("Which costs more? 🔥 Streak {streak}"),
+        Message::RunEnded { player, streak } => {
+            format!("{player} ran out of luck with a streak of {streak}!")
+        }
+        Message::HigherLowerRecord { stats } => format!(
+            "🏆 Best streak {} · {}/{} correct",
+            stats.best_streak, stats.won, stats.played
+        ),
+        Message::More { count } => format!("{count} more"),
+        Message::DeckModalTitle => String::from("Analyse a deck"),
+        Message::CommanderModalTitle => String::from("Check a Commander deck"),
+        Message::DeckListLabel => String::from("Deck list"),
+        Message::DeckAnalysis => String::from("Deck analysis"),
+        Message::MainCount { count } => format!("{count} main"),
+        Message::SideboardCount { count } => format!("{count} sideboard"),
+        Message::CommanderCount { count } => format!("{count} commander"),
+        Message::LegalityHeading => String::from("Legality"),
+        Message::Violation {
+            legality: Legality::Restricted,
+            copies,
+        } => format!("restricted, {copies} copies"),
+        Message::Violation { legality, .. } => legality.name().to_string(),
+        Message::ManaCurve => String::from("Mana curve"),
+        Message::Colours => String::from("Colours"),
+        Message::Types => String::from("Types"),
+        Message::Price => String::from("Price"),
+        Message::Unpriced { count } => format!("{count} card(s) without a price"),
+        Message::CouldNotFind => String::from("Couldn't find"),
+        Message::Colourless => String::from("colourless"),
+        Message::ColourIdentity { identity } => format!("Colour identity: {identity}"),
+        Message::DeckSize { size } => format!("{size} cards"),
+        Message::WrongDeckSize { total, size } => format!("{total} cards, needs exactly {size}"),
+        Message::CommanderCanLead => String::from("Commander can lead the deck"),
+        Message::CantBeCommander { names } => format!("Can't be a commander: {names}"),
+        Message::WithinColourIdentity => String::from("Within colour identity"),
+        Message::OutsideColourIdentity { names } => format!("Outside colour identity: {names}"),
+        Message::Singleton => String::from("Singleton"),
+        Message::TooManyCopies { names } => format!("Too many copies: {names}"),
+        Message::NoBannedCards => String::from("No banned cards"),
+        Message::BannedCards { names } => format!("Banned: {names}"),
+        Message::NotLegalInCommander { names } => format!("Not legal in Commander: {names}"),
+        Message::GameChangers => String::from("Game changers"),
+        Message::NoGameChangers => String::from("None"),
+        Message::Bracket => String::from("Bracket"),
+        Message::BracketOrHigher { bracket } => format!("{bracket} or higher"),
+        Message::ProxiesModalTitle => String::from("Print proxies"),
+        Message::ProxyListLabel { max } => format!("Card list (up to {max} cards)"),
+        Message::ProxiesReady => String::from("Here are your proxies, printed at 100% scale"),
+        Message::LeftOut => String::from("Left out:"),
+        Message::Booster { set_name } => format!("{set_name} booster"),
+        Message::SealedPool { set_name } => format!("{set_name} sealed pool"),
+        Message::PacksOpened { packs } => format!("{packs} packs opened. Rares and mythics:"),
+        Message::ImportIntoCube { cube } => format!("Import into {cube}"),
+        Message::CubeListLabel => String::from("Cards, one per line"),
+        Message::CubePack { cube, pack } => format!("{cube} — pack {pack}"),
+    }
+}
@@ -0,0 +1,318 @@
+use crate::domain::functions::game::state::Difficulty;
+use crate::domain::i18n::Message;
+use contracts::card_details::Legality;
+
+fn plural(count: usize, one: &str, many: &str) -> String {
+    if count > 1 {
+        many.to_string()
+    } else {
+        one.to_string()
+    }
+}
+
+fn difficulty(difficulty: &Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Easy => "Facile",
+        Difficulty::Medium => "Moyen",
+        Difficulty::Hard => "Difficile",
+    }
+}
+
+// One arm per message; splitting it up would only scatter the wording.
+#[allow(clippy::too_many_lines)]
+pub(super) fn text(message: &Message) -> String {
+    match message {
+        Message::CardNotFound => String::from("Impossible de trouver la carte :("),
+        Message::QueryNotMatched => String::from("Carte introuvable :("),
+        Message::PrintNotFound => String::from("Impossible de trouver cette impression :("),
+        Message::RateLimited => String::from("Vous cherchez trop vite — patientez quelques secondes puis réessayez."),
+        Message::TooManySearches { max } => {
+            format!("Cela fait trop de cartes pour un seul message ; demandez-en {max} au plus.")
+        }
+        Message::ListTooLong { max } => {
+            format!("Cette liste est trop longue ; collez {max} lignes au plus.")
+        }
+        Message::NoGameFound => String::from("Aucune partie en cours dans ce salon :("),
+        Message::SetNotFound { set_name } => {
+            format!("Impossible de trouver l'extension « {set_name} »")
+        }
+        Message::NewGame {
+            difficulty: level,
+            set_name: None,
+        } => format!("La difficulté est réglée sur `{}`.", difficulty(level)),
+        Message::NewGame {
+            difficulty: level,
+            set_name: Some(set_name),
+        } => format!(
+            "La difficulté est réglée sur `{}`. Cette carte vient de `{set_name}`",
+            difficulty(level)
+        ),
+        Message::WrongGuess { guess, remaining } => format!(
+            "« {guess} » n'était pas la bonne carte. Il vous reste {remaining} {}",
+            plural(*remaining, "essai", "essais")
+        ),
+        Message::GameWon { player, guesses } => format!(
+            "{player} a gagné en {guesses} {} !",
+            plural(*guesses, "essai", "essais")
+        ),
+        Message::GameLost { guesses } => format!(
+            "Vous avez tous échoué après {guesses} {} !",
+            plural(*guesses, "essai", "essais")
+        ),
+        Message::SpoilersAlreadyEnabled { channel } => {
+            format!("Les annonces de spoilers sont déjà activées pour <#{channel}>.")
+        }
+        Message::SpoilersEnabled {
+            channel,
+            issues_url,
+        } => format!(
+            "Annonces de spoilers activées pour <#{channel}>. Cette fonctionnalité est en bêta — \
+             si quelque chose cloche, dites-le-nous ici : {issues_url}"
+        ),
+        Message::SpoilersMissingPermissions { reauthorize_url } => format!(
+            "Impossible de créer un webhook dans ce salon — il me manque des permissions. \
+             Demandez à un administrateur du serveur de me ré-autoriser ici pour accorder ce qui manque : {reauthorize_url}"
+        ),
+        Message::SpoilersDisabled { channel } => {
+            format!("Annonces de spoilers désactivées pour <#{channel}>.")
+        }
+        Message::SpoilersUnreachable { channel } => format!(
+            "Impossible de joindre Discord pour supprimer le webhook de <#{channel}> — rien n'a changé, réessayez dans un instant."
+        ),
+        Message::QuestionAnswered => String::from("Quelqu'un a déjà répondu à cette question."),
+        Message::NoQuestion => String::from("Impossible de trouver une question cette fois, réessayez :("),
+        Message::NotYourGame => String::from("Ce n'est pas votre partie, lancez la vôtre avec `/plusoumoins` !"),
+        Message::RoundOver => String::from("Cette manche est déjà terminée."),
+        Message::RunOutOfCards => String::from("Impossible de trouver une autre carte avec un prix, votre série s'arrête là :("),
+        Message::NoPricedCards => String::from("Impossible de trouver des cartes avec un prix pour le moment :("),
+        Message::CardImagesUnavailable => String::from("Impossible de récupérer les images des cartes, réessayez :("),
+        Message::CubeCreated { cube } => format!("Cube « {cube} » créé"),
+        Message::CubeExists { cube } => format!("Ce serveur a déjà un cube nommé « {cube} »"),
+        Message::CubeNotCreated { cube } => {
+            format!("Impossible de créer le cube « {cube} », réessayez plus tard")
+        }
+        Message::NoSuchCube { cube } => format!(
+            "Ce serveur n'a pas de cube nommé « {cube} » — créez-le avec `/cube create`"
+        ),
+        Message::CubeNotAllowed => String::from(
+            "Seul quelqu'un qui peut gérer ce serveur peut modifier ses cubes.",
+        ),
+        Message::NamedCardNotFound { name } => format!("Impossible de trouver la carte « {name} »"),
+        Message::AlreadyInCube { card, cube } => format!("{card} est déjà dans « {cube} »"),
+        Message::AddedToCube { card, cube } => format!("{card} ajoutée à « {cube} »"),
+        Message::CubeImported { cube, added, already } => format!(
+            "{added} {} à « {cube} » ({already} {} déjà)",
+            plural(*added, "nouvelle carte ajoutée", "nouvelles cartes ajoutées"),
+            plural(*already, "y était", "y étaient")
+        ),
+        Message::CubeTooSmall { cube, packs, pack_size } => format!(
+            "« {cube} » n'a pas assez de cartes pour {packs} booster(s) de {pack_size}"
+        ),
+        Message::NoCardsInList => String::from("Aucune carte trouvée dans cette liste"),
+        Message::Unresolved => String::from("Introuvables :"),
+        Message::AndMore { count } => format!("...et {count} de plus"),
+        Message::DeckUnavailable => String::from(
+            "Impossible d'analyser ce deck pour le moment, réessayez plus tard",
+        ),
+        Message::CommanderNotFound => String::from(
+            "Impossible de savoir quelle carte est votre commandant — placez-la sous un titre `Commander`",
+        ),
+        Message::SetTooSmall { set_name } => format!(
+            "« {set_name} » n'a pas assez de cartes pour faire un booster"
+        ),
+        Message::NoIllustrations { artist } => {
+            format!("Impossible de trouver des illustrations de « {artist} »")
+        }
+        Message::ArtistNotFound { artist } => {
+            format!("Impossible de trouver l'artiste « {artist} »")
+        }
+        Message::NoOracleText => String::from(
+            "Donnez-moi un texte de règles à chercher, comme « draw two cards »",
+        ),
+        Message::NoOracleMatches { text } => {
+            format!("Aucune carte ne dit « {text} »")
+        }
+        Message::RuleNotFound { query } => {
+            format!("Impossible de trouver une règle pour « {query} »")
+        }
+        Message::TooManyProxies { copies, max } => format!(
+            "Cela fait {copies} cartes à imprimer ; un PDF peut en contenir {max} au plus"
+        ),
+        Message::ProxiesUnavailable => String::from(
+            "Impossible de créer ce PDF pour le moment, réessayez plus tard",
+        ),
+        Message::NoProxyImages => {
+            String::from("Impossible de trouver des images pour ces cartes")
+        }
+        Message::ProxiesTooBig { printed } => format!(
+            "Ce PDF est trop lourd pour être envoyé ; essayez d'imprimer moins de {printed} cartes à la fois"
+        ),
+        Message::DeleteNotAllowed => String::from(
+            "Seul qui a demandé cette carte, ou quelqu'un qui peut gérer les messages, peut la supprimer.",
+        ),
+        Message::OpenCard => String::from("Ouvrir une carte..."),
+        Message::TurnToPage => String::from("Aller à la page..."),
+        Message::PageOf { page, pages } => format!("Page {page} sur {pages}"),
+        Message::PageRange { page, from, to } => format!("Page {page} ({from}–{to})"),
+        Message::CardCount { count } => {
+            format!("{count} {}", plural(*count, "carte", "cartes"))
+        }
+        Message::CardsListed { count } => {
+            format!("{count} {}", plural(*count, "carte listée", "cartes listées"))
+        }
+        Message::SetReleased { date } => format!("Sortie le {date}"),
+        Message::SetType => String::from("Type"),
+        Message::SetBlock => String::from("Bloc"),
+        Message::SetParent => String::from("Fait partie de"),
+        Message::ArtistSummary {
+            cards,
+            illustrations,
+        } => format!(
+            "A illustré {cards} {} à travers {illustrations} {}",
+            plural(*cards, "carte", "cartes"),
+            plural(*illustrations, "illustration", "illustrations")
+        ),
+        Message::FirstWork => String::from("Première œuvre"),
+        Message::LatestWork => String::from("Dernière œuvre"),
+        Message::CardsThatSay { text } => format!("Cartes qui disent « {text} »"),
+        Message::BestMatchesOnly => String::from(
+            "seulement les meilleurs résultats, précisez la recherche pour en voir plus",
+        ),
+        Message::SelectPrint => String::from("Choisir une impression..."),
+        Message::SimilarCards => String::from("Cartes similaires..."),
+        Message::FunctionallySimilar => String::from("Fonctionnellement similaires..."),
+        Message::WhichCard => String::from("Quelle est cette carte ?"),
+        Message::GotIt { player } => format!("{player} a trouvé !"),
+        Message::PickedWrong {
+            player,
+            picked,
+            answer,
+        } => format!(
+            "{player} a choisi {}... c'était {answer} !",
+            picked.unwrap_or("rien")
+        ),
+        Message::TriviaRecord { stats } => format!(
+            "🔥 Série de {} (record {}) · {}/{} bonnes réponses",
+            stats.streak, stats.best_streak, stats.won, stats.played
+        ),
+        Message::WhichCostsMore { streak } => {
+            format!("Laquelle coûte le plus cher ? 🔥 Série de {streak}")
+        }
+        Message::RunEnded { player, streak } => {
+            format!("{player} n'a plus de chance, avec une série de {streak} !")
+        }
+        Message::HigherLowerRecord { stats } => format!(
+            "🏆 Meilleure série {} · {}/{} bonnes réponses",
+            stats.best_streak, stats.won, stats.played
+        ),
+        Message::More { count } => format!("{count} de plus"),
+        Message::DeckModalTitle => String::from("Analyser un deck"),
+        Message::CommanderModalTitle => String::from("Vérifier un deck Commander"),
+        Message::DeckListLabel => String::from("Liste du deck"),
+        Message::DeckAnalysis => String::from("Analyse du deck"),
+        Message::MainCount { count } => format!("{count} en main deck"),
+        Message::SideboardCount { count } => format!("{count} en réserve"),
+        Message::CommanderCount { count } => format!(
+            "{count} {}",
+            plural(*count as usize, "commandant", "commandants")
+        ),
+        Message::LegalityHeading => String::from("Légalité"),
+        Message::Violation { legality, copies } => match legality {
+            Legality::Legal => String::from("légale"),
+            Legality::NotLegal => String::from("non légale"),
+            Legality::Restricted => format!("restreinte, {copies} exemplaires"),
+            Legality::Banned => String::from("bannie"),
+        },
+        Message::ManaCurve => String::from("Courbe de mana"),
+        Message::Colours => String::from("Couleurs"),
+        Message::Types => String::from("Types"),
+        Message::Price => String::from("Prix"),
+        Message::Unpriced { count } => format!(
+            "{count} {}",
+            plural(*count, "carte sans prix", "cartes sans prix")
+        ),
+        Message::CouldNotFind => String::from("Introuvables"),
+        Message::Colourless => String::from("incolore"),
+        Message::ColourIdentity { identity } => format!("Identité couleur : {identity}"),
+        Message::DeckSize { size } => format!("{size} cartes"),
+        Message::WrongDeckSize { total, size } => format!(
+            "{total} {}, il en faut exactement {size}",
+            plural(*total as usize, "carte", "cartes")
+        ),
+        Message::CommanderCanLead => String::from("Le commandant peut mener le deck"),
+        Message::CantBeCommander { names } => format!("Ne peut pas être commandant : {names}"),
+        Message::WithinColourIdentity => String::from("Dans l'identité couleur"),
+        Message::OutsideColourIdentity { names } => {
+            format!("Hors de l'identité couleur : {names}")
+        }
+        Message::Singleton => String::from("Singleton"),
+        Message::TooManyCopies { names } => format!("Trop d'exemplaires : {names}"),
+        Message::NoBannedCards => String::from("Aucune carte bannie"),
+        Message::BannedCards { names } => format!("Bannies : {names}"),
+        Message::NotLegalInCommander { names } => {
+            format!("Non légales en Commander : {names}")
+        }
+        Message::GameChangers => String::from("Game changers"),
+        Message::NoGameChangers => String::from("Aucun"),
+        Message::Bracket => String::from("Tranche"),
+        Message::BracketOrHigher { bracket } => format!("{bracket} ou plus"),
+        Message::ProxiesModalTitle => String::from("Imprimer des proxies"),
+        Message::ProxyListLabel { max } => format!("Liste de cartes ({max} cartes au plus)"),
+        Message::ProxiesReady => {
+            String::from("Voici vos proxies, à imprimer à l'échelle 100 %")
+        }
+        Message::LeftOut => String::from("Laissées de côté :"),
+        Message::Booster { set_name } => format!("Booster {set_name}"),
+        Message::SealedPool { set_name } => format!("Pool scellé {set_name}"),
+        Message::PacksOpened { packs } => format!(
+            "{packs} {}. Rares et mythiques :",
+            plural(*packs, "booster ouvert", "boosters ouverts")
+        ),
+        Message::ImportIntoCube { cube } => format!("Importer dans {cube}"),
+        Message::CubeListLabel => String::from("Cartes, une par ligne"),
+        Message::CubePack { cube, pack } => format!("{cube} — booster {pack}"),
+    }
+}
+
+pub(super) fn command(name: &str) -> Option<(&'static str, &'static str)> {
+    let localised = match name {
+        "artist" => (
+            "artiste",
+            "Chercher un artiste et parcourir ses illustrations",
+        ),
+        "commander" => ("commander", "Vérifier un deck Commander collé"),
+        "cr" => ("regles", "Chercher une règle dans les Règles complètes"),
+        "cube" => ("cube", "Construire les cubes du serveur et les drafter"),
+        "deck" => ("deck", "Analyser une liste de deck collée"),
+        "give_up" => ("abandonner", "Abandonner la partie en cours"),
+        "guess" => ("deviner", "Deviner la carte"),
+        "help" => ("aide", "Mode d'emploi du bot."),
+        "higherlower" => (
+            "plusoumoins",
+            "Deviner laquelle de deux cartes coûte le plus cher",
+        ),
+        "oracle" => ("oracle", "Trouver des cartes d'après leur texte de règles"),
+        "pack" => ("booster", "Ouvrir un booster d'une extension"),
+        "play" => ("jouer", "Lancer une partie de devine la carte"),
+        "proxies" => (
+            "proxies",
+            "Créer un PDF de proxies à partir d'une liste de cartes collée",
+        ),
+        "sealed" => ("scellé", "Ouvrir six boosters en pool scellé"),
+        "search" => ("chercher", "Chercher une carte"),
+        "set" => (
+            "extension",
+            "Chercher une extension et parcourir ses cartes",
+        ),
+        "spoilers" => (
+            "spoilers",
+            "Gérer les annonces automatiques de spoilers du serveur",
+        ),
+        "trivia" => (
+            "quiz",
+            "Nommer la carte d'après son illustration ou son texte",
+        ),
+        _ => return None,
+    };
+    Some(localised)
+}
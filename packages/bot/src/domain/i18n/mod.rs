@@ -0,0 +1,454 @@
+mod en;
+mod fr;
+
+use crate::domain::functions::game::state::Difficulty;
+use cards_sdk::GameStats;
+use contracts::card_details::Legality;
+
+/// A language the bot can answer in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    French,
+}
+
+impl Locale {
+    /// Every locale the bot has a catalogue for.
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::French];
+
+    /// From a Discord locale like `fr` or `en-GB`. Languages without a
+    /// catalogue fall back to English.
+    #[must_use]
+    pub fn from_discord(locale: &str) -> Self {
+        match locale.split('-').next() {
+            Some("fr") => Locale::French,
+            _ => Locale::English,
+        }
+    }
+
+    /// Discord's code for the locale, for command localisations.
+    #[must_use]
+    pub fn discord_code(self) -> &'static str {
+        match self {
+            Locale::English => "en-US",
+            Locale::French => "fr",
+        }
+    }
+}
+
+/// Something the bot says, with whatever goes into it. Each locale's
+/// catalogue has to say all of them.
+pub enum Message<'a> {
+    /// `/search` found nothing.
+    CardNotFound,
+    /// One of a message's `[[card]]` queries found nothing.
+    QueryNotMatched,
+    PrintNotFound,
+    /// A search was turned away by the rate limiter.
+    RateLimited,
+    /// A message asked for more cards than one message is allowed.
+    TooManySearches {
+        max: u32,
+    },
+    ListTooLong {
+        max: usize,
+    },
+    NoGameFound,
+    SetNotFound {
+        set_name: &'a str,
+    },
+    /// A guessing game has started; `set_name` is given away on all but
+    /// Hard.
+    NewGame {
+        difficulty: &'a Difficulty,
+        set_name: Option<&'a str>,
+    },
+    WrongGuess {
+        guess: &'a str,
+        remaining: usize,
+    },
+    /// `player` is a mention of whoever won.
+    GameWon {
+        player: &'a str,
+        guesses: usize,
+    },
+    GameLost {
+        guesses: usize,
+    },
+    SpoilersAlreadyEnabled {
+        channel: u64,
+    },
+    SpoilersEnabled {
+        channel: u64,
+        issues_url: &'a str,
+    },
+    /// The bot can't make a webhook; `reauthorize_url` grants what it's
+    /// missing.
+    SpoilersMissingPermissions {
+        reauthorize_url: &'a str,
+    },
+    SpoilersDisabled {
+        channel: u64,
+    },
+    SpoilersUnreachable {
+        channel: u64,
+    },
+    /// A trivia answer came in after the question was settled.
+    QuestionAnswered,
+    NoQuestion,
+    /// Someone pressed the buttons on another player's higher-or-lower run.
+    NotYourGame,
+    RoundOver,
+    /// A higher-or-lower run had nothing left to compare against.
+    RunOutOfCards,
+    NoPricedCards,
+    /// A higher-or-lower round's card images couldn't be fetched.
+    CardImagesUnavailable,
+    CubeCreated {
+        cube: &'a str,
+    },
+    CubeExists {
+        cube: &'a str,
+    },
+    CubeNotCreated {
+        cube: &'a str,
+    },
+    NoSuchCube {
+        cube: &'a str,
+    },
+    CubeNotAllowed,
+    /// A card named by hand, rather than in a `[[query]]`, wasn't found.
+    NamedCardNotFound {
+        name: &'a str,
+    },
+    AlreadyInCube {
+        card: &'a str,
+        cube: &'a str,
+    },
+    AddedToCube {
+        card: &'a str,
+        cube: &'a str,
+    },
+    CubeImported {
+        cube: &'a str,
+        added: usize,
+        already: usize,
+    },
+    CubeTooSmall {
+        cube: &'a str,
+        packs: usize,
+        pack_size: usize,
+    },
+    /// Nothing in a pasted list matched a card.
+    NoCardsInList,
+    /// Heads the lines of a pasted list that didn't match a card.
+    Unresolved,
+    /// Sums up the lines left out of a list.
+    AndMore {
+        count: usize,
+    },
+    DeckUnavailable,
+    CommanderNotFound,
+    SetTooSmall {
+        set_name: &'a str,
+    },
+    NoIllustrations {
+        artist: &'a str,
+    },
+    ArtistNotFound {
+        artist: &'a str,
+    },
+    NoOracleText,
+    NoOracleMatches {
+        text: &'a str,
+    },
+    RuleNotFound {
+        query: &'a str,
+    },
+    TooManyProxies {
+        copies: u32,
+        max: u32,
+    },
+    ProxiesUnavailable,
+    NoProxyImages,
+    ProxiesTooBig {
+        printed: usize,
+    },
+    DeleteNotAllowed,
+    /// Placeholder for a select menu of cards.
+    OpenCard,
+    /// Placeholder for a browse's page select menu.
+    TurnToPage,
+    /// `page` counts from one.
+    PageOf {
+        page: usize,
+        pages: usize,
+    },
+    /// A page select menu option; `page` counts from one, and `from` and
+    /// `to` are the positions of its first and last entries.
+    PageRange {
+        page: usize,
+        from: usize,
+        to: usize,
+    },
+    CardCount {
+        count: usize,
+    },
+    CardsListed {
+        count: usize,
+    },
+    SetReleased {
+        date: &'a str,
+    },
+    SetType,
+    SetBlock,
+    SetParent,
+    ArtistSummary {
+        cards: usize,
+        illustrations: usize,
+    },
+    FirstWork,
+    LatestWork,
+    CardsThatSay {
+        text: &'a str,
+    },
+    /// Added to an `/oracle` footer when there were too many matches to
+    /// list them all.
+    BestMatchesOnly,
+    /// Placeholder for a card's other printings.
+    SelectPrint,
+    SimilarCards,
+    FunctionallySimilar,
+    WhichCard,
+    /// `player` is a mention of whoever answered.
+    GotIt {
+        player: &'a str,
+    },
+    /// `picked` is `None` if the choice no longer matches any button.
+    PickedWrong {
+        player: &'a str,
+        picked: Option<&'a str>,
+        answer: &'a str,
+    },
+    TriviaRecord {
+        stats: &'a GameStats,
+    },
+    WhichCostsMore {
+        streak: usize,
+    },
+    RunEnded {
+        player: &'a str,
+        streak: usize,
+    },
+    HigherLowerRecord {
+        stats: &'a GameStats,
+    },
+    /// Sums up the entries left out of a short, comma-separated list.
+    More {
+        count: usize,
+    },
+    DeckModalTitle,
+    CommanderModalTitle,
+    DeckListLabel,
+    DeckAnalysis,
+    MainCount {
+        count: u32,
+    },
+    SideboardCount {
+        count: u32,
+    },
+    CommanderCount {
+        count: u32,
+    },
+    LegalityHeading,
+    /// Why a card keeps a deck out of a format.
+    Violation {
+        legality: Legality,
+        copies: u32,
+    },
+    ManaCurve,
+    Colours,
+    Types,
+    Price,
+    Unpriced {
+        count: usize,
+    },
+    /// Heads the lines of a deck that didn't match a card.
+    CouldNotFind,
+    Colourless,
+    ColourIdentity {
+        identity: &'a str,
+    },
+    DeckSize {
+        size: u32,
+    },
+    WrongDeckSize {
+        total: u32,
+        size: u32,
+    },
+    CommanderCanLead,
+    CantBeCommander {
+        names: &'a str,
+    },
+    WithinColourIdentity,
+    OutsideColourIdentity {
+        names: &'a str,
+    },
+    Singleton,
+    TooManyCopies {
+        names: &'a str,
+    },
+    NoBannedCards,
+    BannedCards {
+        names: &'a str,
+    },
+    NotLegalInCommander {
+        names: &'a str,
+    },
+    GameChangers,
+    NoGameChangers,
+    Bracket,
+    /// A bracket estimate, which only counts game changers so can only
+    /// ever be a floor.
+    BracketOrHigher {
+        bracket: &'a str,
+    },
+    ProxiesModalTitle,
+    ProxyListLabel {
+        max: u32,
+    },
+    ProxiesReady,
+    LeftOut,
+    Booster {
+        set_name: &'a str,
+    },
+    SealedPool {
+        set_name: &'a str,
+    },
+    PacksOpened {
+        packs: usize,
+    },
+    ImportIntoCube {
+        cube: &'a str,
+    },
+    CubeListLabel,
+    /// `pack` counts from one.
+    CubePack {
+        cube: &'a str,
+        pack: usize,
+    },
+}
+
+impl Message<'_> {
+    #[must_use]
+    pub fn text(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => en::text(self),
+            Locale::French => fr::text(self),
+        }
+    }
+}
+
+/// A slash command's name and description in `locale`, if it's translated.
+#[must_use]
+pub fn command(locale: Locale, name: &str) -> Option<(&'static str, &'static str)> {
+    match locale {
+        Locale::English => None,
+        Locale::French => fr::command(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_discord() {
+        assert_eq!(Locale::from_discord("fr"), Locale::French);
+        assert_eq!(Locale::from_discord("en-GB"), Locale::English);
+        assert_eq!(Locale::from_discord("ja"), Locale::English);
+    }
+
+    #[test]
+    fn test_english_is_unchanged() {
+        assert_eq!(
+            Message::CardNotFound.text(Locale::English),
+            "Could not find card :("
+        );
+        assert_eq!(
+            Message::WrongGuess {
+                guess: "Shock",
+                remaining: 1
+            }
+            .text(Locale::English),
+            "'Shock' was not the correct card. You have 1 guess remaining"
+        );
+    }
+
+    #[test]
+    fn test_french() {
+        assert_eq!(
+            Message::GameLost { guesses: 3 }.text(Locale::French),
+            "Vous avez tous échoué après 3 essais !"
+        );
+        assert_eq!(
+            Message::NewGame {
+                difficulty: &Difficulty::Easy,
+                set_name: Some("Alpha")
+            }
+            .text(Locale::French),
+            "La difficulté est réglée sur `Facile`. Cette carte vient de `Alpha`"
+        );
+        assert_eq!(
+            Message::PageOf { page: 2, pages: 5 }.text(Locale::French),
+            "Page 2 sur 5"
+        );
+        assert_eq!(
+            Message::CardsListed { count: 1 }.text(Locale::French),
+            "1 carte listée"
+        );
+        assert_eq!(
+            Message::PacksOpened { packs: 6 }.text(Locale::French),
+            "6 boosters ouverts. Rares et mythiques :"
+        );
+        assert_eq!(
+            Message::Violation {
+                legality: Legality::Restricted,
+                copies: 2
+            }
+            .text(Locale::French),
+            "restreinte, 2 exemplaires"
+        );
+    }
+
+    #[test]
+    fn test_command_names_are_valid() {
+        for name in [
+            "artist",
+            "commander",
+            "cr",
+            "cube",
+            "deck",
+            "give_up",
+            "guess",
+            "help",
+            "higherlower",
+            "oracle",
+            "pack",
+            "play",
+            "proxies",
+            "sealed",
+            "search",
+            "set",
+            "spoilers",
+            "trivia",
+        ] {
+            let (localised, description) = command(Locale::French, name).unwrap();
+            assert!(!localised.is_empty() && localised.chars().count() <= 32);
+            assert_eq!(localised, localised.to_lowercase());
+            assert!(!localised.contains(' '));
+            assert!(!description.is_empty() && description.chars().count() <= 100);
+        }
+    }
+}
@@ -0,0 +1,444 @@
+use crate::domain::card;
+use crate::domain::grid::GRID_THRESHOLD;
+use crate::domain::query::QueryParams;
+use crate::domain::search;
+use crate::impl_app;
+use crate::ports::drivers::client::{
+    GridInteraction, MessageInteraction, ReplyCleanup, ReplyTracking,
+};
+use serde::{Deserialize, Serialize};
+
+/// The replies sent to a message's inline queries, and the text they
+/// answered, so an edit that leaves the text alone (Discord sends one when
+/// a link preview loads) doesn't redo the search.
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+struct InlineReplies {
+    content: String,
+    replies: Vec<u64>,
+}
+
+fn key(source: &str) -> String {
+    format!("inline-replies:{source}")
+}
+
+/// What a message's queries cost against the rate limit.
+fn searches(queries: &[QueryParams]) -> u32 {
+    u32::try_from(queries.len()).unwrap_or(u32::MAX)
+}
+
+impl_app! {
+    async fn fetch_inline_replies(&self, key: String) -> Option<InlineReplies> {
+        let replies_string = self.cache.get(key).await?;
+        match ron::from_str::<InlineReplies>(&replies_string) {
+            Ok(replies) => Some(replies),
+            Err(why) => {
+                log::warn!("Couldn't parse inline replies: {why}");
+                None
+            }
+        }
+    }
+
+    async fn store_inline_replies(&self, key: String, inline_replies: &InlineReplies) {
+        let ron_string = match ron::to_string(inline_replies) {
+            Ok(ron_string) => ron_string,
+            Err(err) => {
+                log::warn!("Error converting inline replies to string: {err}");
+                return;
+            }
+        };
+        if let Err(why) = self.cache.set(key, ron_string).await {
+            log::warn!("Error while trying to set value in redis: {why}");
+        }
+    }
+
+    async fn remember_inline_replies<I: ReplyTracking>(&self, interaction: &I, content: &str) {
+        let key = key(&interaction.source());
+        let replies = interaction.sent();
+        if replies.is_empty() {
+            if let Err(why) = self.cache.delete(key).await {
+                log::warn!("Error deleting inline replies: {why}");
+            }
+            return;
+        }
+
+        let inline_replies = InlineReplies {
+            content: content.to_string(),
+            replies,
+        };
+        self.store_inline_replies(key, &inline_replies).await;
+    }
+
+    /// Stops tracking a reply someone deleted by hand. The message's text is
+    /// kept even if that was its last reply, so a link preview loading
+    /// doesn't bring the card back.
+    pub async fn forget_inline_reply(&self, source: &str, reply: u64) {
+        let key = key(source);
+        let Some(mut inline_replies) = self.fetch_inline_replies(key.clone()).await else {
+            return;
+        };
+        inline_replies.replies.retain(|sent| *sent != reply);
+        self.store_inline_replies(key, &inline_replies).await;
+    }
+
+    /// Answers a new message's inline queries and remembers the replies.
+    pub async fn inline_search<I: MessageInteraction + GridInteraction + ReplyTracking>(
+        &self,
+        interaction: &I,
+        content: &str,
+    ) {
+        let queries = search::queries(content);
+        if queries.is_empty() || !self.rate_limit(interaction, searches(&queries)).await {
+            return;
+        }
+        let results = self.find_cards(queries).await;
+        card::inline_response(results, interaction, *GRID_THRESHOLD).await;
+        self.remember_inline_replies(interaction, content).await;
+    }
+
+    /// Re-runs an edited message's inline queries, editing the earlier
+    /// replies in place and deleting any that are no longer needed.
+    pub async fn inline_edit<I: MessageInteraction + GridInteraction + ReplyTracking>(
+        &self,
+        interaction: &I,
+        content: &str,
+    ) {
+        let previous = self
+            .fetch_inline_replies(key(&interaction.source()))
+            .await
+            .unwrap_or_default();
+        if previous.content == content {
+            return;
+        }
+
+        let queries = search::queries(content);
+        if queries.is_empty() && previous.replies.is_empty() {
+            return;
+        }
+        if !queries.is_empty() && !self.rate_limit(interaction, searches(&queries)).await {
+            return;
+        }
+        let results = self.find_cards(queries).await;
+
+        interaction.reuse(previous.replies.clone());
+        card::inline_response(results, interaction, *GRID_THRESHOLD).await;
+
+        let sent = interaction.sent();
+        let unused: Vec<u64> = previous
+            .replies
+            .into_iter()
+            .filter(|reply| !sent.contains(reply))
+            .collect();
+        if !unused.is_empty() {
+            interaction.delete_replies(unused).await;
+        }
+        self.remember_inline_replies(interaction, content).await;
+    }
+
+    /// Deletes the replies to a message that's gone, or that no longer has
+    /// any inline queries.
+    pub async fn inline_delete<I: ReplyCleanup>(&self, interaction: &I) {
+        let key = key(&interaction.source());
+        let Some(previous) = self.fetch_inline_replies(key.clone()).await else {
+            return;
+        };
+
+        interaction.delete_replies(previous.replies).await;
+        if let Err(why) = self.cache.delete(key).await {
+            log::warn!("Error deleting inline replies: {why}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::grid::CardGrid;
+    use crate::domain::i18n::Locale;
+    use crate::domain::rate_limit::{expect_unlimited, Requester};
+    use crate::ports::drivers::client::{MessageInteractionError, MockReplyCleanup};
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use async_trait::async_trait;
+    use contracts::card::Card;
+    use contracts::image::Image;
+    use contracts::search_result::SearchResultDto;
+    use mockall::mock;
+    use mockall::predicate::eq;
+    use uuid::uuid;
+
+    mock! {
+        Inline {}
+
+        #[async_trait]
+        impl MessageInteraction for Inline {
+            async fn send_card(&self, result: SearchResultDto) -> Result<(), MessageInteractionError>;
+            async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+            async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError>;
+            async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError>;
+            fn locale(&self) -> Locale;
+            fn requester(&self) -> Requester;
+        }
+
+        #[async_trait]
+        impl GridInteraction for Inline {
+            async fn send_grid(&self, grid: CardGrid) -> Result<(), MessageInteractionError>;
+        }
+
+        #[async_trait]
+        impl ReplyCleanup for Inline {
+            fn source(&self) -> String;
+            async fn delete_replies(&self, replies: Vec<u64>);
+        }
+
+        #[async_trait]
+        impl ReplyTracking for Inline {
+            fn sent(&self) -> Vec<u64>;
+            fn reuse(&self, replies: Vec<u64>);
+        }
+    }
+
+    fn card() -> Card {
+        Card::new(
+            uuid!("00000000-0000-0000-0000-000000000001"),
+            String::from("Lightning Bolt"),
+            String::from("lightning bolt"),
+            uuid!("00000000-0000-0000-0000-000000000002"),
+            String::from("https://scryfall.com/card/test"),
+            uuid!("00000000-0000-0000-0000-000000000003"),
+            None,
+            String::from("{R}"),
+            vec![String::from("R")],
+            None,
+            None,
+            None,
+            None,
+            String::from("Instant"),
+            String::from("Lightning Bolt deals 3 damage to any target."),
+            None,
+            String::from("Christopher Rush"),
+            String::from("Alpha"),
+            String::from("LEA"),
+            time::Date::from_calendar_date(1993, time::Month::August, 5).unwrap(),
+        )
+    }
+
+    fn app(
+        cache: MockCache,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        let mut card_store = MockCardStore::new();
+        card_store.expect_search().return_const(Some(vec![card()]));
+        card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+        App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    fn stored(content: &str, replies: Vec<u64>) -> String {
+        ron::to_string(&InlineReplies {
+            content: content.to_string(),
+            replies,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_remembers_replies() {
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
+        cache
+            .expect_set()
+            .times(1)
+            .with(
+                eq(String::from("inline-replies:1:2")),
+                eq(stored("[[lightning bolt]]", vec![10])),
+            )
+            .returning(|_, _| Ok(()));
+
+        let mut interaction = MockInline::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction
+            .expect_send_card()
+            .times(1)
+            .returning(|_| Ok(()));
+        interaction
+            .expect_source()
+            .return_const(String::from("1:2"));
+        interaction.expect_sent().return_const(vec![10]);
+
+        app(cache)
+            .inline_search(&interaction, "[[lightning bolt]]")
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_no_queries_no_replies() {
+        let mut cache = MockCache::new();
+        cache.expect_set().times(0);
+
+        let mut interaction = MockInline::new();
+        interaction.expect_send_card().times(0);
+
+        app(cache)
+            .inline_search(&interaction, "just chatting")
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_edit_reuses_replies_and_deletes_the_rest() {
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
+        cache
+            .expect_get()
+            .with(eq(String::from("inline-replies:1:2")))
+            .return_const(Some(stored(
+                "[[lightning bolt]] [[lightning bolt]]",
+                vec![10, 11],
+            )));
+        cache
+            .expect_set()
+            .times(1)
+            .with(
+                eq(String::from("inline-replies:1:2")),
+                eq(stored("[[lightning bolt]]", vec![10])),
+            )
+            .returning(|_, _| Ok(()));
+
+        let mut interaction = MockInline::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id: None,
+        });
+        interaction
+            .expect_source()
+            .return_const(String::from("1:2"));
+        interaction
+            .expect_reuse()
+            .times(1)
+            .with(eq(vec![10, 11]))
+            .return_const(());
+        interaction
+            .expect_send_card()
+            .times(1)
+            .returning(|_| Ok(()));
+        interaction.expect_sent().return_const(vec![10]);
+        interaction
+            .expect_delete_replies()
+            .times(1)
+            .with(eq(vec![11]))
+            .return_const(());
+
+        app(cache)
+            .inline_edit(&interaction, "[[lightning bolt]]")
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_edit_with_same_content_does_nothing() {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .return_const(Some(stored("[[lightning bolt]]", vec![10])));
+        cache.expect_set().times(0);
+
+        let mut interaction = MockInline::new();
+        interaction
+            .expect_source()
+            .return_const(String::from("1:2"));
+        interaction.expect_reuse().times(0);
+        interaction.expect_send_card().times(0);
+
+        app(cache)
+            .inline_edit(&interaction, "[[lightning bolt]]")
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_edit_untracked_message_without_queries() {
+        let mut cache = MockCache::new();
+        cache.expect_get().return_const(None);
+        cache.expect_set().times(0);
+
+        let mut interaction = MockInline::new();
+        interaction
+            .expect_source()
+            .return_const(String::from("1:2"));
+        interaction.expect_reuse().times(0);
+
+        app(cache).inline_edit(&interaction, "fixed a typo").await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_replies() {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .return_const(Some(stored("[[lightning bolt]]", vec![10, 11])));
+        cache
+            .expect_delete()
+            .times(1)
+            .with(eq(String::from("inline-replies:1:2")))
+            .returning(|_| Ok(()));
+
+        let mut interaction = MockReplyCleanup::new();
+        interaction
+            .expect_source()
+            .return_const(String::from("1:2"));
+        interaction
+            .expect_delete_replies()
+            .times(1)
+            .with(eq(vec![10, 11]))
+            .return_const(());
+
+        app(cache).inline_delete(&interaction).await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_untracked_message() {
+        let mut cache = MockCache::new();
+        cache.expect_get().return_const(None);
+        cache.expect_delete().times(0);
+
+        let mut interaction = MockReplyCleanup::new();
+        interaction
+            .expect_source()
+            .return_const(String::from("1:2"));
+        interaction.expect_delete_replies().times(0);
+
+        app(cache).inline_delete(&interaction).await;
+    }
+
+    #[tokio::test]
+    async fn test_forgets_deleted_reply() {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .with(eq(String::from("inline-replies:1:2")))
+            .return_const(Some(stored("[[bolt]] [[shock]]", vec![10, 11])));
+        cache
+            .expect_set()
+            .times(1)
+            .with(
+                eq(String::from("inline-replies:1:2")),
+                eq(stored("[[bolt]] [[shock]]", vec![11])),
+            )
+            .returning(|_, _| Ok(()));
+
+        app(cache).forget_inline_reply("1:2", 10).await;
+    }
+}
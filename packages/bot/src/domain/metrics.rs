@@ -0,0 +1,124 @@
+use cards_sdk::Game;
+use std::sync::LazyLock;
+use std::time::Duration;
+use telemetry::prometheus::{
+    register_histogram_vec, register_int_counter, register_int_counter_vec, HistogramVec,
+    IntCounter, IntCounterVec,
+};
+
+/// How long a card search took, by how it was scoped: `name`, `set`,
+/// `artist` or `localised`.
+pub static SEARCH_DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "rustcord_search_duration_seconds",
+        "How long a card search took, by how it was scoped",
+        &["branch"],
+        vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5]
+    )
+    .expect("Invalid metric")
+});
+
+/// Card searches by how they were scoped, and whether they found a card
+/// (`hit`) or not (`miss`).
+pub static SEARCHES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "rustcord_searches_total",
+        "Card searches, by how they were scoped and whether they found a card",
+        &["branch", "result"]
+    )
+    .expect("Invalid metric")
+});
+
+pub static IMAGE_FETCH_FAILURES: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "rustcord_image_fetch_failures_total",
+        "Card images or illustrations that couldn't be read"
+    )
+    .expect("Invalid metric")
+});
+
+/// Games started, by `game` and, for the guessing game, `difficulty`.
+pub static GAMES_STARTED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "rustcord_games_started_total",
+        "Games started, by game and difficulty",
+        &["game", "difficulty"]
+    )
+    .expect("Invalid metric")
+});
+
+/// Games won, by `game` and, for the guessing game, `difficulty`. Each
+/// correct pick in higher-or-lower counts, as it does in the player's stats.
+pub static GAMES_WON: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "rustcord_games_won_total",
+        "Games won, by game and difficulty",
+        &["game", "difficulty"]
+    )
+    .expect("Invalid metric")
+});
+
+/// Registers every metric up front, so they're scraped as zero before
+/// anything has happened rather than missing.
+pub fn init() {
+    LazyLock::force(&SEARCH_DURATION);
+    LazyLock::force(&SEARCHES);
+    LazyLock::force(&IMAGE_FETCH_FAILURES);
+    LazyLock::force(&GAMES_STARTED);
+    LazyLock::force(&GAMES_WON);
+}
+
+pub fn record_search(branch: &str, elapsed: Duration, found: bool) {
+    SEARCH_DURATION
+        .with_label_values(&[branch])
+        .observe(elapsed.as_secs_f64());
+    SEARCHES
+        .with_label_values(&[branch, if found { "hit" } else { "miss" }])
+        .inc();
+}
+
+/// Only the guessing game has a difficulty; the others leave it empty, which
+/// Prometheus treats the same as not having the label.
+pub fn record_game_started(game: Game, difficulty: Option<&str>) {
+    GAMES_STARTED
+        .with_label_values(&[game.as_str(), difficulty.unwrap_or_default()])
+        .inc();
+}
+
+pub fn record_game_won(game: Game, difficulty: Option<&str>) {
+    GAMES_WON
+        .with_label_values(&[game.as_str(), difficulty.unwrap_or_default()])
+        .inc();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_search() {
+        let misses = || SEARCHES.with_label_values(&["artist", "miss"]).get();
+        let before = misses();
+        record_search("artist", Duration::from_millis(5), false);
+        assert!(misses() > before);
+        assert!(
+            SEARCH_DURATION
+                .with_label_values(&["artist"])
+                .get_sample_count()
+                > 0
+        );
+    }
+
+    #[test]
+    fn test_record_game() {
+        let started = || GAMES_STARTED.with_label_values(&["trivia", ""]).get();
+        let before = started();
+        record_game_started(Game::Trivia, None);
+        assert!(started() > before);
+
+        let won = || GAMES_WON.with_label_values(&["guess", "Hard"]).get();
+        let before = won();
+        record_game_won(Game::Guess, Some("Hard"));
+        assert!(won() > before);
+    }
+}
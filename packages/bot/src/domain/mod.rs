@@ -1,6 +1,12 @@
 pub mod app;
 pub mod card;
+pub mod card_list;
 pub mod functions;
+pub mod grid;
+pub mod i18n;
+pub mod inline;
+pub mod metrics;
 pub mod query;
+pub mod rate_limit;
 pub mod search;
 pub mod utils;
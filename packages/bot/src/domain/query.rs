@@ -27,30 +27,30 @@ impl QueryParams {
 
     #[must_use]
     pub fn from(capture: &Captures<'_>) -> Option<Self> {
-        let raw_name = capture.get(1)?.as_str().trim();
-        let name = utils::normalise_card_name(raw_name);
-        let (set_code, set_name) = match capture.get(4) {
-            Some(set) => {
-                let set = set.as_str().trim();
-                if set.chars().count() < 5 {
-                    (Some(utils::normalise_card_name(set)), None)
-                } else {
-                    (None, Some(utils::normalise_card_name(set)))
-                }
-            }
+        let name = capture.get(1)?.as_str();
+        let set = capture.get(4).map(|set| set.as_str());
+        let artist = capture.get(7).map(|artist| artist.as_str());
+        Some(Self::parse(name, set, artist))
+    }
+
+    /// A query from its raw parts, as a user typed them. A set shorter than
+    /// five characters is taken as a set code, anything longer as a name.
+    #[must_use]
+    pub fn parse(name: &str, set: Option<&str>, artist: Option<&str>) -> Self {
+        let name = utils::normalise_card_name(name.trim());
+        let (set_code, set_name) = match set.map(str::trim) {
+            Some(set) if set.chars().count() < 5 => (Some(utils::normalise_card_name(set)), None),
+            Some(set) => (None, Some(utils::normalise_card_name(set))),
             None => (None, None),
         };
+        let artist = artist.map(|artist| utils::normalise_card_name(artist.trim()));
 
-        let artist = capture
-            .get(7)
-            .map(|artist| utils::normalise_card_name(artist.as_str().trim()));
-
-        Some(Self {
+        Self {
             artist,
             name,
             set_code,
             set_name,
-        })
+        }
     }
 
     #[cfg(test)]
@@ -109,6 +109,17 @@ mod tests {
         assert_eq!(params.set_name(), None);
     }
 
+    #[test]
+    fn test_query_params_parse() {
+        let params =
+            QueryParams::parse(" Lightning Bolt ", Some("M11"), Some("Christopher Moeller"));
+
+        assert_eq!(params.name(), "lightning bolt");
+        assert_eq!(params.set_code(), Some(&"m11".to_string()));
+        assert_eq!(params.set_name(), None);
+        assert_eq!(params.artist(), Some(&"christopher moeller".to_string()));
+    }
+
     #[test]
     fn test_query_params_from_simple_card() {
         let text = "[[lightning bolt]]";
@@ -0,0 +1,424 @@
+use crate::domain::i18n::Message;
+use crate::impl_app;
+use crate::ports::drivers::client::MessageInteraction;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Who a request came from, and the server it was made in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Requester {
+    pub user_id: u64,
+    pub guild_id: Option<u64>,
+}
+
+/// How many searches a bucket holds, and how quickly it fills back up.
+struct Limit {
+    capacity: f64,
+    per_second: f64,
+}
+
+/// A burst of ten, then one every three seconds.
+const USER_LIMIT: Limit = Limit {
+    capacity: 10.0,
+    per_second: 1.0 / 3.0,
+};
+/// Shared by everyone in a server, so a crowd can't do what one user can't.
+const GUILD_LIMIT: Limit = Limit {
+    capacity: 60.0,
+    per_second: 2.0,
+};
+
+/// The most searches one message can make: as many as a full user bucket
+/// holds, so a single message can't do more than a burst would.
+pub const MAX_SEARCHES: u32 = 10;
+/// The most lines a pasted list can have. A list costs at most a full
+/// bucket however long it is, so this is what bounds its lookups.
+pub const MAX_LIST_LINES: usize = 300;
+
+/// For the card API, per address. A page of results can ask for a card and
+/// its image at once, so it bursts further than a Discord user.
+const CLIENT_LIMIT: Limit = Limit {
+    capacity: 30.0,
+    per_second: 5.0,
+};
+
+/// A token bucket as kept in the cache. `warned` is set once the owner has
+/// been told they're throttled, so they're only told once.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Bucket {
+    tokens: f64,
+    updated: u64,
+    warned: bool,
+}
+
+impl Bucket {
+    fn full(limit: &Limit, now: u64) -> Self {
+        Self {
+            tokens: limit.capacity,
+            updated: now,
+            warned: false,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn refill(&mut self, limit: &Limit, now: u64) {
+        let elapsed = now.saturating_sub(self.updated) as f64 / 1000.0;
+        self.tokens = (self.tokens + elapsed * limit.per_second).min(limit.capacity);
+        self.updated = now;
+    }
+}
+
+/// Whether a search got through, and if not, whether it's the first one
+/// turned away since the requester was last let through.
+enum Verdict {
+    Allowed,
+    Throttled { first: bool },
+}
+
+fn user_key(user_id: u64) -> String {
+    format!("rate-limit:user:{user_id}")
+}
+
+fn guild_key(guild_id: u64) -> String {
+    format!("rate-limit:guild:{guild_id}")
+}
+
+fn client_key(address: IpAddr) -> String {
+    format!("rate-limit:client:{address}")
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+impl_app! {
+    async fn fetch_bucket(&self, key: String, limit: &Limit, now: u64) -> Bucket {
+        let mut bucket = self
+            .cache
+            .get(key)
+            .await
+            .and_then(|bucket| match ron::from_str::<Bucket>(&bucket) {
+                Ok(bucket) => Some(bucket),
+                Err(why) => {
+                    log::warn!("Couldn't parse rate limit bucket: {why}");
+                    None
+                }
+            })
+            .unwrap_or_else(|| Bucket::full(limit, now));
+        bucket.refill(limit, now);
+        bucket
+    }
+
+    async fn store_bucket(&self, key: String, bucket: &Bucket) {
+        let ron_string = match ron::to_string(bucket) {
+            Ok(ron_string) => ron_string,
+            Err(why) => {
+                log::warn!("Error converting rate limit bucket to string: {why}");
+                return;
+            }
+        };
+        if let Err(why) = self.cache.set(key, ron_string).await {
+            log::warn!("Error while trying to set rate limit in redis: {why}");
+        }
+    }
+
+    /// Takes `searches` tokens from the requester's bucket and their server's.
+    /// Buckets live in the cache so every replica shares them; the read and
+    /// write aren't atomic, so a burst split across replicas can get a little
+    /// past the limit.
+    async fn spend(&self, requester: Requester, searches: u32) -> Verdict {
+        let now = now();
+
+        let user_key = user_key(requester.user_id);
+        let mut user = self.fetch_bucket(user_key.clone(), &USER_LIMIT, now).await;
+        let mut guild = match requester.guild_id {
+            Some(guild_id) => {
+                let guild_key = guild_key(guild_id);
+                let bucket = self.fetch_bucket(guild_key.clone(), &GUILD_LIMIT, now).await;
+                Some((guild_key, bucket))
+            }
+            None => None,
+        };
+
+        // A list with more lines than a bucket holds still gets through when
+        // the bucket is full; `MAX_LIST_LINES` caps what that lets through.
+        let searches = f64::from(searches);
+        let user_cost = searches.min(USER_LIMIT.capacity);
+        let guild_cost = searches.min(GUILD_LIMIT.capacity);
+        let allowed = user.tokens >= user_cost
+            && guild
+                .as_ref()
+                .is_none_or(|(_, bucket)| bucket.tokens >= guild_cost);
+
+        let verdict = if allowed {
+            user.tokens -= user_cost;
+            user.warned = false;
+            if let Some((_, bucket)) = &mut guild {
+                bucket.tokens -= guild_cost;
+            }
+            Verdict::Allowed
+        } else {
+            log::info!("Rate limited user {}", requester.user_id);
+            let first = !user.warned;
+            user.warned = true;
+            Verdict::Throttled { first }
+        };
+
+        self.store_bucket(user_key, &user).await;
+        if let Some((guild_key, bucket)) = guild {
+            self.store_bucket(guild_key, &bucket).await;
+        }
+        verdict
+    }
+
+    /// Charges a search against the requester's buckets, or tells them —
+    /// once — that they're going too fast. Messages with more than
+    /// `MAX_SEARCHES` queries are turned away whatever's in the bucket.
+    pub async fn rate_limit<I: MessageInteraction>(&self, interaction: &I, searches: u32) -> bool {
+        if searches > MAX_SEARCHES {
+            log::info!("Too many searches from user {}", interaction.requester().user_id);
+            let message = Message::TooManySearches { max: MAX_SEARCHES };
+            if let Err(why) = interaction
+                .reply_ephemeral(message.text(interaction.locale()))
+                .await
+            {
+                log::warn!("Error sending rate limit message: {why}");
+            }
+            return false;
+        }
+        match self.spend(interaction.requester(), searches).await {
+            Verdict::Allowed => true,
+            Verdict::Throttled { first } => {
+                if first {
+                    if let Err(why) = interaction
+                        .reply_ephemeral(Message::RateLimited.text(interaction.locale()))
+                        .await
+                    {
+                        log::warn!("Error sending rate limit message: {why}");
+                    }
+                }
+                false
+            }
+        }
+    }
+
+    /// Takes one request from an API client's bucket. There's no one to warn,
+    /// just a status code, so the bucket's `warned` is left alone.
+    pub async fn rate_limit_client(&self, address: IpAddr) -> bool {
+        let now = now();
+        let key = client_key(address);
+        let mut bucket = self.fetch_bucket(key.clone(), &CLIENT_LIMIT, now).await;
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        } else {
+            log::info!("Rate limited API client {address}");
+        }
+        self.store_bucket(key, &bucket).await;
+        allowed
+    }
+
+    /// Charges a pasted list one search per line, since each is looked up like
+    /// an inline search. Commands taking a list are deferred and have to be
+    /// answered, so unlike `rate_limit` this hands back what to tell them.
+    pub async fn rate_limit_lines(
+        &self,
+        requester: Requester,
+        lines: usize,
+    ) -> Result<(), Message<'static>> {
+        if lines > MAX_LIST_LINES {
+            return Err(Message::ListTooLong { max: MAX_LIST_LINES });
+        }
+        let searches = u32::try_from(lines).unwrap_or(u32::MAX);
+        match self.spend(requester, searches).await {
+            Verdict::Allowed => Ok(()),
+            Verdict::Throttled { .. } => Err(Message::RateLimited),
+        }
+    }
+}
+
+/// Lets every request through, for tests of what's behind the limit.
+#[cfg(test)]
+pub fn expect_unlimited(cache: &mut crate::ports::services::cache::MockCache) {
+    cache
+        .expect_get()
+        .withf(|key| key.starts_with("rate-limit:"))
+        .return_const(None);
+    cache
+        .expect_set()
+        .withf(|key, _| key.starts_with("rate-limit:"))
+        .returning(|_, _| Ok(()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::ports::drivers::client::MockMessageInteraction;
+    use crate::ports::services::cache::MockCache;
+    use crate::ports::services::card_store::{MockCardStore, TestCardStore};
+    use crate::ports::services::image_store::MockImageStore;
+    use crate::ports::services::spoiler_subscription::MockSpoilerSubscription;
+    use mockall::predicate::eq;
+
+    fn app(
+        cache: MockCache,
+    ) -> App<MockImageStore, TestCardStore, MockCache, MockSpoilerSubscription> {
+        App::new(
+            MockImageStore::new(),
+            TestCardStore::new(MockCardStore::new()),
+            cache,
+            MockSpoilerSubscription::new(),
+        )
+    }
+
+    fn interaction(guild_id: Option<u64>) -> MockMessageInteraction {
+        let mut interaction = MockMessageInteraction::new();
+        interaction.expect_requester().return_const(Requester {
+            user_id: 1,
+            guild_id,
+        });
+        interaction.expect_locale().return_const(Locale::English);
+        interaction
+    }
+
+    fn stored(tokens: f64, warned: bool) -> String {
+        ron::to_string(&Bucket {
+            tokens,
+            updated: now() + 60_000,
+            warned,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_refill_stops_at_capacity() {
+        let mut bucket = Bucket {
+            tokens: 0.0,
+            updated: 0,
+            warned: false,
+        };
+        bucket.refill(&USER_LIMIT, 3_000);
+        assert!((bucket.tokens - 1.0).abs() < 1e-9);
+        bucket.refill(&USER_LIMIT, 3_600_000);
+        assert!((bucket.tokens - USER_LIMIT.capacity).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_new_requester_is_allowed() {
+        let mut cache = MockCache::new();
+        cache.expect_get().times(2).return_const(None);
+        cache.expect_set().times(2).returning(|_, _| Ok(()));
+        let mut interaction = interaction(Some(2));
+        interaction.expect_reply_ephemeral().times(0);
+
+        assert!(app(cache).rate_limit(&interaction, 1).await);
+    }
+
+    #[tokio::test]
+    async fn test_empty_bucket_is_warned_once() {
+        let mut cache = MockCache::new();
+        cache
+            .expect_get()
+            .with(eq(user_key(1)))
+            .return_const(Some(stored(0.0, false)));
+        cache
+            .expect_set()
+            .times(1)
+            .withf(|key, bucket| {
+                key == &user_key(1) && ron::from_str::<Bucket>(bucket).unwrap().warned
+            })
+            .returning(|_, _| Ok(()));
+        let mut interaction = interaction(None);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        assert!(!app(cache).rate_limit(&interaction, 1).await);
+    }
+
+    #[tokio::test]
+    async fn test_warned_requester_is_not_told_again() {
+        let mut cache = MockCache::new();
+        cache.expect_get().return_const(Some(stored(0.0, true)));
+        cache.expect_set().returning(|_, _| Ok(()));
+        let mut interaction = interaction(None);
+        interaction.expect_reply_ephemeral().times(0);
+
+        assert!(!app(cache).rate_limit(&interaction, 1).await);
+    }
+
+    #[tokio::test]
+    async fn test_busy_guild_limits_everyone() {
+        let mut cache = MockCache::new();
+        cache.expect_get().with(eq(user_key(1))).return_const(None);
+        cache
+            .expect_get()
+            .with(eq(guild_key(2)))
+            .return_const(Some(stored(0.0, false)));
+        cache.expect_set().returning(|_, _| Ok(()));
+        let mut interaction = interaction(Some(2));
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        assert!(!app(cache).rate_limit(&interaction, 1).await);
+    }
+
+    #[tokio::test]
+    async fn test_too_many_searches_are_turned_away() {
+        let mut cache = MockCache::new();
+        cache.expect_get().times(0);
+        cache.expect_set().times(0);
+        let mut interaction = interaction(None);
+        interaction
+            .expect_reply_ephemeral()
+            .times(1)
+            .with(eq(String::from(
+                "That's too many cards for one message; ask for 10 at most.",
+            )))
+            .returning(|_| Ok(()));
+
+        assert!(!app(cache).rate_limit(&interaction, 25).await);
+    }
+
+    #[tokio::test]
+    async fn test_long_list_fits_a_full_bucket() {
+        let mut cache = MockCache::new();
+        cache.expect_get().return_const(None);
+        cache.expect_set().returning(|_, _| Ok(()));
+        let requester = Requester {
+            user_id: 1,
+            guild_id: Some(2),
+        };
+
+        assert!(app(cache).rate_limit_lines(requester, 100).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_overlong_list_is_turned_away() {
+        let mut cache = MockCache::new();
+        cache.expect_get().times(0);
+        cache.expect_set().times(0);
+        let requester = Requester {
+            user_id: 1,
+            guild_id: None,
+        };
+
+        let denied = app(cache)
+            .rate_limit_lines(requester, MAX_LIST_LINES + 1)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            denied.text(Locale::English),
+            "That list is too long; paste 300 lines at most."
+        );
+    }
+}
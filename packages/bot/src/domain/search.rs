@@ -1,24 +1,35 @@
+use crate::domain::i18n::Message;
+use crate::domain::metrics;
 use crate::domain::query::QueryParams;
+use crate::domain::utils;
 use crate::domain::utils::card_picking::{extract_match, fuzzy_sort};
 use crate::domain::utils::REGEX_COLLECTION;
 use crate::impl_app;
 use crate::ports::drivers::client::MessageInteraction;
 use contracts::card::Card;
+use contracts::card_set::CardSet;
+use contracts::image::Image;
 use contracts::search_result::SearchResultDto;
 use fuzzy;
 use serenity::futures::future::join_all;
 use tokio::time::Instant;
 use uuid::Uuid;
 
+const SET_ABBR_CHAR_LIMIT: usize = 5;
+
+/// The `[[card]]` queries in a message.
+#[must_use]
+pub fn queries(msg: &str) -> Vec<QueryParams> {
+    REGEX_COLLECTION
+        .cards
+        .captures_iter(msg)
+        .filter_map(|capture| QueryParams::from(&capture))
+        .collect()
+}
+
 impl_app! {
-    pub async fn parse_message(&self, msg: &str) -> Vec<Option<SearchResultDto>> {
-        join_all(
-            REGEX_COLLECTION
-                .cards
-                .captures_iter(msg)
-                .filter_map(|capture| Some(self.find_card(QueryParams::from(&capture)?))),
-        )
-        .await
+    pub async fn find_cards(&self, queries: Vec<QueryParams>) -> Vec<Option<SearchResultDto>> {
+        join_all(queries.into_iter().map(|query| self.find_card(query))).await
     }
 
     async fn search_distinct_cards(&self, normalised_name: &str) -> Option<Vec<Card>> {
@@ -48,20 +59,27 @@ impl_app! {
         self.card_store.search_artist(artist, normalised_name).await
     }
 
-    pub async fn find_card(&self, query: QueryParams) -> Option<SearchResultDto> {
+    /// The fuzzy-search half of `find_card`: the best match for `query`
+    /// and the runners-up, without fetching images or printings.
+    pub async fn match_card(&self, query: &QueryParams) -> Option<(Card, Vec<Card>)> {
         let start = Instant::now();
 
-        let found_cards = if let Some(set_code) = query.set_code() {
-            self.search_set_abbreviation(set_code, query.name()).await?
+        let (branch, found_cards) = if let Some(set_code) = query.set_code() {
+            ("set", self.search_set_abbreviation(set_code, query.name()).await)
         } else if let Some(set_name) = query.set_name() {
-            self.search_set_name(set_name, query.name()).await?
+            ("set", self.search_set_name(set_name, query.name()).await)
         } else if let Some(artist) = query.artist() {
-            self.search_artist(artist, query.name()).await?
+            ("artist", self.search_artist(artist, query.name()).await)
         } else {
-            self.search_distinct_cards(query.name()).await?
+            ("name", self.search_distinct_cards(query.name()).await)
         };
 
-        if found_cards.is_empty() {
+        let found = found_cards
+            .filter(|found_cards| !found_cards.is_empty())
+            .and_then(|found_cards| extract_match(found_cards, query.name()));
+        metrics::record_search(branch, start.elapsed(), found.is_some());
+
+        let Some((found_card, discarded)) = found else {
             log::info!(
                 "No match for query '{}' in {} ms",
                 query.name(),
@@ -69,9 +87,7 @@ impl_app! {
             );
 
             return None;
-        }
-
-        let (found_card, discarded) = extract_match(found_cards, query.name())?;
+        };
 
         log::info!(
             "Found match for query '{}' -> '{}' in {} ms",
@@ -80,15 +96,68 @@ impl_app! {
             start.elapsed().as_millis()
         );
 
-        let (sets, images) = tokio::join!(
+        Some((found_card, discarded))
+    }
+
+    /// For names printed in another language: the card printed with the
+    /// localised name closest to `query`, and any others printed with it.
+    /// Set and artist searches only know the English names.
+    async fn match_localised_card(&self, query: &QueryParams) -> Option<(Card, Vec<Card>)> {
+        if query.set_code().is_some() || query.set_name().is_some() || query.artist().is_some() {
+            return None;
+        }
+
+        let start = Instant::now();
+        let found = self.find_localised_card(query).await;
+        metrics::record_search("localised", start.elapsed(), found.is_some());
+        found
+    }
+
+    async fn find_localised_card(&self, query: &QueryParams) -> Option<(Card, Vec<Card>)> {
+        let potentials = self
+            .card_store
+            .search_for_localised_name(query.name())
+            .await?;
+        let localised_name = fuzzy::winkliest_match(&query.name(), potentials)?;
+        let mut cards = self
+            .card_store
+            .cards_by_localised_name(&localised_name)
+            .await?;
+        if cards.is_empty() {
+            return None;
+        }
+
+        let found_card = cards.remove(0);
+        log::info!(
+            "Found localised match for query '{}' -> '{}' ({localised_name})",
+            query.name(),
+            found_card.name()
+        );
+        Some((found_card, cards))
+    }
+
+    pub async fn find_card(&self, query: QueryParams) -> Option<SearchResultDto> {
+        let (found_card, discarded) = if let Some(found) = self.match_card(&query).await {
+            found
+        } else {
+            self.match_localised_card(&query).await?
+        };
+        let (sets, images, functionally_similar) = tokio::join!(
             self.card_store.all_prints(found_card.oracle_id()),
             self.image_store.fetch(&found_card),
+            self.card_store.functionally_similar_cards(&found_card),
         );
 
+        let Ok(image) = images else {
+            metrics::IMAGE_FETCH_FAILURES.inc();
+            return None;
+        };
+
         Some(
-            SearchResultDto::new(found_card, images.ok()?)
+            SearchResultDto::new(found_card, image)
                 .add_printings(sets)
-                .add_similar_cards(discarded),
+                .add_similar_cards(discarded)
+                .add_functionally_similar(functionally_similar),
         )
     }
 
@@ -96,21 +165,57 @@ impl_app! {
         let start = Instant::now();
         let card = self.card_store.fetch_card_by_id(card_id).await?;
 
-        let (sets, images, similar_cards) = tokio::join!(
+        let (sets, images, similar_cards, functionally_similar) = tokio::join!(
             self.card_store.all_prints(card.oracle_id()),
             self.image_store.fetch(&card),
             self.card_store.similar_cards(&card),
+            self.card_store.functionally_similar_cards(&card),
         );
 
         let similar_cards = fuzzy_sort(card.normalised_name(), similar_cards?);
         log::info!("Fetch new print in {}ms", start.elapsed().as_millis());
+        let Ok(image) = images else {
+            metrics::IMAGE_FETCH_FAILURES.inc();
+            return None;
+        };
         Some(
-            SearchResultDto::new(card, images.ok()?)
+            SearchResultDto::new(card, image)
                 .add_printings(sets)
-                .add_similar_cards(similar_cards),
+                .add_similar_cards(similar_cards)
+                .add_functionally_similar(functionally_similar),
         )
     }
 
+    /// Every printing of the card with `card_id`.
+    pub async fn printings(&self, card_id: &Uuid) -> Option<Vec<CardSet>> {
+        let card = self.card_store.fetch_card_by_id(card_id).await?;
+        self.card_store.all_prints(card.oracle_id()).await
+    }
+
+    /// A random card, from `set` if there is one, as it'd be found by id.
+    pub async fn random_card(&self, set: Option<&str>) -> Option<SearchResultDto> {
+        let card = match set {
+            Some(set) => {
+                let set_name = self.resolve_set(set).await?;
+                self.card_store.random_card_from_set(&set_name, &[]).await?
+            }
+            None => self.card_store.random_card(&[]).await?,
+        };
+        self.fetch_from_id(card.id()).await
+    }
+
+    pub async fn card_image(&self, card_id: &Uuid) -> Option<Image> {
+        let card = self.card_store.fetch_card_by_id(card_id).await?;
+        match self.image_store.fetch(&card).await {
+            Ok(image) => Some(image),
+            Err(why) => {
+                log::warn!("Couldn't read image for {card_id}: {why}");
+                metrics::IMAGE_FETCH_FAILURES.inc();
+                None
+            }
+        }
+    }
+
     pub async fn fuzzy_match_set_name(&self, normalised_set_name: &str) -> Option<String> {
         let potentials = self
             .card_store
@@ -125,14 +230,28 @@ impl_app! {
             .await
     }
 
+    /// Resolves a user-supplied set to its normalised name: short input is
+    /// looked up as a set code, anything longer is fuzzy matched by name.
+    pub async fn resolve_set(&self, set: &str) -> Option<String> {
+        if set.chars().count() < SET_ABBR_CHAR_LIMIT {
+            self.set_from_abbreviation(set).await
+        } else {
+            self.fuzzy_match_set_name(&utils::normalise_card_name(set))
+                .await
+        }
+    }
+
     pub async fn search<I: MessageInteraction>(&self, interaction: &I, query_params: QueryParams) {
+        if !self.rate_limit(interaction, 1).await {
+            return;
+        }
         let result = self.find_card(query_params).await;
         if let Some(result) = result {
             if let Err(why) = interaction.send_card(result).await {
                 log::warn!("Error sending card from search command: {why}");
             };
         } else if let Err(why) = interaction
-            .reply(String::from("Could not find card :("))
+            .reply(Message::CardNotFound.text(interaction.locale()))
             .await
         {
             log::warn!("Error the failed to find card message from search command: {why}");
@@ -148,7 +267,7 @@ impl_app! {
             }
             None => {
                 if let Err(why) = interaction
-                    .reply(String::from("Could not find that print :("))
+                    .reply(Message::PrintNotFound.text(interaction.locale()))
                     .await
                 {
                     log::warn!("Error sending print not found: {why}");
@@ -162,6 +281,8 @@ impl_app! {
 mod tests {
     use super::*;
     use crate::domain::app::App;
+    use crate::domain::i18n::Locale;
+    use crate::domain::rate_limit::{expect_unlimited, Requester};
     use crate::ports::drivers::client::MockMessageInteraction;
     use crate::ports::services::cache::MockCache;
     use crate::ports::services::card_store::{MockCardStore, TestCardStore};
@@ -171,6 +292,13 @@ mod tests {
     use mockall::predicate::eq;
     use uuid::{uuid, Uuid};
 
+    fn requester() -> Requester {
+        Requester {
+            user_id: 1,
+            guild_id: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_search() {
         let query = QueryParams::from_test(String::from("gitrog monster"), None, None, None);
@@ -214,9 +342,14 @@ mod tests {
             .with(eq(name))
             .return_const(Some(vec![card.clone()]));
         card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
 
-        let cache = MockCache::new();
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_requester().return_const(requester());
         interaction.expect_send_card().times(1).return_const(Ok(()));
 
         let app = App::new(
@@ -242,9 +375,15 @@ mod tests {
             .times(1)
             .with(eq(name))
             .return_const(None);
+        card_store
+            .expect_search_for_localised_name()
+            .return_const(None);
 
-        let cache = MockCache::new();
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_requester().return_const(requester());
+        interaction.expect_locale().return_const(Locale::English);
         interaction
             .expect_reply()
             .times(1)
@@ -296,9 +435,14 @@ mod tests {
             .with(eq("Limited Edition Alpha"), eq(name))
             .return_const(Some(vec![card.clone()]));
         card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
 
-        let cache = MockCache::new();
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_requester().return_const(requester());
         interaction.expect_send_card().times(1).return_const(Ok(()));
 
         let app = App::new(
@@ -366,9 +510,14 @@ mod tests {
             .with(eq("Christopher Rush"), eq(name))
             .return_const(Some(vec![card.clone()]));
         card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
 
-        let cache = MockCache::new();
+        let mut cache = MockCache::new();
+        expect_unlimited(&mut cache);
         let mut interaction = MockMessageInteraction::new();
+        interaction.expect_requester().return_const(requester());
         interaction.expect_send_card().times(1).return_const(Ok(()));
 
         let app = App::new(
@@ -382,7 +531,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_parse_message_single_card() {
+    async fn test_find_cards_in_message_single_card() {
         let card = make_test_card(
             uuid!("12345678-1234-1234-1234-123456789012"),
             "Lightning Bolt",
@@ -404,6 +553,9 @@ mod tests {
             .with(eq("lightning bolt"))
             .return_const(Some(vec![card.clone()]));
         card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
 
         let cache = MockCache::new();
         let app = App::new(
@@ -413,7 +565,9 @@ mod tests {
             MockSpoilerSubscription::new(),
         );
 
-        let results = app.parse_message("Check out [[Lightning Bolt]]!").await;
+        let results = app
+            .find_cards(queries("Check out [[Lightning Bolt]]!"))
+            .await;
 
         assert_eq!(results.len(), 1);
         assert!(results[0].is_some());
@@ -423,7 +577,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_parse_message_multiple_cards() {
+    async fn test_find_cards_in_message_multiple_cards() {
         let bolt_card = make_test_card(
             uuid!("12345678-1234-1234-1234-123456789012"),
             "Lightning Bolt",
@@ -457,6 +611,9 @@ mod tests {
             .with(eq("giant growth"))
             .return_const(Some(vec![giant_card.clone()]));
         card_store.expect_all_prints().times(2).returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
 
         let cache = MockCache::new();
         let app = App::new(
@@ -467,7 +624,7 @@ mod tests {
         );
 
         let results = app
-            .parse_message("I love [[Lightning Bolt]] and [[Giant Growth]]!")
+            .find_cards(queries("I love [[Lightning Bolt]] and [[Giant Growth]]!"))
             .await;
 
         assert_eq!(results.len(), 2);
@@ -476,7 +633,7 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_parse_message_no_cards() {
+    async fn test_find_cards_in_message_no_cards() {
         let image_store = MockImageStore::new();
         let card_store = MockCardStore::new();
         let cache = MockCache::new();
@@ -488,7 +645,7 @@ mod tests {
         );
 
         let results = app
-            .parse_message("This message has no card references")
+            .find_cards(queries("This message has no card references"))
             .await;
 
         assert_eq!(results.len(), 0);
@@ -523,6 +680,9 @@ mod tests {
             .with(eq("limited edition alpha"), eq("lightning bolt"))
             .return_const(Some(vec![card.clone()]));
         card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
 
         let cache = MockCache::new();
         let app = App::new(
@@ -553,6 +713,9 @@ mod tests {
             .times(1)
             .with(eq(name))
             .return_const(Some(vec![]));
+        card_store
+            .expect_search_for_localised_name()
+            .return_const(Some(vec![]));
 
         let cache = MockCache::new();
         let app = App::new(
@@ -567,6 +730,73 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn test_find_card_by_localised_name() {
+        let query = QueryParams::from_test(String::from("blitzschlg"), None, None, None);
+        let card = make_test_card(
+            uuid!("77c6fa74-5543-42ac-9ead-0e890b188e99"),
+            "Lightning Bolt",
+            "lightning bolt",
+            "Limited Edition Alpha",
+        );
+
+        let mut image_store = MockImageStore::new();
+        image_store
+            .expect_fetch()
+            .returning(|_| Ok(Image::new(vec![1, 2, 3])));
+
+        let mut card_store = MockCardStore::new();
+        card_store.expect_search().return_const(Some(vec![]));
+        card_store
+            .expect_search_for_localised_name()
+            .with(eq("blitzschlg"))
+            .return_const(Some(vec![
+                String::from("blitzschlag"),
+                String::from("blitz"),
+            ]));
+        card_store
+            .expect_cards_by_localised_name()
+            .with(eq("blitzschlag"))
+            .return_const(Some(vec![card]));
+        card_store.expect_all_prints().returning(|_| None);
+        card_store
+            .expect_functionally_similar_cards()
+            .returning(|_| None);
+
+        let app = App::new(
+            image_store,
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        );
+
+        let result = app.find_card(query).await.unwrap();
+        assert_eq!(result.card().name(), "Lightning Bolt");
+    }
+
+    #[tokio::test]
+    async fn test_set_search_skips_localised_names() {
+        let query = QueryParams::from_test(
+            String::from("blitzschlag"),
+            None,
+            Some(String::from("alpha")),
+            None,
+        );
+
+        let mut card_store = MockCardStore::new();
+        card_store.expect_search_set().return_const(Some(vec![]));
+        card_store.expect_search_for_localised_name().times(0);
+
+        let app = App::new(
+            MockImageStore::new(),
+            TestCardStore::new(card_store),
+            MockCache::new(),
+            MockSpoilerSubscription::new(),
+        );
+
+        assert!(app.find_card(query).await.is_none());
+    }
+
     #[tokio::test]
     async fn test_fuzzy_match_set_name() {
         let mut card_store = MockCardStore::new();
@@ -5,7 +5,9 @@ macro_rules! impl_app {
           where
               IS: $crate::ports::services::image_store::ImageStore + Send + Sync,
               CS: $crate::ports::services::card_store::CardStore
-                  + ::cards_sdk::SpoilerQueue + Send + Sync,
+                  + ::cards_sdk::SpoilerQueue
+                  + ::cards_sdk::StatsRepository
+                  + ::cards_sdk::CubeRepository + Send + Sync,
               C: $crate::ports::services::cache::Cache + Send + Sync,
               Sub: $crate::ports::services::spoiler_subscription::SpoilerSubscription
                   + Send + Sync,
@@ -23,7 +25,9 @@ macro_rules! impl_async_for_app {
           where
               IS: $crate::ports::services::image_store::ImageStore + Send + Sync,
               CS: $crate::ports::services::card_store::CardStore
-                  + ::cards_sdk::SpoilerQueue + Send + Sync,
+                  + ::cards_sdk::SpoilerQueue
+                  + ::cards_sdk::StatsRepository
+                  + ::cards_sdk::CubeRepository + Send + Sync,
               C: $crate::ports::services::cache::Cache + Send + Sync,
               Sub: $crate::ports::services::spoiler_subscription::SpoilerSubscription
                   + Send + Sync,
@@ -1,5 +1,6 @@
 pub mod card_picking;
 pub mod impl_app;
+pub mod raster;
 
 use regex::Regex;
 use std::sync::LazyLock;
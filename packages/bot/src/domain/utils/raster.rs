@@ -0,0 +1,192 @@
+use std::io::Cursor;
+
+const CHANNELS: usize = 4;
+
+/// A decoded image as 8-bit RGBA, for the few places the bot composes card
+/// images itself rather than passing the stored PNGs straight through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// A fully transparent canvas.
+    #[must_use]
+    pub fn blank(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; width * height * CHANNELS],
+        }
+    }
+
+    /// `None` if `bytes` isn't a PNG that can be decoded.
+    #[must_use]
+    pub fn decode_png(bytes: &[u8]) -> Option<Self> {
+        let mut decoder = png::Decoder::new(Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info().ok()?;
+        let mut buffer = vec![0; reader.output_buffer_size()?];
+        let info = reader.next_frame(&mut buffer).ok()?;
+        let buffer = &buffer[..info.buffer_size()];
+
+        let pixels = match info.color_type.samples() {
+            1 => buffer
+                .iter()
+                .flat_map(|grey| [*grey, *grey, *grey, u8::MAX])
+                .collect(),
+            2 => buffer
+                .chunks_exact(2)
+                .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], pixel[1]])
+                .collect(),
+            3 => buffer
+                .chunks_exact(3)
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], u8::MAX])
+                .collect(),
+            4 => buffer.to_vec(),
+            _ => return None,
+        };
+
+        Some(Self {
+            width: usize::try_from(info.width).ok()?,
+            height: usize::try_from(info.height).ok()?,
+            pixels,
+        })
+    }
+
+    /// `None` only if the image is too big for a PNG.
+    #[must_use]
+    pub fn encode_png(&self) -> Option<Vec<u8>> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(
+            &mut bytes,
+            u32::try_from(self.width).ok()?,
+            u32::try_from(self.height).ok()?,
+        );
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().ok()?;
+        writer.write_image_data(&self.pixels).ok()?;
+        writer.finish().ok()?;
+        Some(bytes)
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Averages each 2 × 2 block of pixels into one.
+    #[must_use]
+    pub fn halve(&self) -> Self {
+        let (width, height) = (self.width / 2, self.height / 2);
+        let mut pixels = Vec::with_capacity(width * height * CHANNELS);
+        for y in 0..height {
+            for x in 0..width {
+                for channel in 0..CHANNELS {
+                    let at = |dx: usize, dy: usize| {
+                        u16::from(
+                            self.pixels
+                                [((y * 2 + dy) * self.width + x * 2 + dx) * CHANNELS + channel],
+                        )
+                    };
+                    let sum = at(0, 0) + at(1, 0) + at(0, 1) + at(1, 1);
+                    pixels.push(u8::try_from(sum / 4).unwrap_or(u8::MAX));
+                }
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Copies `other` in with its top-left corner at `x`, `y`, clipping
+    /// whatever falls off the edge.
+    pub fn paste(&mut self, other: &Raster, x: usize, y: usize) {
+        let columns = other.width.min(self.width.saturating_sub(x));
+        for row in 0..other.height.min(self.height.saturating_sub(y)) {
+            let from = row * other.width * CHANNELS;
+            let to = ((y + row) * self.width + x) * CHANNELS;
+            self.pixels[to..to + columns * CHANNELS]
+                .copy_from_slice(&other.pixels[from..from + columns * CHANNELS]);
+        }
+    }
+
+    /// RGB with transparency composited onto white, so rounded corners
+    /// print as paper rather than black.
+    #[must_use]
+    pub fn flatten(&self) -> Vec<u8> {
+        let over_white = |colour: u8, alpha: u8| {
+            let colour = u16::from(colour) * u16::from(alpha) + 255 * u16::from(255 - alpha);
+            u8::try_from(colour / 255).unwrap_or(u8::MAX)
+        };
+        self.pixels
+            .chunks_exact(CHANNELS)
+            .flat_map(|pixel| {
+                [
+                    over_white(pixel[0], pixel[3]),
+                    over_white(pixel[1], pixel[3]),
+                    over_white(pixel[2], pixel[3]),
+                ]
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raster(width: usize, height: usize, pixels: &[u8]) -> Raster {
+        Raster {
+            width,
+            height,
+            pixels: pixels.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_round_trips_through_png() {
+        let original = raster(2, 1, &[255, 0, 0, 255, 0, 0, 255, 128]);
+        let decoded = Raster::decode_png(&original.encode_png().unwrap()).unwrap();
+        assert_eq!(decoded, original);
+        assert!(Raster::decode_png(b"not a png").is_none());
+    }
+
+    #[test]
+    fn test_transparent_pixels_flatten_to_white() {
+        let image = raster(2, 1, &[0, 0, 0, 0, 10, 20, 30, 255]);
+        assert_eq!(image.flatten(), vec![255, 255, 255, 10, 20, 30]);
+    }
+
+    #[test]
+    fn test_halve_averages_blocks() {
+        let image = raster(
+            2,
+            2,
+            &[
+                0, 0, 0, 255, 100, 100, 100, 255, 200, 200, 200, 255, 100, 100, 100, 255,
+            ],
+        );
+        assert_eq!(image.halve(), raster(1, 1, &[100, 100, 100, 255]));
+    }
+
+    #[test]
+    fn test_paste_clips_at_the_edge() {
+        let mut canvas = Raster::blank(2, 2);
+        canvas.paste(&raster(2, 1, &[1, 1, 1, 1, 2, 2, 2, 2]), 1, 1);
+        assert_eq!(
+            canvas,
+            raster(2, 2, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1])
+        );
+    }
+}
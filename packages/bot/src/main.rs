@@ -1,10 +1,12 @@
-use crate::adapters::drivers::create_client;
+use crate::adapters::drivers::health::BotHealth;
+use crate::adapters::drivers::{create_api, create_client, create_terminal};
 use crate::adapters::services::cache::init_cache;
 use crate::adapters::services::card_store::init_card_store;
 use crate::adapters::services::image_store::init_image_store;
 use crate::adapters::services::spoiler_subscription::init_spoiler_subscription;
 use crate::domain::app::App;
 use crate::ports::drivers::client::Client;
+use std::env;
 
 #[cfg(feature = "local-dev")]
 use dotenv::dotenv;
@@ -19,13 +21,30 @@ async fn main() {
     dotenv().ok();
 
     env_logger::init();
+    domain::metrics::init();
+
+    // `rustcord api` serves card search over HTTP, and `rustcord repl`
+    // answers what's typed at a terminal, instead of joining Discord.
+    let driver = env::args().nth(1).unwrap_or_default();
+
     let card_store = init_card_store().await;
     let image_store = init_image_store();
     let cache = init_cache();
     let sub = init_spoiler_subscription();
 
-    let app = App::new(image_store, card_store, cache, sub);
-    let mut client = create_client(app).await;
+    if driver == "repl" {
+        let app = App::new(image_store, card_store, cache, sub);
+        create_terminal(app).run().await;
+        return;
+    }
 
-    client.run().await;
+    let api = driver == "api";
+    telemetry::serve_with(BotHealth::new(card_store.clone(), cache.clone(), !api));
+
+    let app = App::new(image_store, card_store, cache, sub);
+    if api {
+        create_api(app).run().await;
+    } else {
+        create_client(app).await.run().await;
+    }
 }
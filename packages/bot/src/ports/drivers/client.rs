@@ -1,5 +1,19 @@
+use crate::domain::functions::artist::ArtistGallery;
+use crate::domain::functions::deck::commander::CommanderReport;
+use crate::domain::functions::deck::report::DeckReport;
 use crate::domain::functions::game::state::GameState;
+use crate::domain::functions::higher_lower::state::{HigherLowerState, Reveal};
+use crate::domain::functions::oracle::OracleResults;
+use crate::domain::functions::pack::booster::{Booster, SealedPool};
+use crate::domain::functions::rules::RuleEntry;
+use crate::domain::functions::set::SetPage;
+use crate::domain::functions::trivia::state::{TriviaOutcome, TriviaState};
+use crate::domain::grid::CardGrid;
+use crate::domain::i18n::Locale;
+use crate::domain::rate_limit::Requester;
 use async_trait::async_trait;
+use cards_sdk::GameStats;
+use contracts::card::Card;
 use contracts::{image::Image, search_result::SearchResultDto};
 use thiserror::Error;
 
@@ -27,6 +41,39 @@ pub trait MessageInteraction {
     /// responses that shouldn't clutter the channel for everyone else, e.g.
     /// a permissions error only the admin who ran the command can act on.
     async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError>;
+    /// Another message after the first `reply`, for responses too long to
+    /// fit in one.
+    async fn follow_up(&self, message: String) -> Result<(), MessageInteractionError>;
+    /// The language to answer in: the server's, or else the user's.
+    fn locale(&self) -> Locale;
+    /// Who's asking, for rate limiting.
+    fn requester(&self) -> Requester;
+}
+
+/// Sends several inline results at once as a `CardGrid`.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait GridInteraction {
+    async fn send_grid(&self, grid: CardGrid) -> Result<(), MessageInteractionError>;
+}
+
+/// A message with inline queries, whose replies are remembered so they can
+/// follow the message when it's edited or deleted.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ReplyCleanup {
+    /// Identifies the message being replied to.
+    fn source(&self) -> String;
+    async fn delete_replies(&self, replies: Vec<u64>);
+}
+
+#[async_trait]
+pub trait ReplyTracking: ReplyCleanup {
+    /// Ids of the replies sent so far, in the order they went out.
+    fn sent(&self) -> Vec<u64>;
+    /// Earlier replies to edit in place, in order, before any new ones are
+    /// sent.
+    fn reuse(&self, replies: Vec<u64>);
 }
 
 #[cfg_attr(test, automock)]
@@ -54,7 +101,175 @@ pub trait GameInteraction {
         images: Image,
     ) -> Result<(), MessageInteractionError>;
     fn id(&self) -> String;
+    /// The guild the game is being played in, or `None` in a DM — used to
+    /// scope per-server state such as the recent-card history.
+    fn guild_id(&self) -> Option<String>;
+    /// The player who triggered this interaction, as a Discord snowflake.
+    fn user_id(&self) -> u64;
     async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+    /// The language to answer in: the server's, or else the user's.
+    fn locale(&self) -> Locale;
+}
+
+/// The multiple-choice counterpart to `GameInteraction`: a question goes
+/// out with one button per choice, and whichever player answers first ends
+/// the round.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait TriviaInteraction {
+    /// `image` is the card's illustration when the clue is the art, and
+    /// `None` when it's the rules text.
+    async fn send_question(
+        &self,
+        state: TriviaState,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError>;
+    async fn send_outcome(
+        &self,
+        outcome: TriviaOutcome,
+        image: Image,
+    ) -> Result<(), MessageInteractionError>;
+    fn id(&self) -> String;
+    fn guild_id(&self) -> Option<String>;
+    fn user_id(&self) -> u64;
+    fn locale(&self) -> Locale;
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+/// A single player's `/higherlower` run: each round shows two priced cards
+/// with a button for each, and the run carries on until a wrong call.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait HigherLowerInteraction {
+    /// `images` are the left and right cards' images; `last` is the call
+    /// that led to this round, if it isn't the first.
+    async fn send_round(
+        &self,
+        state: HigherLowerState,
+        images: (Image, Image),
+        last: Option<Reveal>,
+    ) -> Result<(), MessageInteractionError>;
+    async fn send_game_over(
+        &self,
+        reveal: Reveal,
+        stats: Option<GameStats>,
+        images: (Image, Image),
+    ) -> Result<(), MessageInteractionError>;
+    fn user_id(&self) -> u64;
+    fn locale(&self) -> Locale;
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait PackInteraction {
+    /// `image` is the pack's rare, shown alongside the list.
+    async fn send_pack(
+        &self,
+        booster: Booster,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError>;
+    async fn send_sealed(&self, pool: SealedPool) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait CubeInteraction {
+    /// Whether whoever ran it has Manage Server, which changing a cube needs.
+    fn can_manage_guild(&self) -> bool;
+    /// One list per pack, each `PACK_SIZE` cards.
+    async fn send_packs(
+        &self,
+        name: String,
+        packs: Vec<Vec<Card>>,
+    ) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    fn requester(&self) -> Requester;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeckInteraction {
+    async fn send_report(&self, report: DeckReport) -> Result<(), MessageInteractionError>;
+    async fn send_commander_report(
+        &self,
+        report: CommanderReport,
+    ) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    fn requester(&self) -> Requester;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ProxyInteraction {
+    /// `skipped` names the cards left out of the PDF, either because they
+    /// couldn't be found or because there's no image of them.
+    async fn send_pdf(
+        &self,
+        pdf: Vec<u8>,
+        skipped: Vec<String>,
+    ) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    fn requester(&self) -> Requester;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SetInteraction {
+    async fn send_set(&self, page: SetPage) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait RulesInteraction {
+    async fn send_rule(&self, entry: RuleEntry) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait OracleInteraction {
+    async fn send_results(&self, results: OracleResults) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait ArtistInteraction {
+    /// `image` is the page's illustrations as one grid, if it could be made.
+    async fn send_gallery(
+        &self,
+        gallery: ArtistGallery,
+        image: Option<Image>,
+    ) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    async fn reply(&self, message: String) -> Result<(), MessageInteractionError>;
+}
+
+/// A press of the delete button under one of the bot's replies.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait DeleteInteraction {
+    fn user_id(&self) -> u64;
+    /// Whether whoever pressed it has Manage Messages in the channel.
+    fn can_manage_messages(&self) -> bool;
+    /// For an inline reply, identifies the message it answered, as
+    /// `ReplyCleanup::source` does.
+    fn source(&self) -> Option<String>;
+    /// The id of the reply being deleted.
+    fn reply_id(&self) -> u64;
+    async fn delete_reply(&self) -> Result<(), MessageInteractionError>;
+    fn locale(&self) -> Locale;
+    async fn reply_ephemeral(&self, message: String) -> Result<(), MessageInteractionError>;
 }
 
 #[async_trait]
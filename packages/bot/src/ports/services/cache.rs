@@ -22,4 +22,6 @@ pub trait Cache {
     async fn get(&self, key: String) -> Option<String>;
     async fn set(&self, key: String, value: String) -> Result<(), CacheError>;
     async fn delete(&self, key: String) -> Result<(), CacheError>;
+    /// Whether the cache answers at all, for health checks.
+    async fn ping(&self) -> bool;
 }
@@ -3,24 +3,40 @@ pub use cards_sdk::ReadRepository as CardStore;
 #[cfg(test)]
 pub use cards_sdk::MockReadRepository as MockCardStore;
 
-/// Test-only combined double: `App`'s `CS` bound is `CardStore + SpoilerQueue`
-/// (one repository trait, kept as a single generic parameter, mirroring how
-/// the *production* `cards_sdk::Postgres` value satisfies both traits at
-/// once) — but `mockall::automock` mints one mock struct per trait, so
-/// existing tests that only exercise `ReadRepository` methods need a
-/// zero-behavior `SpoilerQueue` delegate bolted on to keep typechecking.
+/// Test-only combined double: `App`'s `CS` bound is
+/// `CardStore + SpoilerQueue + StatsRepository + CubeRepository` (one
+/// repository, kept as a single generic parameter, mirroring how the
+/// *production* `cards_sdk::Postgres` value satisfies every trait at once) —
+/// but `mockall::automock` mints one mock struct per trait, so existing
+/// tests that only exercise `ReadRepository` methods need zero-behavior
+/// delegates for the other traits bolted on to keep typechecking.
 #[cfg(test)]
 pub struct TestCardStore {
     pub read: MockCardStore,
     pub spoiler: cards_sdk::MockSpoilerQueue,
+    pub stats: cards_sdk::MockStatsRepository,
+    pub cube: cards_sdk::MockCubeRepository,
 }
 
 #[cfg(test)]
 impl TestCardStore {
     pub fn new(read: MockCardStore) -> Self {
+        Self::with_stats(read, cards_sdk::MockStatsRepository::new())
+    }
+
+    pub fn with_stats(read: MockCardStore, stats: cards_sdk::MockStatsRepository) -> Self {
         Self {
             read,
             spoiler: cards_sdk::MockSpoilerQueue::new(),
+            stats,
+            cube: cards_sdk::MockCubeRepository::new(),
+        }
+    }
+
+    pub fn with_cube(read: MockCardStore, cube: cards_sdk::MockCubeRepository) -> Self {
+        Self {
+            cube,
+            ..Self::new(read)
         }
     }
 }
@@ -51,11 +67,27 @@ impl cards_sdk::ReadRepository for TestCardStore {
     async fn set_name_from_abbreviation(&self, abbreviation: &str) -> Option<String> {
         self.read.set_name_from_abbreviation(abbreviation).await
     }
-    async fn random_card(&self) -> Option<contracts::card::Card> {
-        self.read.random_card().await
+    async fn search_for_artist_name(&self, normalised_name: &str) -> Option<Vec<String>> {
+        self.read.search_for_artist_name(normalised_name).await
+    }
+    async fn artist_card_count(&self, artist_name: &str) -> Option<u32> {
+        self.read.artist_card_count(artist_name).await
     }
-    async fn random_card_from_set(&self, set_name: &str) -> Option<contracts::card::Card> {
-        self.read.random_card_from_set(set_name).await
+    async fn artist_illustrations(&self, artist_name: &str) -> Option<Vec<contracts::card::Card>> {
+        self.read.artist_illustrations(artist_name).await
+    }
+    async fn search_oracle(&self, text: &str, limit: i64) -> Option<Vec<contracts::card::Card>> {
+        self.read.search_oracle(text, limit).await
+    }
+    async fn random_card(&self, exclude: &[uuid::Uuid]) -> Option<contracts::card::Card> {
+        self.read.random_card(exclude).await
+    }
+    async fn random_card_from_set(
+        &self,
+        set_name: &str,
+        exclude: &[uuid::Uuid],
+    ) -> Option<contracts::card::Card> {
+        self.read.random_card_from_set(set_name, exclude).await
     }
     async fn all_prints(
         &self,
@@ -72,6 +104,63 @@ impl cards_sdk::ReadRepository for TestCardStore {
     ) -> Option<Vec<contracts::card::Card>> {
         self.read.similar_cards(card).await
     }
+    async fn functionally_similar_cards(
+        &self,
+        card: &contracts::card::Card,
+    ) -> Option<Vec<contracts::card::Card>> {
+        self.read.functionally_similar_cards(card).await
+    }
+    async fn cards_like(
+        &self,
+        card: &contracts::card::Card,
+        limit: i64,
+    ) -> Option<Vec<contracts::card::Card>> {
+        self.read.cards_like(card, limit).await
+    }
+    async fn random_priced_cards(
+        &self,
+        currency: contracts::priced_card::Currency,
+        limit: i64,
+    ) -> Option<Vec<contracts::priced_card::PricedCard>> {
+        self.read.random_priced_cards(currency, limit).await
+    }
+    async fn set_cards(&self, set_name: &str) -> Option<Vec<contracts::set_card::SetCard>> {
+        self.read.set_cards(set_name).await
+    }
+    async fn set_details(&self, set_name: &str) -> Option<contracts::set_details::SetDetails> {
+        self.read.set_details(set_name).await
+    }
+    async fn card_details(
+        &self,
+        ids: &[uuid::Uuid],
+        currency: contracts::priced_card::Currency,
+    ) -> Option<Vec<contracts::card_details::CardDetails>> {
+        self.read.card_details(ids, currency).await
+    }
+    async fn rule_sections(
+        &self,
+        number: &str,
+    ) -> Option<Vec<contracts::rule_section::RuleSection>> {
+        self.read.rule_sections(number).await
+    }
+    async fn search_for_rule_title(&self, normalised_title: &str) -> Option<Vec<String>> {
+        self.read.search_for_rule_title(normalised_title).await
+    }
+    async fn rule_number(&self, normalised_title: &str) -> Option<String> {
+        self.read.rule_number(normalised_title).await
+    }
+    async fn search_for_localised_name(&self, normalised_name: &str) -> Option<Vec<String>> {
+        self.read.search_for_localised_name(normalised_name).await
+    }
+    async fn cards_by_localised_name(
+        &self,
+        normalised_name: &str,
+    ) -> Option<Vec<contracts::card::Card>> {
+        self.read.cards_by_localised_name(normalised_name).await
+    }
+    async fn ping(&self) -> bool {
+        self.read.ping().await
+    }
 }
 
 #[cfg(test)]
@@ -127,3 +216,39 @@ impl cards_sdk::SpoilerQueue for TestCardStore {
         self.spoiler.prune_queue().await;
     }
 }
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl cards_sdk::StatsRepository for TestCardStore {
+    async fn record_result(
+        &self,
+        user_id: cards_sdk::UserId,
+        game: cards_sdk::Game,
+        won: bool,
+    ) -> Option<cards_sdk::GameStats> {
+        self.stats.record_result(user_id, game, won).await
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl cards_sdk::CubeRepository for TestCardStore {
+    async fn create_cube(&self, guild_id: cards_sdk::GuildId, name: &str) -> Option<bool> {
+        self.cube.create_cube(guild_id, name).await
+    }
+    async fn add_to_cube(
+        &self,
+        guild_id: cards_sdk::GuildId,
+        name: &str,
+        card_ids: &[uuid::Uuid],
+    ) -> Option<usize> {
+        self.cube.add_to_cube(guild_id, name, card_ids).await
+    }
+    async fn cube_cards(
+        &self,
+        guild_id: cards_sdk::GuildId,
+        name: &str,
+    ) -> Option<Vec<contracts::card::Card>> {
+        self.cube.cube_cards(guild_id, name).await
+    }
+}
@@ -64,6 +64,7 @@ macro_rules! sub_id {
 sub_id!(GuildId);
 sub_id!(ChannelId);
 sub_id!(SubscriptionId);
+sub_id!(UserId);
 
 #[cfg(test)]
 mod tests {
@@ -13,6 +13,44 @@ pub struct Set {
     pub abbreviation: String,
 }
 
+/// A set as listed by the source, with the details its cards don't carry.
+pub struct SetInfo {
+    pub set: Set,
+    pub set_type: String,
+    pub release_date: Date,
+    pub card_count: i32,
+    pub block: Option<String>,
+    pub parent_abbreviation: Option<String>,
+    pub icon_svg_uri: String,
+}
+
+/// One numbered entry of the Comprehensive Rules, as parsed from the
+/// document. `parent` is the number it sits under: `702.19` for `702.19b`,
+/// `702` for `702.19` and `7` for `702`.
+pub struct RuleSectionInfo {
+    pub number: String,
+    pub parent: Option<String>,
+    pub title: Option<String>,
+    pub normalised_title: Option<String>,
+    pub text: String,
+}
+
+/// `similar_oracle_id` plays like `oracle_id`, by `score` from 0 to 1.
+pub struct FunctionalSimilarity {
+    pub oracle_id: Uuid,
+    pub similar_oracle_id: Uuid,
+    pub score: f32,
+}
+
+/// A card's name as printed in `lang`, one of Scryfall's language codes
+/// like `de` or `ja`.
+pub struct LocalisedName {
+    pub oracle_id: Uuid,
+    pub lang: String,
+    pub name: String,
+    pub normalised_name: String,
+}
+
 pub struct Artist {
     pub id: Uuid,
     pub name: String,
@@ -3,15 +3,22 @@ pub mod ingest;
 mod postgres;
 pub mod repository;
 pub mod spoiler;
+pub mod stats;
 
-pub use ids::{ChannelId, GuildId, SubscriptionId};
+pub use ids::{ChannelId, GuildId, SubscriptionId, UserId};
 pub use ingest::{
-    Artist, CardInfo, CardRecord, Combo, Illustration, Image, Legality, Price, RelatedToken, Rule,
-    Set, UpsertResult,
+    Artist, CardInfo, CardRecord, Combo, FunctionalSimilarity, Illustration, Image, Legality,
+    LocalisedName, Price, RelatedToken, Rule, RuleSectionInfo, Set, SetInfo, UpsertResult,
 };
 pub use postgres::Postgres;
-pub use repository::{ReadRepository, SpoilerQueue, WriteRepository};
+pub use repository::{
+    CubeRepository, ReadRepository, SpoilerQueue, StatsRepository, WriteRepository,
+};
 pub use spoiler::{PendingCard, Subscription};
+pub use stats::{Game, GameStats};
 
 #[cfg(feature = "test-util")]
-pub use repository::{MockReadRepository, MockSpoilerQueue, MockWriteRepository};
+pub use repository::{
+    MockCubeRepository, MockReadRepository, MockSpoilerQueue, MockStatsRepository,
+    MockWriteRepository,
+};
@@ -3,21 +3,36 @@ mod queries;
 #[cfg(feature = "local-dev")]
 use indicatif::{ProgressBar, ProgressStyle};
 
-use crate::ids::{ChannelId, GuildId, SubscriptionId};
+use crate::ids::{ChannelId, GuildId, SubscriptionId, UserId};
 use crate::ingest::{
-    Artist, CardInfo, CardRecord, Combo, Illustration, Image, Legality, Price, RelatedToken, Rule,
-    Set, UpsertResult,
+    Artist, CardInfo, CardRecord, Combo, FunctionalSimilarity, Illustration, Image, Legality,
+    LocalisedName, Price, RelatedToken, Rule, RuleSectionInfo, Set, SetInfo, UpsertResult,
 };
 use crate::postgres::queries::{
-    ALL_PRINTS, CARD_FROM_ID, FUZZY_SEARCH_CARD_AND_ARTIST, FUZZY_SEARCH_CARD_AND_SET_NAME,
-    FUZZY_SEARCH_DISTINCT_CARDS, FUZZY_SEARCH_SET_NAME, NORMALISED_SET_NAME, PENDING_CARDS,
-    RANDOM_CARD, RANDOM_SET_CARD, SIMILAR_CARDS_FROM, SUBSCRIPTION_ID, SUBSCRIPTIONS_WITH_PENDING,
+    ADD_TO_CUBE, ALL_PRINTS, ARTIST_CARD_COUNT, ARTIST_ILLUSTRATIONS, CARD_DETAILS, CARD_FROM_ID,
+    CARDS_BY_LOCALISED_NAME, CARDS_LIKE, CREATE_CUBE, CUBE_CARDS, CUBE_ID,
+    DELETE_FUNCTIONAL_SIMILARITIES, DELETE_LOCALISED_NAMES, DELETE_RULE_SECTIONS,
+    FUNCTIONALLY_SIMILAR_CARDS, FUZZY_SEARCH_ARTIST_NAME, FUZZY_SEARCH_CARD_AND_ARTIST,
+    FUZZY_SEARCH_CARD_AND_SET_NAME, FUZZY_SEARCH_DISTINCT_CARDS, FUZZY_SEARCH_LOCALISED_NAME,
+    FUZZY_SEARCH_RULE_TITLE, FUZZY_SEARCH_SET_NAME, INSERT_FUNCTIONAL_SIMILARITIES,
+    INSERT_LOCALISED_NAMES, INSERT_RULE_SECTIONS, NORMALISED_SET_NAME, ORACLE_SEARCH,
+    PENDING_CARDS, RANDOM_CARD, RANDOM_PRICED_CARDS, RANDOM_SET_CARD, RECORD_GAME_RESULT,
+    RULE_NUMBER, RULE_SECTIONS, SET_CARDS, SET_DETAILS, SIMILAR_CARDS_FROM, SUBSCRIPTION_ID,
+    SUBSCRIPTIONS_WITH_PENDING,
+};
+use crate::repository::{
+    CubeRepository, ReadRepository, SpoilerQueue, StatsRepository, WriteRepository,
 };
-use crate::repository::{ReadRepository, SpoilerQueue, WriteRepository};
 use crate::spoiler::{PendingCard, Subscription};
+use crate::stats::{Game, GameStats};
 use async_trait::async_trait;
 use contracts::card::Card;
+use contracts::card_details::{CardDetails, Format, Legality as FormatLegality};
 use contracts::card_set::CardSet;
+use contracts::priced_card::{Currency, PricedCard};
+use contracts::rule_section::RuleSection;
+use contracts::set_card::{Rarity, SetCard};
+use contracts::set_details::SetDetails;
 use futures::StreamExt;
 use futures::future::Either;
 use sqlx::postgres::{PgConnection, PgPoolOptions, PgRow};
@@ -41,6 +56,7 @@ const MAX_QUEUE_AGE_DAYS: i64 = 7;
 /// try to claim it.
 const POOL_SIZING_LOCK_KEY: i64 = 727_663_001;
 
+#[derive(Clone)]
 pub struct Postgres {
     pool: Pool<sqlx::Postgres>,
     pool_size: usize,
@@ -186,6 +202,142 @@ impl Postgres {
         }
     }
 
+    async fn replace_rule_sections_in_transaction(
+        &self,
+        sections: &[RuleSectionInfo],
+    ) -> Result<(), sqlx::Error> {
+        let positions: Vec<i32> = (0..).take(sections.len()).collect();
+
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query(DELETE_RULE_SECTIONS)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query(INSERT_RULE_SECTIONS)
+            .bind(
+                sections
+                    .iter()
+                    .map(|section| &section.number)
+                    .collect::<Vec<_>>(),
+            )
+            .bind(
+                sections
+                    .iter()
+                    .map(|section| &section.parent)
+                    .collect::<Vec<_>>(),
+            )
+            .bind(positions)
+            .bind(
+                sections
+                    .iter()
+                    .map(|section| &section.title)
+                    .collect::<Vec<_>>(),
+            )
+            .bind(
+                sections
+                    .iter()
+                    .map(|section| &section.normalised_title)
+                    .collect::<Vec<_>>(),
+            )
+            .bind(
+                sections
+                    .iter()
+                    .map(|section| &section.text)
+                    .collect::<Vec<_>>(),
+            )
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await
+    }
+
+    async fn replace_functional_similarities_in_transaction(
+        &self,
+        similarities: &[FunctionalSimilarity],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query(DELETE_FUNCTIONAL_SIMILARITIES)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query(INSERT_FUNCTIONAL_SIMILARITIES)
+            .bind(
+                similarities
+                    .iter()
+                    .map(|similarity| similarity.oracle_id)
+                    .collect::<Vec<_>>(),
+            )
+            .bind(
+                similarities
+                    .iter()
+                    .map(|similarity| similarity.similar_oracle_id)
+                    .collect::<Vec<_>>(),
+            )
+            .bind(
+                similarities
+                    .iter()
+                    .map(|similarity| similarity.score)
+                    .collect::<Vec<_>>(),
+            )
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await
+    }
+
+    async fn replace_localised_names_in_transaction(
+        &self,
+        names: &[LocalisedName],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.pool.begin().await?;
+        sqlx::query(DELETE_LOCALISED_NAMES)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query(INSERT_LOCALISED_NAMES)
+            .bind(names.iter().map(|name| name.oracle_id).collect::<Vec<_>>())
+            .bind(names.iter().map(|name| &name.lang).collect::<Vec<_>>())
+            .bind(names.iter().map(|name| &name.name).collect::<Vec<_>>())
+            .bind(
+                names
+                    .iter()
+                    .map(|name| &name.normalised_name)
+                    .collect::<Vec<_>>(),
+            )
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await
+    }
+
+    async fn upsert_set_info(&self, info: &SetInfo) {
+        if let Err(e) = sqlx::query(
+            "INSERT INTO set
+             (id, name, normalised_name, abbreviation, set_type, release_date, card_count,
+              block, parent_abbreviation, icon_svg_uri)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             ON CONFLICT(id) DO UPDATE SET
+               name                = EXCLUDED.name,
+               normalised_name     = EXCLUDED.normalised_name,
+               abbreviation        = EXCLUDED.abbreviation,
+               set_type            = EXCLUDED.set_type,
+               release_date        = EXCLUDED.release_date,
+               card_count          = EXCLUDED.card_count,
+               block               = EXCLUDED.block,
+               parent_abbreviation = EXCLUDED.parent_abbreviation,
+               icon_svg_uri        = EXCLUDED.icon_svg_uri",
+        )
+        .bind(info.set.id)
+        .bind(&info.set.name)
+        .bind(&info.set.normalised_name)
+        .bind(&info.set.abbreviation)
+        .bind(&info.set_type)
+        .bind(info.release_date)
+        .bind(info.card_count)
+        .bind(&info.block)
+        .bind(&info.parent_abbreviation)
+        .bind(&info.icon_svg_uri)
+        .execute(&self.pool)
+        .await
+        {
+            log::warn!("Failed to upsert set info {}: {}", info.set.id, e);
+        }
+    }
+
     async fn upsert_rule(&self, rule: &Rule) {
         if let Err(e) = sqlx::query(
             "INSERT INTO rule
@@ -489,6 +641,64 @@ impl ReadRepository for Postgres {
         }
     }
 
+    async fn search_for_artist_name(&self, normalised_name: &str) -> Option<Vec<String>> {
+        match sqlx::query(FUZZY_SEARCH_ARTIST_NAME)
+            .bind(normalised_name)
+            .fetch_one(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed artist name fetch - {why}");
+                None
+            }
+            Ok(row) => row.try_get::<Vec<String>, &str>("array_agg").ok(),
+        }
+    }
+
+    async fn artist_card_count(&self, artist_name: &str) -> Option<u32> {
+        match sqlx::query(ARTIST_CARD_COUNT)
+            .bind(artist_name)
+            .fetch_one(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed artist card count fetch - {why}");
+                None
+            }
+            Ok(row) => u32::try_from(row.get::<i64, &str>("card_count")).ok(),
+        }
+    }
+
+    async fn artist_illustrations(&self, artist_name: &str) -> Option<Vec<Card>> {
+        match sqlx::query(ARTIST_ILLUSTRATIONS)
+            .bind(artist_name)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed artist illustrations fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(rows.into_iter().map(|row| card_from(&row)).collect()),
+        }
+    }
+
+    async fn search_oracle(&self, text: &str, limit: i64) -> Option<Vec<Card>> {
+        match sqlx::query(ORACLE_SEARCH)
+            .bind(text)
+            .bind(like_pattern(text))
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed oracle search - {why}");
+                None
+            }
+            Ok(rows) => Some(rows.into_iter().map(|row| card_from(&row)).collect()),
+        }
+    }
+
     async fn set_name_from_abbreviation(&self, abbreviation: &str) -> Option<String> {
         match sqlx::query(NORMALISED_SET_NAME)
             .bind(abbreviation)
@@ -503,8 +713,12 @@ impl ReadRepository for Postgres {
         }
     }
 
-    async fn random_card(&self) -> Option<Card> {
-        match sqlx::query(RANDOM_CARD).fetch_one(&self.pool).await {
+    async fn random_card(&self, exclude: &[Uuid]) -> Option<Card> {
+        match sqlx::query(RANDOM_CARD)
+            .bind(exclude)
+            .fetch_one(&self.pool)
+            .await
+        {
             Err(why) => {
                 log::warn!("Failed random card fetch - {why}");
                 None
@@ -513,9 +727,10 @@ impl ReadRepository for Postgres {
         }
     }
 
-    async fn random_card_from_set(&self, set_name: &str) -> Option<Card> {
+    async fn random_card_from_set(&self, set_name: &str, exclude: &[Uuid]) -> Option<Card> {
         match sqlx::query(RANDOM_SET_CARD)
             .bind(set_name)
+            .bind(exclude)
             .fetch_one(&self.pool)
             .await
         {
@@ -569,10 +784,246 @@ impl ReadRepository for Postgres {
             Ok(rows) => Some(rows.into_iter().map(|row| card_from(&row)).collect()),
         }
     }
+
+    async fn functionally_similar_cards(&self, card: &Card) -> Option<Vec<Card>> {
+        match sqlx::query(FUNCTIONALLY_SIMILAR_CARDS)
+            .bind(card.oracle_id())
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed functionally similar cards fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(rows.into_iter().map(|row| card_from(&row)).collect()),
+        }
+    }
+
+    async fn cards_like(&self, card: &Card, limit: i64) -> Option<Vec<Card>> {
+        match sqlx::query(CARDS_LIKE)
+            .bind(card.set_abbreviation())
+            .bind(card.colour_identity())
+            .bind(card.oracle_id())
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed cards like fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(rows.into_iter().map(|row| card_from(&row)).collect()),
+        }
+    }
+
+    async fn random_priced_cards(&self, currency: Currency, limit: i64) -> Option<Vec<PricedCard>> {
+        match sqlx::query(RANDOM_PRICED_CARDS)
+            .bind(currency.as_str())
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed random priced cards fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(
+                rows.into_iter()
+                    .map(|row| {
+                        PricedCard::new(card_from(&row), row.get::<f64, &str>("price"), currency)
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    async fn card_details(&self, ids: &[Uuid], currency: Currency) -> Option<Vec<CardDetails>> {
+        match sqlx::query(CARD_DETAILS)
+            .bind(ids)
+            .bind(currency.as_str())
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed card details fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(rows.iter().map(card_details_from).collect()),
+        }
+    }
+
+    async fn set_cards(&self, set_name: &str) -> Option<Vec<SetCard>> {
+        match sqlx::query(SET_CARDS)
+            .bind(set_name)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed set cards fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(
+                rows.into_iter()
+                    .filter_map(|row| {
+                        let rarity = row.get::<Option<String>, &str>("rarity")?;
+                        let Some(rarity) = Rarity::parse(&rarity) else {
+                            log::warn!("Unknown rarity '{rarity}'");
+                            return None;
+                        };
+                        Some(SetCard::new(card_from(&row), rarity))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    async fn set_details(&self, set_name: &str) -> Option<SetDetails> {
+        match sqlx::query(SET_DETAILS)
+            .bind(set_name)
+            .fetch_optional(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed set details fetch - {why}");
+                None
+            }
+            Ok(row) => row.map(|row| {
+                SetDetails::new(
+                    row.get("name"),
+                    row.get("abbreviation"),
+                    row.get("set_type"),
+                    row.get("release_date"),
+                    row.get::<Option<i32>, &str>("card_count")
+                        .and_then(|count| u32::try_from(count).ok()),
+                    row.get("block"),
+                    row.get("parent_abbreviation"),
+                )
+            }),
+        }
+    }
+
+    async fn rule_sections(&self, number: &str) -> Option<Vec<RuleSection>> {
+        match sqlx::query(RULE_SECTIONS)
+            .bind(number)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed rule sections fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(
+                rows.into_iter()
+                    .map(|row| {
+                        RuleSection::new(row.get("number"), row.get("title"), row.get("text"))
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    async fn search_for_rule_title(&self, normalised_title: &str) -> Option<Vec<String>> {
+        match sqlx::query(FUZZY_SEARCH_RULE_TITLE)
+            .bind(normalised_title)
+            .fetch_one(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed rule title fetch - {why}");
+                None
+            }
+            Ok(row) => row.try_get::<Vec<String>, &str>("array_agg").ok(),
+        }
+    }
+
+    async fn rule_number(&self, normalised_title: &str) -> Option<String> {
+        match sqlx::query(RULE_NUMBER)
+            .bind(normalised_title)
+            .fetch_optional(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed rule number fetch - {why}");
+                None
+            }
+            Ok(row) => row.map(|row| row.get("number")),
+        }
+    }
+
+    async fn search_for_localised_name(&self, normalised_name: &str) -> Option<Vec<String>> {
+        match sqlx::query(FUZZY_SEARCH_LOCALISED_NAME)
+            .bind(normalised_name)
+            .fetch_one(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed localised name fetch - {why}");
+                None
+            }
+            Ok(row) => row.try_get::<Vec<String>, &str>("array_agg").ok(),
+        }
+    }
+
+    async fn cards_by_localised_name(&self, normalised_name: &str) -> Option<Vec<Card>> {
+        match sqlx::query(CARDS_BY_LOCALISED_NAME)
+            .bind(normalised_name)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Err(why) => {
+                log::warn!("Failed cards by localised name fetch - {why}");
+                None
+            }
+            Ok(rows) => Some(rows.into_iter().map(|row| card_from(&row)).collect()),
+        }
+    }
+
+    async fn ping(&self) -> bool {
+        match sqlx::query("SELECT 1").execute(&self.pool).await {
+            Ok(_) => true,
+            Err(why) => {
+                log::warn!("Failed Postgres ping - {why}");
+                false
+            }
+        }
+    }
 }
 
 #[async_trait]
 impl WriteRepository for Postgres {
+    async fn upsert_sets(&self, sets: &[SetInfo]) {
+        log::info!("Upserting {} sets", sets.len());
+        let set_futs: Vec<_> = sets.iter().map(|info| self.upsert_set_info(info)).collect();
+        futures::stream::iter(set_futs)
+            .buffer_unordered(self.pool_size)
+            .collect::<Vec<_>>()
+            .await;
+    }
+
+    async fn replace_rule_sections(&self, sections: &[RuleSectionInfo]) {
+        log::info!("Replacing rules with {} sections", sections.len());
+        if let Err(why) = self.replace_rule_sections_in_transaction(sections).await {
+            log::warn!("Failed to replace rule sections: {why}");
+        }
+    }
+
+    async fn replace_functional_similarities(&self, similarities: &[FunctionalSimilarity]) {
+        log::info!("Replacing {} functional similarities", similarities.len());
+        if let Err(why) = self
+            .replace_functional_similarities_in_transaction(similarities)
+            .await
+        {
+            log::warn!("Failed to replace functional similarities: {why}");
+        }
+    }
+
+    async fn replace_localised_names(&self, names: &[LocalisedName]) {
+        log::info!("Replacing {} localised names", names.len());
+        if let Err(why) = self.replace_localised_names_in_transaction(names).await {
+            log::warn!("Failed to replace localised names: {why}");
+        }
+    }
+
     async fn upsert_cards(&self, cards: &[CardInfo]) -> UpsertResult {
         log::info!("Upserting {} cards", cards.len());
 
@@ -881,6 +1332,99 @@ impl SpoilerQueue for Postgres {
     }
 }
 
+#[async_trait]
+impl StatsRepository for Postgres {
+    async fn record_result(&self, user_id: UserId, game: Game, won: bool) -> Option<GameStats> {
+        match sqlx::query_as::<_, (i32, i32, i32, i32)>(RECORD_GAME_RESULT)
+            .bind(user_id)
+            .bind(game.as_str())
+            .bind(i32::from(won))
+            .fetch_one(&self.pool)
+            .await
+        {
+            Ok((played, won, streak, best_streak)) => Some(GameStats {
+                played,
+                won,
+                streak,
+                best_streak,
+            }),
+            Err(e) => {
+                log::warn!(
+                    "Failed to record {} result for user {user_id}: {e}",
+                    game.as_str()
+                );
+                None
+            }
+        }
+    }
+}
+
+impl Postgres {
+    async fn cube_id(&self, guild_id: GuildId, name: &str) -> Option<i64> {
+        match sqlx::query_scalar::<_, i64>(CUBE_ID)
+            .bind(guild_id)
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+        {
+            Ok(id) => id,
+            Err(e) => {
+                log::warn!("Failed to look up cube '{name}' for guild {guild_id}: {e}");
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CubeRepository for Postgres {
+    async fn create_cube(&self, guild_id: GuildId, name: &str) -> Option<bool> {
+        match sqlx::query(CREATE_CUBE)
+            .bind(guild_id)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+        {
+            Ok(result) => Some(result.rows_affected() > 0),
+            Err(e) => {
+                log::warn!("Failed to create cube '{name}' for guild {guild_id}: {e}");
+                None
+            }
+        }
+    }
+
+    async fn add_to_cube(&self, guild_id: GuildId, name: &str, card_ids: &[Uuid]) -> Option<usize> {
+        let cube_id = self.cube_id(guild_id, name).await?;
+        match sqlx::query(ADD_TO_CUBE)
+            .bind(cube_id)
+            .bind(card_ids)
+            .execute(&self.pool)
+            .await
+        {
+            Ok(result) => Some(usize::try_from(result.rows_affected()).unwrap_or(usize::MAX)),
+            Err(e) => {
+                log::warn!("Failed to add cards to cube '{name}' for guild {guild_id}: {e}");
+                None
+            }
+        }
+    }
+
+    async fn cube_cards(&self, guild_id: GuildId, name: &str) -> Option<Vec<Card>> {
+        let cube_id = self.cube_id(guild_id, name).await?;
+        match sqlx::query(CUBE_CARDS)
+            .bind(cube_id)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Ok(rows) => Some(rows.iter().map(card_from).collect()),
+            Err(e) => {
+                log::warn!("Failed to fetch cube '{name}' for guild {guild_id}: {e}");
+                None
+            }
+        }
+    }
+}
+
 fn set_from(row: &PgRow) -> CardSet {
     CardSet::new(
         row.get::<Uuid, &str>("card_id"),
@@ -928,6 +1472,21 @@ fn collect_upsert_results(
     (orphaned_images, orphaned_illustrations, new_card_ids)
 }
 
+/// A `like` pattern for rules text containing `text`, lowercased and with
+/// any quotes around a phrase dropped, so `"draw two cards"` matches
+/// "…you draw two cards…".
+fn like_pattern(text: &str) -> String {
+    let mut pattern = String::from("%");
+    for char in text.trim().trim_matches('"').to_lowercase().chars() {
+        if matches!(char, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(char);
+    }
+    pattern.push('%');
+    pattern
+}
+
 fn card_from(row: &PgRow) -> Card {
     Card::new(
         row.get::<Uuid, &str>("front_id"),
@@ -953,10 +1512,37 @@ fn card_from(row: &PgRow) -> Card {
     )
 }
 
+fn card_details_from(row: &PgRow) -> CardDetails {
+    let legalities = Format::ALL
+        .into_iter()
+        .filter_map(|format| {
+            let value = row.get::<Option<String>, &str>(format.column())?;
+            FormatLegality::parse(&value).map(|legality| (format, legality))
+        })
+        .collect();
+
+    CardDetails::new(
+        row.get::<Uuid, &str>("card_id"),
+        row.get::<Option<f64>, &str>("cmc").unwrap_or_default(),
+        row.get::<Option<Vec<String>>, &str>("colours")
+            .unwrap_or_default(),
+        row.get::<Option<f64>, &str>("price"),
+        legalities,
+        row.get::<Option<bool>, &str>("game_changer")
+            .unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn like_pattern_drops_quotes_and_escapes_wildcards() {
+        assert_eq!(like_pattern("\"Draw two cards\""), "%draw two cards%");
+        assert_eq!(like_pattern(" 100% _ "), "%100\\% \\_%");
+    }
+
     #[test]
     fn new_card_flag_stays_with_its_own_card_regardless_of_result_order() {
         let new_card = Uuid::new_v4();
@@ -106,8 +106,137 @@ select array_agg(normalised_name)
 where word_similarity(normalised_name, $1) > 0.25
 ";
 
+pub const FUZZY_SEARCH_ARTIST_NAME: &str = r"
+select array_agg(distinct normalised_name)
+    from artist
+where word_similarity(normalised_name, $1) > 0.25
+";
+
+pub const ARTIST_CARD_COUNT: &str = r"
+select count(distinct card.oracle_id) as card_count
+from card
+         join artist on card.artist_id = artist.id
+where artist.normalised_name = $1
+";
+
+/// The earliest printing of each illustration, oldest first.
+pub const ARTIST_ILLUSTRATIONS: &str = r"
+select *
+from (select distinct on (card.illustration_id) card.id                   as front_id,
+                                                card.oracle_id            as front_oracle_id,
+                                                card.name                 as front_name,
+                                                card.normalised_name      as front_normalised_name,
+                                                card.scryfall_url         as front_scryfall_url,
+                                                card.image_id             as front_image_id,
+                                                card.illustration_id      as front_illustration_id,
+                                                card.backside_id          as back_id,
+                                                rule.mana_cost            as front_mana_cost,
+                                                rule.colour_identity      as front_colour_identity,
+                                                rule.power                as front_power,
+                                                rule.toughness            as front_toughness,
+                                                rule.loyalty              as front_loyalty,
+                                                rule.defence              as front_defence,
+                                                rule.type_line            as front_type_line,
+                                                rule.oracle_text          as front_oracle_text,
+
+                                                artist.name               as artist,
+                                                set.name                  as set_name,
+                                                set.abbreviation          as set_abbreviation,
+                                                card.release_date         as release_date
+      from card
+               join rule on card.oracle_id = rule.id
+               join artist on card.artist_id = artist.id
+               join set on set.id = card.set_id
+      where artist.normalised_name = $1
+        and card.illustration_id is not null
+      order by card.illustration_id, card.release_date, card.id) illustrations
+order by release_date, front_name
+";
+
+/// Cards whose rules text matches `$1` as a web-style search, or contains
+/// the `like` pattern `$2`. Substring matches come first, then the best
+/// ranked; one printing per `oracle_id`, the newest.
+pub const ORACLE_SEARCH: &str = r"
+select *
+from (select distinct on (card.oracle_id) card.id                   as front_id,
+                                          card.oracle_id            as front_oracle_id,
+                                          card.name                 as front_name,
+                                          card.normalised_name      as front_normalised_name,
+                                          card.scryfall_url         as front_scryfall_url,
+                                          card.image_id             as front_image_id,
+                                          card.illustration_id      as front_illustration_id,
+                                          card.backside_id          as back_id,
+                                          rule.mana_cost            as front_mana_cost,
+                                          rule.colour_identity      as front_colour_identity,
+                                          rule.power                as front_power,
+                                          rule.toughness            as front_toughness,
+                                          rule.loyalty              as front_loyalty,
+                                          rule.defence              as front_defence,
+                                          rule.type_line            as front_type_line,
+                                          rule.oracle_text          as front_oracle_text,
+
+                                          artist.name               as artist,
+                                          set.name                  as set_name,
+                                          set.abbreviation          as set_abbreviation,
+                                          card.release_date         as release_date,
+                                          lower(rule.oracle_text) like $2 as contains,
+                                          ts_rank(rule.oracle_tsv, websearch_to_tsquery('english', $1)) as rank
+      from card
+               join rule on card.oracle_id = rule.id
+               left join artist on card.artist_id = artist.id
+               join set on set.id = card.set_id
+      where rule.oracle_tsv @@ websearch_to_tsquery('english', $1)
+         or lower(rule.oracle_text) like $2
+      order by card.oracle_id, card.release_date desc, card.id) matches
+order by contains desc, rank desc, front_name
+limit $3
+";
+
 pub const NORMALISED_SET_NAME: &str = r"select normalised_name from set where abbreviation = $1";
 
+pub const SET_DETAILS: &str = r"
+select name,
+       abbreviation,
+       set_type,
+       release_date,
+       card_count,
+       block,
+       parent_abbreviation
+from set
+where normalised_name = $1
+limit 1
+";
+
+pub const RULE_SECTIONS: &str = r"
+select number, title, text
+from rule_section
+where number = $1
+   or parent = $1
+order by number <> $1, position
+";
+
+pub const FUZZY_SEARCH_RULE_TITLE: &str = r"
+select array_agg(distinct normalised_title)
+    from rule_section
+where word_similarity(normalised_title, $1) > 0.25
+";
+
+pub const RULE_NUMBER: &str = r"
+select number
+from rule_section
+where normalised_title = $1
+order by position
+limit 1
+";
+
+pub const DELETE_RULE_SECTIONS: &str = r"delete from rule_section";
+
+pub const INSERT_RULE_SECTIONS: &str = r"
+insert into rule_section (number, parent, position, title, normalised_title, text)
+select *
+from unnest($1::text[], $2::text[], $3::int[], $4::text[], $5::text[], $6::text[])
+";
+
 pub const RANDOM_CARD: &str = r"
 select set.id                     as set_id,
        front.id                   as front_id,
@@ -134,7 +263,12 @@ select set.id                     as set_id,
        set.name                   as set_name,
        set.abbreviation          as set_abbreviation,
        front.release_date         as release_date
-from (select * from card where random() < 0.0001 limit 25) front
+from (select *
+      from card
+      where random() < 0.0001
+        and oracle_id is not null
+        and not (oracle_id = any ($1))
+      limit 25) front
          left join rule on front.oracle_id = rule.id
          join set on front.set_id = set.id
          left join artist on front.artist_id = artist.id
@@ -168,14 +302,16 @@ select set.id                     as set_id,
        artist.name                as artist,
        set.name                   as set_name,
        set.abbreviation           as set_abbreviation,
-       card.release_date          as release_date
+       front.release_date         as release_date
 
-from (select * from card where random() < 0.0001 limit 25) front
+from card front
          left join rule on front.oracle_id = rule.id
          join set on front.set_id = set.id
          left join artist on front.artist_id = artist.id
 where front.illustration_id is not null
   and set.normalised_name = $1
+  and front.oracle_id is not null
+  and not (front.oracle_id = any ($2))
 order by random()
 limit 1;
 ";
@@ -254,6 +390,307 @@ where card.normalised_name % $1 and card.oracle_id != $2
 order by card.oracle_id desc
 ";
 
+pub const FUNCTIONALLY_SIMILAR_CARDS: &str = r"
+select *
+from (select distinct on (card.oracle_id) card.id                   as front_id,
+                                          card.oracle_id            as front_oracle_id,
+                                          card.name                 as front_name,
+                                          card.normalised_name      as front_normalised_name,
+                                          card.scryfall_url         as front_scryfall_url,
+                                          card.image_id             as front_image_id,
+                                          card.illustration_id      as front_illustration_id,
+                                          card.backside_id          as back_id,
+                                          rule.mana_cost            as front_mana_cost,
+                                          rule.colour_identity      as front_colour_identity,
+                                          rule.power                as front_power,
+                                          rule.toughness            as front_toughness,
+                                          rule.loyalty              as front_loyalty,
+                                          rule.defence              as front_defence,
+                                          rule.type_line            as front_type_line,
+                                          rule.oracle_text          as front_oracle_text,
+
+                                          artist.name               as artist,
+                                          set.name                  as set_name,
+                                          set.abbreviation          as set_abbreviation,
+                                          card.release_date         as release_date,
+                                          functional_similarity.score as score
+      from functional_similarity
+               join card on card.oracle_id = functional_similarity.similar_oracle_id
+               join rule on card.oracle_id = rule.id
+               left join artist on card.artist_id = artist.id
+               join set on set.id = card.set_id
+      where functional_similarity.oracle_id = $1
+      order by card.oracle_id, card.release_date desc, card.id) similar
+order by score desc, front_name
+";
+
+pub const DELETE_FUNCTIONAL_SIMILARITIES: &str = r"delete from functional_similarity";
+
+pub const INSERT_FUNCTIONAL_SIMILARITIES: &str = r"
+insert into functional_similarity (oracle_id, similar_oracle_id, score)
+select *
+from unnest($1::uuid[], $2::uuid[], $3::real[])
+";
+
+pub const CARDS_BY_LOCALISED_NAME: &str = r"
+select *
+from (select distinct on (card.oracle_id) card.id                   as front_id,
+                                          card.oracle_id            as front_oracle_id,
+                                          card.name                 as front_name,
+                                          card.normalised_name      as front_normalised_name,
+                                          card.scryfall_url         as front_scryfall_url,
+                                          card.image_id             as front_image_id,
+                                          card.illustration_id      as front_illustration_id,
+                                          card.backside_id          as back_id,
+                                          rule.mana_cost            as front_mana_cost,
+                                          rule.colour_identity      as front_colour_identity,
+                                          rule.power                as front_power,
+                                          rule.toughness            as front_toughness,
+                                          rule.loyalty              as front_loyalty,
+                                          rule.defence              as front_defence,
+                                          rule.type_line            as front_type_line,
+                                          rule.oracle_text          as front_oracle_text,
+
+                                          artist.name               as artist,
+                                          set.name                  as set_name,
+                                          set.abbreviation          as set_abbreviation,
+                                          card.release_date         as release_date
+      from card_localised_name
+               join card on card.oracle_id = card_localised_name.oracle_id
+               join rule on card.oracle_id = rule.id
+               left join artist on card.artist_id = artist.id
+               join set on set.id = card.set_id
+      where card_localised_name.normalised_name = $1
+      order by card.oracle_id, card.release_date desc, card.id) localised
+order by front_name
+";
+
+pub const FUZZY_SEARCH_LOCALISED_NAME: &str = r"
+select array_agg(distinct normalised_name)
+    from card_localised_name
+where word_similarity(normalised_name, $1) > 0.25
+";
+
+pub const DELETE_LOCALISED_NAMES: &str = r"delete from card_localised_name";
+
+pub const INSERT_LOCALISED_NAMES: &str = r"
+insert into card_localised_name (oracle_id, lang, name, normalised_name)
+select *
+from unnest($1::uuid[], $2::text[], $3::text[], $4::text[])
+";
+
+pub const CARDS_LIKE: &str = r"
+select *
+from (select distinct on (card.oracle_id) card.id                   as front_id,
+                                          card.oracle_id            as front_oracle_id,
+                                          card.name                 as front_name,
+                                          card.normalised_name      as front_normalised_name,
+                                          card.scryfall_url         as front_scryfall_url,
+                                          card.image_id             as front_image_id,
+                                          card.illustration_id      as front_illustration_id,
+                                          card.backside_id          as back_id,
+                                          rule.mana_cost            as front_mana_cost,
+                                          rule.colour_identity      as front_colour_identity,
+                                          rule.power                as front_power,
+                                          rule.toughness            as front_toughness,
+                                          rule.loyalty              as front_loyalty,
+                                          rule.defence              as front_defence,
+                                          rule.type_line            as front_type_line,
+                                          rule.oracle_text          as front_oracle_text,
+
+                                          artist.name               as artist,
+                                          set.name                  as set_name,
+                                          set.abbreviation          as set_abbreviation,
+                                          card.release_date         as release_date
+      from card
+               join rule on card.oracle_id = rule.id
+               left join artist on card.artist_id = artist.id
+               join set on set.id = card.set_id
+      where set.abbreviation = $1
+        and rule.colour_identity = $2::char(1)[]
+        and card.oracle_id != $3
+      order by card.oracle_id) candidates
+order by random()
+limit $4
+";
+
+pub const RANDOM_PRICED_CARDS: &str = r"
+select *
+from (select distinct on (front.oracle_id) front.id                   as front_id,
+                                           front.oracle_id            as front_oracle_id,
+                                           front.name                 as front_name,
+                                           front.normalised_name      as front_normalised_name,
+                                           front.scryfall_url         as front_scryfall_url,
+                                           front.image_id             as front_image_id,
+                                           front.illustration_id      as front_illustration_id,
+                                           front.backside_id          as back_id,
+                                           rule.mana_cost             as front_mana_cost,
+                                           rule.colour_identity       as front_colour_identity,
+                                           rule.power                 as front_power,
+                                           rule.toughness             as front_toughness,
+                                           rule.loyalty               as front_loyalty,
+                                           rule.defence               as front_defence,
+                                           rule.type_line             as front_type_line,
+                                           rule.oracle_text           as front_oracle_text,
+
+                                           artist.name                as artist,
+                                           set.name                   as set_name,
+                                           set.abbreviation           as set_abbreviation,
+                                           front.release_date         as release_date,
+                                           (case $1
+                                                when 'usd' then price.usd
+                                                when 'eur' then price.euro
+                                                when 'tix' then price.tix
+                                               end)::float8           as price
+      from (select * from card where random() < 0.001) front
+               join price on price.id = front.id
+               left join rule on front.oracle_id = rule.id
+               join set on front.set_id = set.id
+               left join artist on front.artist_id = artist.id
+      where front.illustration_id is not null
+        and (case $1
+                 when 'usd' then price.usd
+                 when 'eur' then price.euro
+                 when 'tix' then price.tix
+            end) is not null
+      order by front.oracle_id) priced
+order by random()
+limit $2
+";
+
+// Double-faced backs are stored as their own rows one id above the front
+// they point back at, so `card.id < card.backside_id` keeps fronts only.
+pub const SET_CARDS: &str = r"
+select distinct on (card.oracle_id) card.id                   as front_id,
+                                    card.oracle_id            as front_oracle_id,
+                                    card.name                 as front_name,
+                                    card.normalised_name      as front_normalised_name,
+                                    card.scryfall_url         as front_scryfall_url,
+                                    card.image_id             as front_image_id,
+                                    card.illustration_id      as front_illustration_id,
+                                    card.backside_id          as back_id,
+                                    rule.mana_cost            as front_mana_cost,
+                                    rule.colour_identity      as front_colour_identity,
+                                    rule.power                as front_power,
+                                    rule.toughness            as front_toughness,
+                                    rule.loyalty              as front_loyalty,
+                                    rule.defence              as front_defence,
+                                    rule.type_line            as front_type_line,
+                                    rule.oracle_text          as front_oracle_text,
+
+                                    artist.name               as artist,
+                                    set.name                  as set_name,
+                                    set.abbreviation          as set_abbreviation,
+                                    card.release_date         as release_date,
+                                    card.rarity               as rarity
+from card
+         join rule on card.oracle_id = rule.id
+         left join artist on card.artist_id = artist.id
+         join set on set.id = card.set_id
+where set.normalised_name = $1
+  and (card.backside_id is null or card.id < card.backside_id)
+order by card.oracle_id, card.id
+";
+
+pub const CARD_DETAILS: &str = r"
+select card.id                        as card_id,
+       rule.cmc                       as cmc,
+       rule.colours                   as colours,
+       (select min(case $2
+                       when 'usd' then price.usd
+                       when 'eur' then price.euro
+                       when 'tix' then price.tix
+                   end)
+        from card print
+                 join price on price.id = print.id
+        where print.oracle_id = card.oracle_id)::float8 as price,
+       legality.alchemy,
+       legality.brawl,
+       legality.commander,
+       legality.duel,
+       legality.future,
+       legality.gladiator,
+       legality.historic,
+       legality.legacy,
+       legality.modern,
+       legality.oathbreaker,
+       legality.oldschool,
+       legality.pauper,
+       legality.paupercommander,
+       legality.penny,
+       legality.pioneer,
+       legality.predh,
+       legality.premodern,
+       legality.standard,
+       legality.standardbrawl,
+       legality.timeless,
+       legality.vintage,
+       legality.game_changer
+from card
+         join rule on card.oracle_id = rule.id
+         left join legality on card.oracle_id = legality.id
+where card.id = any($1)
+";
+
+pub const RECORD_GAME_RESULT: &str = r"
+insert into game_stats (user_id, game, played, won, streak, best_streak)
+values ($1, $2, 1, $3, $3, $3)
+on conflict (user_id, game) do update
+    set played      = game_stats.played + 1,
+        won         = game_stats.won + excluded.won,
+        streak      = case when excluded.won = 1 then game_stats.streak + 1 else 0 end,
+        best_streak = greatest(game_stats.best_streak,
+                               case when excluded.won = 1 then game_stats.streak + 1 else 0 end),
+        updated_at  = now()
+returning played, won, streak, best_streak
+";
+
+pub const CREATE_CUBE: &str = r"
+insert into cube (guild_id, name)
+values ($1, $2)
+on conflict (guild_id, name) do nothing
+";
+
+pub const CUBE_ID: &str = r"select id from cube where guild_id = $1 and name = $2";
+
+pub const ADD_TO_CUBE: &str = r"
+insert into cube_card (cube_id, card_id)
+select $1, card_id
+from unnest($2::uuid[]) as card_id
+on conflict (cube_id, card_id) do nothing
+";
+
+pub const CUBE_CARDS: &str = r"
+select card.id                   as front_id,
+       card.oracle_id            as front_oracle_id,
+       card.name                 as front_name,
+       card.normalised_name      as front_normalised_name,
+       card.scryfall_url         as front_scryfall_url,
+       card.image_id             as front_image_id,
+       card.illustration_id      as front_illustration_id,
+       card.backside_id          as back_id,
+       rule.mana_cost            as front_mana_cost,
+       rule.colour_identity      as front_colour_identity,
+       rule.power                as front_power,
+       rule.toughness            as front_toughness,
+       rule.loyalty              as front_loyalty,
+       rule.defence              as front_defence,
+       rule.type_line            as front_type_line,
+       rule.oracle_text          as front_oracle_text,
+
+       artist.name               as artist,
+       set.name                  as set_name,
+       set.abbreviation          as set_abbreviation,
+       card.release_date         as release_date
+from cube_card
+         join card on card.id = cube_card.card_id
+         left join rule on card.oracle_id = rule.id
+         left join artist on card.artist_id = artist.id
+         left join set on set.id = card.set_id
+where cube_card.cube_id = $1
+order by card.name
+";
+
 pub const SUBSCRIPTIONS_WITH_PENDING: &str = r"
 select guild_id, channel_id, subscription_id, subscription_token, cursor
 from spoiler_subscription s
@@ -1,9 +1,17 @@
-use crate::ids::{ChannelId, GuildId, SubscriptionId};
-use crate::ingest::{CardInfo, UpsertResult};
+use crate::ids::{ChannelId, GuildId, SubscriptionId, UserId};
+use crate::ingest::{
+    CardInfo, FunctionalSimilarity, LocalisedName, RuleSectionInfo, SetInfo, UpsertResult,
+};
 use crate::spoiler::{PendingCard, Subscription};
+use crate::stats::{Game, GameStats};
 use async_trait::async_trait;
 use contracts::card::Card;
+use contracts::card_details::CardDetails;
 use contracts::card_set::CardSet;
+use contracts::priced_card::{Currency, PricedCard};
+use contracts::rule_section::RuleSection;
+use contracts::set_card::SetCard;
+use contracts::set_details::SetDetails;
 use uuid::Uuid;
 
 #[cfg(feature = "test-util")]
@@ -17,16 +25,77 @@ pub trait ReadRepository {
     async fn search_set(&self, set_name: &str, normalised_name: &str) -> Option<Vec<Card>>;
     async fn search_for_set_name(&self, normalised_name: &str) -> Option<Vec<String>>;
     async fn set_name_from_abbreviation(&self, abbreviation: &str) -> Option<String>;
-    async fn random_card(&self) -> Option<Card>;
-    async fn random_card_from_set(&self, set_name: &str) -> Option<Card>;
+    /// Normalised names of artists loosely matching `normalised_name`, to be
+    /// narrowed down by the caller.
+    async fn search_for_artist_name(&self, normalised_name: &str) -> Option<Vec<String>>;
+    /// How many distinct cards (by `oracle_id`) the artist with normalised
+    /// name `artist_name` has illustrated.
+    async fn artist_card_count(&self, artist_name: &str) -> Option<u32>;
+    /// One printing of each of the artist's illustrations, the earliest,
+    /// oldest first.
+    async fn artist_illustrations(&self, artist_name: &str) -> Option<Vec<Card>>;
+    /// Up to `limit` cards whose rules text matches `text`, by word (as a
+    /// web-style search, so quotes make a phrase) or as a substring —
+    /// substring matches first. One printing per `oracle_id`.
+    async fn search_oracle(&self, text: &str, limit: i64) -> Option<Vec<Card>>;
+    /// Picks a random illustrated card whose `oracle_id` is not in
+    /// `exclude`, so callers can keep recently used cards out of rotation.
+    async fn random_card(&self, exclude: &[Uuid]) -> Option<Card>;
+    async fn random_card_from_set(&self, set_name: &str, exclude: &[Uuid]) -> Option<Card>;
     async fn all_prints(&self, oracle_id: &Uuid) -> Option<Vec<CardSet>>;
     async fn fetch_card_by_id(&self, id: &Uuid) -> Option<Card>;
     async fn similar_cards(&self, card: &Card) -> Option<Vec<Card>>;
+    /// Cards whose rules text reads most like `card`'s, most alike first —
+    /// `similar_cards` goes by name instead. One printing per `oracle_id`.
+    async fn functionally_similar_cards(&self, card: &Card) -> Option<Vec<Card>>;
+    /// Up to `limit` random cards from the same set as `card` sharing its
+    /// colour identity, one per `oracle_id` and never `card` itself — a
+    /// source of plausible wrong answers when `similar_cards` comes up short.
+    async fn cards_like(&self, card: &Card, limit: i64) -> Option<Vec<Card>>;
+    /// Up to `limit` random illustrated printings that have a `currency`
+    /// price, one per `oracle_id`.
+    async fn random_priced_cards(&self, currency: Currency, limit: i64) -> Option<Vec<PricedCard>>;
+    /// Every card in the set with normalised name `set_name`, one printing
+    /// per `oracle_id` and front faces only, along with its rarity there.
+    async fn set_cards(&self, set_name: &str) -> Option<Vec<SetCard>>;
+    /// The set with normalised name `set_name`.
+    async fn set_details(&self, set_name: &str) -> Option<SetDetails>;
+    /// Rules, legality and `currency` price details for each printing in
+    /// `ids`, in no particular order. Prices are the cheapest across all of
+    /// a card's printings rather than the printing asked about.
+    async fn card_details(&self, ids: &[Uuid], currency: Currency) -> Option<Vec<CardDetails>>;
+    /// The rule numbered `number`, followed by the rules directly under it
+    /// in the order they're written. Empty if there's no such rule.
+    async fn rule_sections(&self, number: &str) -> Option<Vec<RuleSection>>;
+    /// Normalised titles of rules loosely matching `normalised_title`, to be
+    /// narrowed down by the caller.
+    async fn search_for_rule_title(&self, normalised_title: &str) -> Option<Vec<String>>;
+    /// The number of the first rule titled `normalised_title`.
+    async fn rule_number(&self, normalised_title: &str) -> Option<String>;
+    /// Normalised non-English names loosely matching `normalised_name`, to
+    /// be narrowed down by the caller.
+    async fn search_for_localised_name(&self, normalised_name: &str) -> Option<Vec<String>>;
+    /// The English cards printed elsewhere as `normalised_name`, newest
+    /// printing of each.
+    async fn cards_by_localised_name(&self, normalised_name: &str) -> Option<Vec<Card>>;
+    /// Whether the store answers at all, for health checks.
+    async fn ping(&self) -> bool;
 }
 
 #[cfg_attr(feature = "test-util", automock)]
 #[async_trait]
 pub trait WriteRepository {
+    /// Stores everything about `sets`, overwriting what's there — unlike
+    /// `upsert_cards`, which only adds sets it hasn't seen.
+    async fn upsert_sets(&self, sets: &[SetInfo]);
+    /// Swaps the stored Comprehensive Rules for `sections`, in document
+    /// order, all at once — a failure leaves the old rules in place.
+    async fn replace_rule_sections(&self, sections: &[RuleSectionInfo]);
+    /// Swaps every stored functional similarity for `similarities`, all at
+    /// once.
+    async fn replace_functional_similarities(&self, similarities: &[FunctionalSimilarity]);
+    /// Swaps every stored localised name for `names`, all at once.
+    async fn replace_localised_names(&self, names: &[LocalisedName]);
     async fn upsert_cards(&self, cards: &[CardInfo]) -> UpsertResult;
     async fn delete_orphaned_images(&self, ids: &[Uuid]) -> Vec<Uuid>;
     async fn delete_orphaned_illustrations(&self, ids: &[Uuid]) -> Vec<Uuid>;
@@ -145,3 +214,26 @@ mod tests {
             .await;
     }
 }
+
+#[cfg_attr(feature = "test-util", automock)]
+#[async_trait]
+pub trait StatsRepository {
+    /// Records one finished round for `user_id` and returns their updated
+    /// totals for that `game` — a win extends the streak, a loss resets it.
+    async fn record_result(&self, user_id: UserId, game: Game, won: bool) -> Option<GameStats>;
+}
+
+/// Guild-owned cube lists. Cube names are matched exactly, so callers
+/// should normalise them (the bot lowercases) before calling in.
+#[cfg_attr(feature = "test-util", automock)]
+#[async_trait]
+pub trait CubeRepository {
+    /// Creates an empty cube. `Some(false)` means the guild already has one
+    /// by that name; `None` means it couldn't be stored.
+    async fn create_cube(&self, guild_id: GuildId, name: &str) -> Option<bool>;
+    /// Adds `card_ids` to a cube, skipping any already in it, and returns
+    /// how many were new — or `None` if the guild has no such cube.
+    async fn add_to_cube(&self, guild_id: GuildId, name: &str, card_ids: &[Uuid]) -> Option<usize>;
+    /// Every card in a cube, or `None` if the guild has no such cube.
+    async fn cube_cards(&self, guild_id: GuildId, name: &str) -> Option<Vec<Card>>;
+}
@@ -0,0 +1,40 @@
+/// Which game a result was recorded against. Every game shares the one
+/// `game_stats` table, keyed by player and game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    Guess,
+    Trivia,
+    HigherLower,
+}
+
+impl Game {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Game::Guess => "guess",
+            Game::Trivia => "trivia",
+            Game::HigherLower => "higher_lower",
+        }
+    }
+}
+
+/// A player's running totals for one game, as of the result just recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GameStats {
+    pub played: i32,
+    pub won: i32,
+    pub streak: i32,
+    pub best_streak: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_names_are_stable() {
+        assert_eq!(Game::Guess.as_str(), "guess");
+        assert_eq!(Game::Trivia.as_str(), "trivia");
+        assert_eq!(Game::HigherLower.as_str(), "higher_lower");
+    }
+}
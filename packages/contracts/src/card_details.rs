@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A format with a column in the `legality` table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Format {
+    Alchemy,
+    Brawl,
+    Commander,
+    Duel,
+    Future,
+    Gladiator,
+    Historic,
+    Legacy,
+    Modern,
+    Oathbreaker,
+    OldSchool,
+    Pauper,
+    PauperCommander,
+    Penny,
+    Pioneer,
+    PreDh,
+    Premodern,
+    Standard,
+    StandardBrawl,
+    Timeless,
+    Vintage,
+}
+
+impl Format {
+    pub const ALL: [Format; 21] = [
+        Format::Alchemy,
+        Format::Brawl,
+        Format::Commander,
+        Format::Duel,
+        Format::Future,
+        Format::Gladiator,
+        Format::Historic,
+        Format::Legacy,
+        Format::Modern,
+        Format::Oathbreaker,
+        Format::OldSchool,
+        Format::Pauper,
+        Format::PauperCommander,
+        Format::Penny,
+        Format::Pioneer,
+        Format::PreDh,
+        Format::Premodern,
+        Format::Standard,
+        Format::StandardBrawl,
+        Format::Timeless,
+        Format::Vintage,
+    ];
+
+    /// The `legality` column holding this format.
+    #[must_use]
+    pub fn column(&self) -> &'static str {
+        match self {
+            Format::Alchemy => "alchemy",
+            Format::Brawl => "brawl",
+            Format::Commander => "commander",
+            Format::Duel => "duel",
+            Format::Future => "future",
+            Format::Gladiator => "gladiator",
+            Format::Historic => "historic",
+            Format::Legacy => "legacy",
+            Format::Modern => "modern",
+            Format::Oathbreaker => "oathbreaker",
+            Format::OldSchool => "oldschool",
+            Format::Pauper => "pauper",
+            Format::PauperCommander => "paupercommander",
+            Format::Penny => "penny",
+            Format::Pioneer => "pioneer",
+            Format::PreDh => "predh",
+            Format::Premodern => "premodern",
+            Format::Standard => "standard",
+            Format::StandardBrawl => "standardbrawl",
+            Format::Timeless => "timeless",
+            Format::Vintage => "vintage",
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Alchemy => "Alchemy",
+            Format::Brawl => "Brawl",
+            Format::Commander => "Commander",
+            Format::Duel => "Duel Commander",
+            Format::Future => "Future Standard",
+            Format::Gladiator => "Gladiator",
+            Format::Historic => "Historic",
+            Format::Legacy => "Legacy",
+            Format::Modern => "Modern",
+            Format::Oathbreaker => "Oathbreaker",
+            Format::OldSchool => "Old School",
+            Format::Pauper => "Pauper",
+            Format::PauperCommander => "Pauper Commander",
+            Format::Penny => "Penny Dreadful",
+            Format::Pioneer => "Pioneer",
+            Format::PreDh => "PreDH",
+            Format::Premodern => "Premodern",
+            Format::Standard => "Standard",
+            Format::StandardBrawl => "Standard Brawl",
+            Format::Timeless => "Timeless",
+            Format::Vintage => "Vintage",
+        }
+    }
+}
+
+/// A card's standing in one format, as Scryfall names it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum Legality {
+    Legal,
+    #[default]
+    NotLegal,
+    Restricted,
+    Banned,
+}
+
+impl Legality {
+    /// Parses a stored `legality` value, e.g. `"not_legal"`.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "legal" => Some(Legality::Legal),
+            "not_legal" => Some(Legality::NotLegal),
+            "restricted" => Some(Legality::Restricted),
+            "banned" => Some(Legality::Banned),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Legality::Legal => "legal",
+            Legality::NotLegal => "not legal",
+            Legality::Restricted => "restricted",
+            Legality::Banned => "banned",
+        }
+    }
+}
+
+/// The rules and market facts about a card that a search result doesn't
+/// carry, looked up by printing id.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CardDetails {
+    id: Uuid,
+    cmc: f64,
+    colours: Vec<String>,
+    price: Option<f64>,
+    legalities: Vec<(Format, Legality)>,
+    game_changer: bool,
+}
+
+impl CardDetails {
+    #[must_use]
+    pub fn new(
+        id: Uuid,
+        cmc: f64,
+        colours: Vec<String>,
+        price: Option<f64>,
+        legalities: Vec<(Format, Legality)>,
+        game_changer: bool,
+    ) -> Self {
+        Self {
+            id,
+            cmc,
+            colours,
+            price,
+            legalities,
+            game_changer,
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    #[must_use]
+    pub fn cmc(&self) -> f64 {
+        self.cmc
+    }
+
+    /// The card's colours, as opposed to its colour identity.
+    #[must_use]
+    pub fn colours(&self) -> &[String] {
+        &self.colours
+    }
+
+    /// The cheapest price across all of the card's printings.
+    #[must_use]
+    pub fn price(&self) -> Option<f64> {
+        self.price
+    }
+
+    /// Formats missing from the table count as not legal.
+    #[must_use]
+    pub fn legality(&self, format: Format) -> Legality {
+        self.legalities
+            .iter()
+            .find_map(|(f, legality)| (*f == format).then_some(*legality))
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn game_changer(&self) -> bool {
+        self.game_changer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_legality() {
+        assert_eq!(Legality::parse("legal"), Some(Legality::Legal));
+        assert_eq!(Legality::parse("not_legal"), Some(Legality::NotLegal));
+        assert_eq!(Legality::parse("banned"), Some(Legality::Banned));
+        assert_eq!(Legality::parse("nope"), None);
+    }
+
+    #[test]
+    fn test_missing_format_is_not_legal() {
+        let details = CardDetails::new(
+            Uuid::nil(),
+            1.0,
+            vec![String::from("R")],
+            None,
+            vec![(Format::Modern, Legality::Legal)],
+            false,
+        );
+
+        assert_eq!(details.legality(Format::Modern), Legality::Legal);
+        assert_eq!(details.legality(Format::Standard), Legality::NotLegal);
+    }
+
+    #[test]
+    fn test_every_format_has_a_column() {
+        let mut columns: Vec<&str> = Format::ALL.iter().map(Format::column).collect();
+        columns.sort_unstable();
+        columns.dedup();
+        assert_eq!(columns.len(), Format::ALL.len());
+    }
+}
@@ -1,4 +1,9 @@
 pub mod card;
+pub mod card_details;
 pub mod card_set;
 pub mod image;
+pub mod priced_card;
+pub mod rule_section;
 pub mod search_result;
+pub mod set_card;
+pub mod set_details;
@@ -0,0 +1,129 @@
+use crate::card::Card;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Which of a printing's market prices to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum Currency {
+    #[default]
+    Usd,
+    Eur,
+    Tix,
+}
+
+impl Currency {
+    /// The stable name the price queries use to pick a column.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Currency::Usd => "usd",
+            Currency::Eur => "eur",
+            Currency::Tix => "tix",
+        }
+    }
+
+    /// Parses the code `Display` writes, e.g. `"EUR"`.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "USD" => Some(Currency::Usd),
+            "EUR" => Some(Currency::Eur),
+            "TIX" => Some(Currency::Tix),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn format(&self, amount: f64) -> String {
+        match self {
+            Currency::Usd => format!("${amount:.2}"),
+            Currency::Eur => format!("€{amount:.2}"),
+            Currency::Tix => format!("{amount:.2} tix"),
+        }
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let string = match self {
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+            Currency::Tix => "TIX",
+        };
+
+        write!(f, "{string}")
+    }
+}
+
+/// A specific printing alongside one of its market prices.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PricedCard {
+    card: Card,
+    price: f64,
+    currency: Currency,
+}
+
+impl PricedCard {
+    #[must_use]
+    pub fn new(card: Card, price: f64, currency: Currency) -> Self {
+        Self {
+            card,
+            price,
+            currency,
+        }
+    }
+
+    #[must_use]
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    #[must_use]
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    #[must_use]
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    #[must_use]
+    pub fn formatted_price(&self) -> String {
+        self.currency.format(self.price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_names() {
+        assert_eq!(Currency::Usd.as_str(), "usd");
+        assert_eq!(Currency::Eur.as_str(), "eur");
+        assert_eq!(Currency::Tix.as_str(), "tix");
+        assert_eq!(Currency::default(), Currency::Usd);
+    }
+
+    #[test]
+    fn test_currency_format() {
+        assert_eq!(Currency::Usd.format(1.5), "$1.50");
+        assert_eq!(Currency::Eur.format(0.2), "€0.20");
+        assert_eq!(Currency::Tix.format(12.0), "12.00 tix");
+    }
+
+    #[test]
+    fn test_currency_display() {
+        assert_eq!(Currency::Usd.to_string(), "USD");
+        assert_eq!(Currency::Tix.to_string(), "TIX");
+    }
+
+    #[test]
+    fn test_currency_from_code_round_trips() {
+        for currency in [Currency::Usd, Currency::Eur, Currency::Tix] {
+            assert_eq!(Currency::from_code(&currency.to_string()), Some(currency));
+        }
+        assert_eq!(Currency::from_code("usd"), None);
+    }
+}
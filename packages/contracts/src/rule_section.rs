@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// One numbered entry in the Comprehensive Rules, from a whole section
+/// (`7`) down to a subrule (`702.19b`).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RuleSection {
+    number: String,
+    title: Option<String>,
+    text: String,
+}
+
+impl RuleSection {
+    #[must_use]
+    pub fn new(number: String, title: Option<String>, text: String) -> Self {
+        Self {
+            number,
+            title,
+            text,
+        }
+    }
+
+    #[must_use]
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// The name of a heading or of a rule that's just a name, like
+    /// `Trample`; the rest are all text.
+    #[must_use]
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
@@ -7,6 +7,7 @@ pub struct SearchResultDto {
     image: Image,
     printings: Option<Vec<CardSet>>,
     similar_cards: Option<Vec<Card>>,
+    functionally_similar: Option<Vec<Card>>,
 }
 
 impl SearchResultDto {
@@ -17,6 +18,7 @@ impl SearchResultDto {
             image,
             printings: None,
             similar_cards: None,
+            functionally_similar: None,
         }
     }
 
@@ -33,6 +35,13 @@ impl SearchResultDto {
         self
     }
 
+    /// Cards that play like this one, going by their rules text.
+    #[must_use]
+    pub fn add_functionally_similar(mut self, cards: Option<Vec<Card>>) -> Self {
+        self.functionally_similar = cards;
+        self
+    }
+
     #[must_use]
     pub fn image(&self) -> &Image {
         &self.image
@@ -52,6 +61,11 @@ impl SearchResultDto {
     pub fn similar_cards(&self) -> Option<&Vec<Card>> {
         self.similar_cards.as_ref()
     }
+
+    #[must_use]
+    pub fn functionally_similar(&self) -> Option<&Vec<Card>> {
+        self.functionally_similar.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,108 @@
+use crate::card::Card;
+use serde::{Deserialize, Serialize};
+
+/// A printing's rarity, as Scryfall names it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Mythic,
+    Special,
+    Bonus,
+}
+
+impl Rarity {
+    /// Parses the stored `card.rarity` value, e.g. `"mythic"`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "common" => Some(Rarity::Common),
+            "uncommon" => Some(Rarity::Uncommon),
+            "rare" => Some(Rarity::Rare),
+            "mythic" => Some(Rarity::Mythic),
+            "special" => Some(Rarity::Special),
+            "bonus" => Some(Rarity::Bonus),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Mythic => "Mythic",
+            Rarity::Special => "Special",
+            Rarity::Bonus => "Bonus",
+        }
+    }
+
+    /// The one-letter code printed next to a set symbol.
+    #[must_use]
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Rarity::Common => "C",
+            Rarity::Uncommon => "U",
+            Rarity::Rare => "R",
+            Rarity::Mythic => "M",
+            Rarity::Special => "S",
+            Rarity::Bonus => "B",
+        }
+    }
+}
+
+/// A card as printed in a particular set, with its rarity there.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetCard {
+    card: Card,
+    rarity: Rarity,
+}
+
+impl SetCard {
+    #[must_use]
+    pub fn new(card: Card, rarity: Rarity) -> Self {
+        Self { card, rarity }
+    }
+
+    #[must_use]
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    #[must_use]
+    pub fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+
+    #[must_use]
+    pub fn is_basic_land(&self) -> bool {
+        self.card.type_line().starts_with("Basic Land")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rarity() {
+        assert_eq!(Rarity::parse("common"), Some(Rarity::Common));
+        assert_eq!(Rarity::parse("mythic"), Some(Rarity::Mythic));
+        assert_eq!(Rarity::parse("bonus"), Some(Rarity::Bonus));
+        assert_eq!(Rarity::parse("legendary"), None);
+    }
+
+    #[test]
+    fn test_rarity_symbol() {
+        assert_eq!(Rarity::Uncommon.symbol(), "U");
+        assert_eq!(Rarity::Mythic.symbol(), "M");
+    }
+
+    #[test]
+    fn test_rarities_order_by_scarcity() {
+        assert!(Rarity::Common < Rarity::Uncommon);
+        assert!(Rarity::Rare < Rarity::Mythic);
+    }
+}
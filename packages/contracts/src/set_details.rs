@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use time::Date;
+
+/// A set as a whole rather than any one card in it. Everything but the name
+/// and abbreviation is only known once the set has been through a sync that
+/// reads the set list, so may be missing for older rows.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SetDetails {
+    name: String,
+    abbreviation: String,
+    set_type: Option<String>,
+    release_date: Option<Date>,
+    card_count: Option<u32>,
+    block: Option<String>,
+    parent_abbreviation: Option<String>,
+}
+
+impl SetDetails {
+    #[must_use]
+    pub fn new(
+        name: String,
+        abbreviation: String,
+        set_type: Option<String>,
+        release_date: Option<Date>,
+        card_count: Option<u32>,
+        block: Option<String>,
+        parent_abbreviation: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            abbreviation,
+            set_type,
+            release_date,
+            card_count,
+            block,
+            parent_abbreviation,
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+
+    /// Scryfall's set type, e.g. `"expansion"` or `"commander"`.
+    #[must_use]
+    pub fn set_type(&self) -> Option<&str> {
+        self.set_type.as_deref()
+    }
+
+    #[must_use]
+    pub fn release_date(&self) -> Option<&Date> {
+        self.release_date.as_ref()
+    }
+
+    /// Cards in the set as Scryfall counts them, every face and variant
+    /// included.
+    #[must_use]
+    pub fn card_count(&self) -> Option<u32> {
+        self.card_count
+    }
+
+    #[must_use]
+    pub fn block(&self) -> Option<&str> {
+        self.block.as_deref()
+    }
+
+    /// The abbreviation of the set this one belongs to, e.g. a commander
+    /// deck's main set.
+    #[must_use]
+    pub fn parent_abbreviation(&self) -> Option<&str> {
+        self.parent_abbreviation.as_deref()
+    }
+}
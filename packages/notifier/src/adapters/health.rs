@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use cards_sdk::ReadRepository;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use telemetry::{Check, Health};
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+/// When the poll loop last finished a pass, shared with the health checks.
+/// Starts at startup, so the first poll gets the same allowance as the rest.
+#[derive(Clone)]
+pub struct LastPoll(Arc<AtomicU64>);
+
+impl LastPoll {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicU64::new(now())))
+    }
+
+    pub fn record(&self) {
+        self.0.store(now(), Ordering::Relaxed);
+    }
+
+    fn within(&self, allowance: Duration) -> bool {
+        now().saturating_sub(self.0.load(Ordering::Relaxed)) <= allowance.as_secs()
+    }
+}
+
+/// Live while the poll loop keeps coming round — one that's stuck gets the
+/// pod restarted — and ready while Postgres answers too.
+pub struct NotifierHealth<S> {
+    storage: S,
+    last_poll: LastPoll,
+    allowance: Duration,
+}
+
+impl<S> NotifierHealth<S> {
+    pub fn new(storage: S, last_poll: LastPoll, allowance: Duration) -> Self {
+        Self {
+            storage,
+            last_poll,
+            allowance,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: ReadRepository + Send + Sync> Health for NotifierHealth<S> {
+    async fn liveness(&self) -> Vec<Check> {
+        vec![Check::new(
+            "poll loop",
+            self.last_poll.within(self.allowance),
+        )]
+    }
+
+    async fn readiness(&self) -> Vec<Check> {
+        vec![
+            Check::new("poll loop", self.last_poll.within(self.allowance)),
+            Check::new("postgres", self.storage.ping().await),
+        ]
+    }
+}
@@ -1,3 +1,4 @@
+pub mod health;
 pub mod images;
 pub mod services;
 pub mod webhook;
@@ -0,0 +1,49 @@
+use std::sync::LazyLock;
+use telemetry::prometheus::{
+    Histogram, IntCounter, IntCounterVec, register_histogram, register_int_counter,
+    register_int_counter_vec,
+};
+
+pub static DELIVERIES: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "notifier_deliveries_total",
+        "Spoiled cards posted to a subscribed channel"
+    )
+    .expect("Invalid metric")
+});
+
+pub static DELIVERY_FAILURES: LazyLock<IntCounter> = LazyLock::new(|| {
+    register_int_counter!(
+        "notifier_delivery_failures_total",
+        "Spoiled cards that couldn't be posted, to be retried next poll"
+    )
+    .expect("Invalid metric")
+});
+
+/// Subscriptions dropped without anyone asking, by why: `gone` for a
+/// webhook that's been deleted, `failures` for one that kept failing.
+pub static AUTO_UNSUBSCRIBES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "notifier_auto_unsubscribes_total",
+        "Subscriptions removed because their webhook stopped working",
+        &["reason"]
+    )
+    .expect("Invalid metric")
+});
+
+pub static POLL_DURATION: LazyLock<Histogram> = LazyLock::new(|| {
+    register_histogram!(
+        "notifier_poll_duration_seconds",
+        "How long a poll for pending spoilers took, deliveries included"
+    )
+    .expect("Invalid metric")
+});
+
+/// Registers every metric up front, so they're scraped as zero before
+/// anything has happened rather than missing.
+pub fn init() {
+    LazyLock::force(&DELIVERIES);
+    LazyLock::force(&DELIVERY_FAILURES);
+    LazyLock::force(&AUTO_UNSUBSCRIBES);
+    LazyLock::force(&POLL_DURATION);
+}
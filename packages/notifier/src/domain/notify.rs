@@ -1,3 +1,4 @@
+use crate::domain::metrics;
 use cards_sdk::spoiler::{PendingCard, Subscription};
 use futures::stream::{self, StreamExt};
 use std::collections::HashMap;
@@ -35,6 +36,7 @@ pub async fn run(
     images: &impl crate::ports::images::ImageStore,
     sender: &impl crate::ports::spoilers::SpoilerSender,
 ) {
+    let _timer = metrics::POLL_DURATION.start_timer();
     let subs = repo.subscriptions_with_pending().await;
 
     if let Some(min_cursor) = subs.iter().map(|sub| sub.cursor).min() {
@@ -148,9 +150,11 @@ async fn deliver_to_subscription(
             .await
         {
             Ok(()) => {
+                metrics::DELIVERIES.inc();
                 last_queue_id = Some(pending.queue_id);
             }
             Err(e) => {
+                metrics::DELIVERY_FAILURES.inc();
                 log::warn!(
                     "Failed to deliver card to guild {} channel {}: {e}",
                     sub.guild_id,
@@ -163,6 +167,9 @@ async fn deliver_to_subscription(
                         sub.channel_id
                     );
                     repo.delete_subscription(sub.guild_id, sub.channel_id).await;
+                    metrics::AUTO_UNSUBSCRIBES
+                        .with_label_values(&["gone"])
+                        .inc();
                 } else if e.counts_toward_failure_threshold() {
                     let failures = repo.record_failure(sub.guild_id, sub.channel_id).await;
                     if failures >= MAX_CONSECUTIVE_FAILURES {
@@ -172,6 +179,9 @@ async fn deliver_to_subscription(
                             sub.channel_id
                         );
                         repo.delete_subscription(sub.guild_id, sub.channel_id).await;
+                        metrics::AUTO_UNSUBSCRIBES
+                            .with_label_values(&["failures"])
+                            .inc();
                     }
                 }
                 // else: a connection error, 5xx, or rate limiting — could
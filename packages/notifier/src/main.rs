@@ -2,10 +2,11 @@ mod adapters;
 mod domain;
 mod ports;
 
+use crate::adapters::health::{LastPoll, NotifierHealth};
 use crate::adapters::services::spoiler_sender_init;
 use adapters::services::card_storage_init;
 use adapters::services::init_image_store;
-use domain::notify;
+use domain::{metrics, notify};
 use std::time::Duration;
 
 #[cfg(feature = "local-dev")]
@@ -18,20 +19,43 @@ use dotenv::dotenv;
 /// idle cost or poll latency actually matters.
 const POLL_INTERVAL: Duration = Duration::from_mins(1);
 
+/// How long since the last finished poll before the loop counts as stuck.
+/// A pass can take a while when there's a big spoiler drop to deliver.
+const POLL_ALLOWANCE: Duration = Duration::from_mins(5);
+
 #[tokio::main]
 async fn main() {
     #[cfg(feature = "local-dev")]
     dotenv().ok();
 
     env_logger::init();
+    metrics::init();
 
     let storage = card_storage_init().await;
     let images = init_image_store();
     let sender = spoiler_sender_init();
 
+    let last_poll = LastPoll::new();
+    telemetry::serve_with(NotifierHealth::new(
+        storage.clone(),
+        last_poll.clone(),
+        POLL_ALLOWANCE,
+    ));
+
+    let shutdown = telemetry::shutdown_signal();
+    tokio::pin!(shutdown);
+
     log::info!("Polling for spoilers...");
     loop {
+        // Never interrupted: a delivery stopped between `send` and `ack`
+        // would be sent again after the restart.
         notify::run(&storage, &images, &sender).await;
-        tokio::time::sleep(POLL_INTERVAL).await;
+        last_poll.record();
+
+        tokio::select! {
+            () = &mut shutdown => break,
+            () = tokio::time::sleep(POLL_INTERVAL) => {}
+        }
     }
+    log::info!("Stopped polling");
 }
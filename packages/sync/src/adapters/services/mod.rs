@@ -1,13 +1,16 @@
 use crate::adapters::services::discord::Discord;
 use crate::adapters::services::file_system::FileSystem;
+use crate::adapters::services::rules::ComprehensiveRules;
 use crate::adapters::services::scryfall::Scryfall;
 use crate::ports::emoji::EmojiStore;
 use crate::ports::image_store::ImageStore;
+use crate::ports::rules::RulesSource;
 use crate::ports::source::CardSource;
 use cards_sdk::WriteRepository;
 
 pub mod discord;
 pub mod file_system;
+pub mod rules;
 mod scryfall;
 
 /// Connections reserved for other long-lived consumers (e.g. `bot`) when
@@ -34,3 +37,8 @@ pub fn image_store_init() -> impl ImageStore {
 pub fn emoji_store_init() -> impl EmojiStore {
     Discord::new()
 }
+
+#[must_use]
+pub fn rules_source_init() -> impl RulesSource {
+    ComprehensiveRules::new()
+}
@@ -0,0 +1,62 @@
+use crate::ports::rules::RulesSource;
+use async_trait::async_trait;
+use reqwest::Client;
+use std::env;
+
+/// The Comprehensive Rules text file, read from disk or downloaded — Wizards
+/// publishes each revision at a new URL, so where to find it is config.
+pub struct ComprehensiveRules {
+    location: String,
+    client: Client,
+}
+
+impl Default for ComprehensiveRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComprehensiveRules {
+    /// # Panics
+    /// Panics if the `COMPREHENSIVE_RULES` environment variable (a path or
+    /// an `http(s)` URL) is not set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            location: env::var("COMPREHENSIVE_RULES")
+                .expect("COMPREHENSIVE_RULES wasn't in env vars"),
+            client: Client::new(),
+        }
+    }
+
+    async fn download(&self) -> Result<Vec<u8>, reqwest::Error> {
+        let response = self
+            .client
+            .get(&self.location)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+#[async_trait]
+impl RulesSource for ComprehensiveRules {
+    async fn fetch_rules(&self) -> Option<String> {
+        let bytes = if self.location.starts_with("http://") || self.location.starts_with("https://")
+        {
+            self.download()
+                .await
+                .inspect_err(|why| log::error!("Failed to download the rules: {why}"))
+                .ok()?
+        } else {
+            tokio::fs::read(&self.location)
+                .await
+                .inspect_err(|why| log::error!("Failed to read the rules: {why}"))
+                .ok()?
+        };
+
+        // Older revisions weren't always clean UTF-8.
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
@@ -0,0 +1,158 @@
+use crate::adapters::services::scryfall::utils::uuid::increment_uuid;
+use cards_sdk::LocalisedName;
+use serde::de::{SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+use std::fmt;
+use uuid::Uuid;
+
+const ENGLISH: &str = "en";
+
+#[derive(Deserialize)]
+pub struct PrintedFace {
+    pub name: String,
+    pub oracle_id: Option<Uuid>,
+    pub printed_name: Option<String>,
+}
+
+/// The little of an `all_cards` printing needed for its printed name.
+#[derive(Deserialize)]
+pub struct ScryfallPrinting {
+    pub oracle_id: Option<Uuid>,
+    pub lang: String,
+    pub printed_name: Option<String>,
+    pub card_faces: Option<Vec<PrintedFace>>,
+}
+
+impl ScryfallPrinting {
+    /// Each face's printed name, against the oracle id `into_storage_records`
+    /// gives that face: a back face with a name of its own gets the front's
+    /// oracle id incremented.
+    pub fn into_localised_names(self) -> Vec<LocalisedName> {
+        if self.lang == ENGLISH {
+            return Vec::new();
+        }
+
+        let Some(faces) = self.card_faces else {
+            return self
+                .oracle_id
+                .zip(self.printed_name)
+                .map(|(oracle_id, name)| localised_name(oracle_id, &self.lang, name))
+                .into_iter()
+                .collect();
+        };
+
+        let mut faces = faces.into_iter();
+        let (Some(front), Some(back)) = (faces.next(), faces.next()) else {
+            return Vec::new();
+        };
+        let Some(front_oracle_id) = front.oracle_id.or(self.oracle_id) else {
+            return Vec::new();
+        };
+        let back_oracle_id = if front.name == back.name {
+            front_oracle_id
+        } else {
+            increment_uuid(front_oracle_id)
+        };
+
+        [
+            (front_oracle_id, front.printed_name),
+            (back_oracle_id, back.printed_name),
+        ]
+        .into_iter()
+        .filter_map(|(oracle_id, name)| Some(localised_name(oracle_id, &self.lang, name?)))
+        .collect()
+    }
+}
+
+fn localised_name(oracle_id: Uuid, lang: &str, name: String) -> LocalisedName {
+    LocalisedName {
+        oracle_id,
+        lang: lang.to_string(),
+        normalised_name: normalise::normalise_card_name(&name),
+        name,
+    }
+}
+
+/// Every distinct localised name in an `all_cards` file, read a printing at
+/// a time — the whole file is too big to hold in memory at once.
+pub struct LocalisedNames(pub Vec<LocalisedName>);
+
+impl<'de> Deserialize<'de> for LocalisedNames {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PrintingsVisitor;
+
+        impl<'de> Visitor<'de> for PrintingsVisitor {
+            type Value = LocalisedNames;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a list of cards")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut seen: HashSet<(Uuid, String, String)> = HashSet::new();
+                let mut names = Vec::new();
+                while let Some(printing) = seq.next_element::<ScryfallPrinting>()? {
+                    for name in printing.into_localised_names() {
+                        let key = (
+                            name.oracle_id,
+                            name.lang.clone(),
+                            name.normalised_name.clone(),
+                        );
+                        if !name.normalised_name.is_empty() && seen.insert(key) {
+                            names.push(name);
+                        }
+                    }
+                }
+                Ok(LocalisedNames(names))
+            }
+        }
+
+        deserializer.deserialize_seq(PrintingsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRINTINGS: &str = r#"[
+        {"oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070", "lang": "de", "printed_name": "Blitzschlag", "name": "Lightning Bolt"},
+        {"oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070", "lang": "de", "printed_name": "Blitzschlag", "name": "Lightning Bolt"},
+        {"oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070", "lang": "ja", "printed_name": "稲妻", "name": "Lightning Bolt"},
+        {"oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070", "lang": "en", "name": "Lightning Bolt"},
+        {"lang": "de", "card_faces": [
+            {"name": "Delver of Secrets", "oracle_id": "11bf83bb-c95b-4b4f-9a56-ce7a1816307a", "printed_name": "Geheimnisforscher"},
+            {"name": "Insectile Aberration", "printed_name": "Insektenhafte Abnormität"}
+        ]}
+    ]"#;
+
+    fn names() -> Vec<LocalisedName> {
+        serde_json::from_str::<LocalisedNames>(PRINTINGS).unwrap().0
+    }
+
+    #[test]
+    fn test_skips_english_and_repeats() {
+        let names: Vec<(String, String)> = names()
+            .into_iter()
+            .map(|name| (name.lang, name.normalised_name))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (String::from("de"), String::from("blitzschlag")),
+                (String::from("ja"), String::from("稲妻")),
+                (String::from("de"), String::from("geheimnisforscher")),
+                (String::from("de"), String::from("insektenhafte abnormität")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_back_face_oracle_id() {
+        let names = names();
+        let front = uuid::uuid!("11bf83bb-c95b-4b4f-9a56-ce7a1816307a");
+        assert_eq!(names[2].oracle_id, front);
+        assert_eq!(names[3].oracle_id, increment_uuid(front));
+    }
+}
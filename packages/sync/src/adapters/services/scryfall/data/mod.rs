@@ -1,4 +1,5 @@
 pub mod card;
+pub mod localised;
 pub mod set;
 pub mod symbols;
 
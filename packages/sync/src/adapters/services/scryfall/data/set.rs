@@ -1,4 +1,4 @@
-use cards_sdk::Set;
+use cards_sdk::{Set, SetInfo};
 use serde::{Deserialize, Serialize};
 use time::Date;
 use time::serde::format_description;
@@ -16,6 +16,11 @@ pub struct ScryfallSet {
     pub card_count: u32,
     #[serde(with = "date_format")]
     pub released_at: Date,
+    pub set_type: String,
+    #[serde(default)]
+    pub block: Option<String>,
+    #[serde(default)]
+    pub parent_set_code: Option<String>,
 }
 
 impl From<ScryfallSet> for Set {
@@ -43,3 +48,17 @@ impl From<&ScryfallSet> for Set {
         }
     }
 }
+
+impl From<&ScryfallSet> for SetInfo {
+    fn from(val: &ScryfallSet) -> Self {
+        SetInfo {
+            set: val.into(),
+            set_type: val.set_type.clone(),
+            release_date: val.released_at,
+            card_count: i32::try_from(val.card_count).unwrap_or(i32::MAX),
+            block: val.block.clone(),
+            parent_abbreviation: val.parent_set_code.clone(),
+            icon_svg_uri: val.icon_svg_uri.clone(),
+        }
+    }
+}
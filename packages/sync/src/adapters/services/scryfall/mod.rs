@@ -3,13 +3,14 @@ pub mod utils;
 
 use crate::adapters::services::scryfall::data::ScryfallData;
 use crate::adapters::services::scryfall::data::card::ScryfallCard;
+use crate::adapters::services::scryfall::data::localised::LocalisedNames;
 use crate::adapters::services::scryfall::data::symbols::ScryfallSymbol;
 #[cfg(feature = "local-dev")]
 use crate::domain::utils::bulk_cache;
 use crate::ports::emoji::{EmojiImage, EmojiMetaData, SetEmoji, SymbolEmoji};
 use crate::ports::source::CardSource;
 use async_trait::async_trait;
-use cards_sdk::{CardInfo, Set};
+use cards_sdk::{CardInfo, LocalisedName, Set, SetInfo};
 use data::set::ScryfallSet;
 use futures::future;
 use governor::clock::DefaultClock;
@@ -19,7 +20,10 @@ use normalise::normalise_emoji_name;
 use reqwest::{Client, Response};
 use std::collections::{HashMap, HashSet};
 use std::env;
+use std::fs::File;
+use std::io::{BufReader, Write};
 use std::num::NonZeroU32;
+use std::path::Path;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::RwLock;
@@ -116,19 +120,10 @@ impl Scryfall {
             }
         }
 
-        let url = format!("{}/bulk-data", self.base_url);
-        let manifest = self.get::<BulkDataEntry>(&url).await?;
-
-        let entry = manifest
-            .data
-            .into_iter()
-            .find(|e| e.data_type == "default_cards")
-            .ok_or(ScryfallError::ParseError)?;
+        let download_uri = self.bulk_data_uri("default_cards").await?;
 
         log::info!("Downloading bulk card data");
-        let resp = self
-            .get_resp(&entry.download_uri, &self.low_limiter)
-            .await?;
+        let resp = self.get_resp(&download_uri, &self.low_limiter).await?;
 
         let bytes = resp.bytes().await.map_err(|e| {
             log::warn!("Failed to download bulk data: {e}");
@@ -144,6 +139,38 @@ impl Scryfall {
         })
     }
 
+    async fn bulk_data_uri(&self, data_type: &str) -> ScryfallResult<String> {
+        let url = format!("{}/bulk-data", self.base_url);
+        let manifest = self.get::<BulkDataEntry>(&url).await?;
+
+        manifest
+            .data
+            .into_iter()
+            .find(|e| e.data_type == data_type)
+            .map(|e| e.download_uri)
+            .ok_or(ScryfallError::ParseError)
+    }
+
+    /// Every printing in every language is several gigabytes, so it's
+    /// written to `path` as it arrives rather than held in memory.
+    async fn download_all_cards(&self, path: &Path) -> ScryfallResult<()> {
+        let download_uri = self.bulk_data_uri("all_cards").await?;
+
+        log::info!("Downloading all cards bulk data");
+        let mut resp = self.get_resp(&download_uri, &self.low_limiter).await?;
+        let mut file = File::create(path).map_err(|e| {
+            log::warn!("Failed to create {}: {e}", path.display());
+            ScryfallError::ParseError
+        })?;
+        while let Some(chunk) = resp.chunk().await? {
+            file.write_all(&chunk).map_err(|e| {
+                log::warn!("Failed to write all cards bulk data: {e}");
+                ScryfallError::ParseError
+            })?;
+        }
+        Ok(())
+    }
+
     async fn get_resp(&self, url: &str, limiter: &Limiter) -> ScryfallResult<Response> {
         limiter.until_ready().await;
         let resp = self.client.get(url).send().await.map_err(|why| {
@@ -208,6 +235,10 @@ impl CardSource for Scryfall {
         self.sets.read().await.values().map(Into::into).collect()
     }
 
+    async fn set_info(&self) -> Vec<SetInfo> {
+        self.sets.read().await.values().map(Into::into).collect()
+    }
+
     async fn fetch_all_cards(&self) -> Vec<CardInfo> {
         match self.fetch_bulk_cards().await {
             Ok(cards) => {
@@ -248,6 +279,35 @@ impl CardSource for Scryfall {
         }
     }
 
+    async fn fetch_localised_names(&self) -> Vec<LocalisedName> {
+        let path = env::temp_dir().join("all-cards.json");
+        if let Err(e) = self.download_all_cards(&path).await {
+            log::error!("Failed to download all cards: {e}");
+            std::fs::remove_file(&path).ok();
+            return vec![];
+        }
+
+        let parse_path = path.clone();
+        let parsed = tokio::task::spawn_blocking(move || {
+            serde_json::from_reader::<_, LocalisedNames>(BufReader::new(File::open(parse_path)?))
+                .map_err(std::io::Error::from)
+        })
+        .await;
+        std::fs::remove_file(&path).ok();
+
+        match parsed {
+            Ok(Ok(names)) => names.0,
+            Ok(Err(e)) => {
+                log::error!("Failed to parse all cards: {e}");
+                vec![]
+            }
+            Err(e) => {
+                log::error!("Failed to parse all cards: {e}");
+                vec![]
+            }
+        }
+    }
+
     async fn fetch_cards_for_sets(&self, sets: &[Set]) -> Vec<CardInfo> {
         let mut scryfall_cards: Vec<ScryfallCard> = Vec::new();
         log::info!("Fetching {} sets", sets.len());
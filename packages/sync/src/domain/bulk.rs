@@ -1,5 +1,7 @@
+use crate::domain::metrics;
 use crate::domain::utils::emoji;
 use crate::domain::utils::images::save_images;
+use crate::domain::utils::similarity::functional_similarities;
 use crate::ports::emoji::EmojiStore;
 use crate::ports::image_store::ImageStore;
 use crate::ports::source::CardSource;
@@ -12,6 +14,9 @@ pub async fn sync(
     emoji_store: impl EmojiStore,
 ) {
     source.get_all_sets().await;
+    let set_info = source.set_info().await;
+    storage.upsert_sets(&set_info).await;
+    metrics::upserted("sets", set_info.len());
     emoji::sync(&source, &emoji_store).await;
 
     let cards = source.fetch_all_cards().await;
@@ -26,6 +31,13 @@ pub async fn sync(
     save_images(&cards, &image_store, &source).await;
 
     let upsert_result = storage.upsert_cards(&cards).await;
+    metrics::upserted("cards", cards.len());
+
+    // Only a full sync sees every card, so only it can rank them against
+    // each other.
+    let similarities = functional_similarities(&cards);
+    storage.replace_functional_similarities(&similarities).await;
+    metrics::upserted("functional_similarities", similarities.len());
 
     let deleted_images = storage
         .delete_orphaned_images(&upsert_result.orphaned_images)
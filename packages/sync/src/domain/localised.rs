@@ -0,0 +1,16 @@
+use crate::domain::metrics;
+use crate::ports::source::CardSource;
+use cards_sdk::WriteRepository;
+
+pub async fn sync(source: impl CardSource, storage: impl WriteRepository) {
+    // Nothing fetched means the download failed, and storing that would
+    // wipe out the names already there.
+    let names = source.fetch_localised_names().await;
+    if names.is_empty() {
+        log::warn!("No localised names fetched");
+        return;
+    }
+
+    storage.replace_localised_names(&names).await;
+    metrics::upserted("localised_names", names.len());
+}
@@ -0,0 +1,34 @@
+use std::sync::LazyLock;
+use telemetry::prometheus::{
+    HistogramVec, IntCounterVec, register_histogram_vec, register_int_counter_vec,
+};
+
+/// Rows written, by what they are: `sets`, `cards`, `functional_similarities`,
+/// `rule_sections` or `localised_names`.
+pub static UPSERTED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    register_int_counter_vec!(
+        "sync_upserted_total",
+        "Rows written to the database, by what they are",
+        &["kind"]
+    )
+    .expect("Invalid metric")
+});
+
+/// How long each sync took, by subcommand.
+pub static DURATION: LazyLock<HistogramVec> = LazyLock::new(|| {
+    register_histogram_vec!(
+        "sync_duration_seconds",
+        "How long a sync took, by subcommand",
+        &["job"],
+        vec![
+            1.0, 5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1200.0, 1800.0, 3600.0
+        ]
+    )
+    .expect("Invalid metric")
+});
+
+pub fn upserted(kind: &str, rows: usize) {
+    UPSERTED
+        .with_label_values(&[kind])
+        .inc_by(u64::try_from(rows).unwrap_or(u64::MAX));
+}
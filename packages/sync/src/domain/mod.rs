@@ -1,3 +1,6 @@
 pub mod bulk;
+pub mod localised;
+pub mod metrics;
+pub mod rules;
 pub mod spoilers;
 pub mod utils;
@@ -0,0 +1,216 @@
+use crate::domain::metrics;
+use crate::ports::rules::RulesSource;
+use cards_sdk::{RuleSectionInfo, WriteRepository};
+use normalise::normalise_card_name;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Named rules are short and, unlike the rest, aren't sentences.
+const MAX_TITLE_LENGTH: usize = 60;
+
+pub async fn sync(source: impl RulesSource, storage: impl WriteRepository) {
+    let Some(document) = source.fetch_rules().await else {
+        log::warn!("No rules fetched");
+        return;
+    };
+
+    // An empty parse means the document wasn't what we expected, and
+    // storing it would wipe out the rules already there.
+    let sections = parse(&document);
+    if sections.is_empty() {
+        log::warn!("No rules found in the rules document");
+        return;
+    }
+
+    storage.replace_rule_sections(&sections).await;
+    metrics::upserted("rule_sections", sections.len());
+}
+
+/// Matches `7. Section`, `702. Chapter`, `702.19. Rule` and `702.19b Subrule`
+/// lines, capturing the number and the text.
+static RULE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\d{3}\.\d+[a-z]?|\d{3}|\d)\.?\s+(.+)$").expect("Invalid regex")
+});
+
+/// `702.19b` sits under `702.19`, which sits under `702`, under `7`.
+fn parent(number: &str) -> Option<String> {
+    let rule = number.trim_end_matches(|c: char| c.is_ascii_lowercase());
+    if rule != number {
+        return Some(rule.to_string());
+    }
+    match number.split_once('.') {
+        Some((chapter, _)) => Some(chapter.to_string()),
+        None if number.len() == 3 => Some(number[..1].to_string()),
+        None => None,
+    }
+}
+
+/// Sections and chapters are headings; a rule is named if it's just a
+/// name, like `Trample`, rather than a sentence.
+fn title(number: &str, text: &str) -> Option<String> {
+    let heading = !number.contains('.');
+    let named = !number.ends_with(|c: char| c.is_ascii_lowercase())
+        && text.len() <= MAX_TITLE_LENGTH
+        && !text.ends_with(['.', ':', ')', '"']);
+    (heading || named).then(|| text.to_string())
+}
+
+/// Every numbered rule in the document, in order. The contents at the
+/// start repeat the headings, which are kept in the order they're first
+/// listed; examples are added to the rule they follow; the glossary and
+/// credits at the end are left out.
+pub fn parse(document: &str) -> Vec<RuleSectionInfo> {
+    let mut sections: Vec<RuleSectionInfo> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut in_rules = false;
+
+    for line in document.lines() {
+        let line = line.trim_start_matches('\u{feff}').trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with("Example:") {
+            if in_rules && let Some(section) = sections.last_mut() {
+                section.text.push('\n');
+                section.text.push_str(line);
+            }
+            continue;
+        }
+
+        if in_rules && line == "Glossary" {
+            break;
+        }
+
+        let Some(captures) = RULE_RE.captures(line) else {
+            continue;
+        };
+        let number = captures[1].to_string();
+        let text = captures[2].to_string();
+        in_rules |= number.contains('.');
+
+        let title = title(&number, &text);
+        let section = RuleSectionInfo {
+            parent: parent(&number),
+            normalised_title: title.as_deref().map(normalise_card_name),
+            title,
+            number,
+            text,
+        };
+        if let Some(&index) = seen.get(&section.number) {
+            sections[index] = section;
+        } else {
+            seen.insert(section.number.clone(), sections.len());
+            sections.push(section);
+        }
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "\u{feff}Magic: The Gathering Comprehensive Rules\r
+\r
+These rules are effective as of November 14, 2025.\r
+\r
+Contents\r
+\r
+1. Game Concepts\r
+100. General\r
+7. Additional Rules\r
+702. Keyword Abilities\r
+Glossary\r
+\r
+1. Game Concepts\r
+\r
+100. General\r
+\r
+100.1. These Magic rules apply to any Magic game with two or more players.\r
+\r
+100.1a A two-player game is a game that begins with only two players.\r
+\r
+7. Additional Rules\r
+\r
+702. Keyword Abilities\r
+\r
+702.19. Trample\r
+\r
+702.19a Trample is a static ability that modifies the rules for assigning combat damage.\r
+\r
+702.19b The controller of an attacking creature with trample first assigns damage to the blockers.\r
+Example: A 6/6 green creature with trample is blocked by a 2/2 creature.\r
+\r
+Glossary\r
+\r
+Trample\r
+A keyword ability that lets a creature deal excess combat damage. See rule 702.19.\r
+";
+
+    fn numbers(sections: &[RuleSectionInfo]) -> Vec<&str> {
+        sections
+            .iter()
+            .map(|section| section.number.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_rules_in_order() {
+        let sections = parse(DOCUMENT);
+        assert_eq!(
+            numbers(&sections),
+            vec![
+                "1", "100", "7", "702", "100.1", "100.1a", "702.19", "702.19a", "702.19b"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parents() {
+        assert_eq!(parent("702.19b").as_deref(), Some("702.19"));
+        assert_eq!(parent("702.19").as_deref(), Some("702"));
+        assert_eq!(parent("702").as_deref(), Some("7"));
+        assert_eq!(parent("7"), None);
+    }
+
+    #[test]
+    fn test_titles() {
+        let sections = parse(DOCUMENT);
+        let titles: Vec<Option<&str>> = sections
+            .iter()
+            .map(|section| section.title.as_deref())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                Some("Game Concepts"),
+                Some("General"),
+                Some("Additional Rules"),
+                Some("Keyword Abilities"),
+                None,
+                None,
+                Some("Trample"),
+                None,
+                None
+            ]
+        );
+        assert_eq!(sections[6].normalised_title.as_deref(), Some("trample"));
+    }
+
+    #[test]
+    fn test_examples_join_their_rule() {
+        let sections = parse(DOCUMENT);
+        assert_eq!(
+            sections.last().unwrap().text,
+            "The controller of an attacking creature with trample first assigns damage to the blockers.\nExample: A 6/6 green creature with trample is blocked by a 2/2 creature."
+        );
+    }
+
+    #[test]
+    fn test_not_the_rules() {
+        assert!(parse("<html><body>Not found</body></html>").is_empty());
+    }
+}
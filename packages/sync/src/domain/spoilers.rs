@@ -1,3 +1,4 @@
+use crate::domain::metrics;
 use crate::domain::utils::emoji;
 use crate::domain::utils::images::save_images;
 use crate::ports::emoji::EmojiStore;
@@ -12,6 +13,9 @@ pub async fn sync(
     emoji_store: impl EmojiStore,
 ) {
     let sets = source.get_recent_sets().await;
+    let set_info = source.set_info().await;
+    storage.upsert_sets(&set_info).await;
+    metrics::upserted("sets", set_info.len());
     emoji::sync(&source, &emoji_store).await;
 
     let cards = source.fetch_cards_for_sets(&sets).await;
@@ -26,6 +30,7 @@ pub async fn sync(
     save_images(&cards, &image_store, &source).await;
 
     let upsert_result = storage.upsert_cards(&cards).await;
+    metrics::upserted("cards", cards.len());
 
     let deleted_images = storage
         .delete_orphaned_images(&upsert_result.orphaned_images)
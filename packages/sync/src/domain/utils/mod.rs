@@ -1,4 +1,5 @@
 pub mod bulk_cache;
 pub mod emoji;
 pub mod images;
+pub mod similarity;
 pub mod svg;
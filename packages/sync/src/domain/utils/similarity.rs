@@ -0,0 +1,350 @@
+use cards_sdk::{CardInfo, FunctionalSimilarity};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+use uuid::Uuid;
+
+/// How many functionally similar cards are kept for each card.
+const NEIGHBOURS: usize = 10;
+/// Below this, two cards share little more than boilerplate.
+const MIN_SCORE: f32 = 0.2;
+/// Terms in more than this share of all rules text say nothing about how a
+/// card plays.
+const MAX_DOCUMENT_FREQUENCY: f32 = 0.5;
+/// In order of what most defines how a card plays: an artifact creature is
+/// compared with creatures, not artifacts.
+const CARD_TYPES: [&str; 8] = [
+    "creature",
+    "planeswalker",
+    "battle",
+    "land",
+    "instant",
+    "sorcery",
+    "artifact",
+    "enchantment",
+];
+
+/// The parts of a card that similarity is worked out from.
+struct Rules<'a> {
+    oracle_id: Uuid,
+    name: &'a str,
+    colour_identity: &'a [String],
+    type_line: &'a str,
+    oracle_text: &'a str,
+}
+
+/// Removes reminder text, which explains keywords rather than adding to them.
+static REMINDER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\([^)]*\)").expect("Invalid regex"));
+
+/// Words, numbers like `+1/+1`, and mana symbols like `{T}`.
+static WORD_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{[^}]+\}|[a-z0-9+\-/']+").expect("Invalid regex"));
+
+/// The type a card is compared within, if it has one worth comparing.
+/// Tokens and emblems don't show up in search, so they're left out.
+fn card_type(type_line: &str) -> Option<&'static str> {
+    let types = type_line
+        .split('—')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let words: Vec<&str> = types.split_whitespace().collect();
+    if words
+        .iter()
+        .any(|&word| word == "token" || word == "emblem")
+    {
+        return None;
+    }
+    CARD_TYPES
+        .into_iter()
+        .find(|card_type| words.contains(card_type))
+}
+
+/// The rules text as words and pairs of words, without the card's own name —
+/// `Lightning Bolt deals 3 damage` should read like `Shock deals 2 damage`.
+fn terms(name: &str, oracle_text: &str) -> Vec<String> {
+    let mut text = REMINDER_RE
+        .replace_all(&oracle_text.to_lowercase(), " ")
+        .into_owned();
+    let name = name.to_lowercase();
+    for face in name.split(" // ") {
+        text = text.replace(face, " ");
+        // Legends are often called by the part of their name before the comma.
+        if let Some((short, _)) = face.split_once(',') {
+            text = text.replace(short, " ");
+        }
+    }
+
+    let words: Vec<&str> = WORD_RE.find_iter(&text).map(|m| m.as_str()).collect();
+    let mut terms: Vec<String> = words.iter().map(ToString::to_string).collect();
+    terms.extend(
+        words
+            .windows(2)
+            .map(|pair| format!("{} {}", pair[0], pair[1])),
+    );
+    terms
+}
+
+/// Each card's rules text weighted by TF-IDF, as `(term, weight)` pairs
+/// normalised to unit length, so a dot product is the cosine similarity.
+#[allow(clippy::cast_precision_loss)]
+fn weigh(documents: &[Vec<String>]) -> Vec<Vec<(usize, f32)>> {
+    let mut vocabulary: HashMap<&str, usize> = HashMap::new();
+    let mut counts: Vec<HashMap<usize, f32>> = Vec::with_capacity(documents.len());
+    for terms in documents {
+        let mut count: HashMap<usize, f32> = HashMap::new();
+        for term in terms {
+            let next = vocabulary.len();
+            let id = *vocabulary.entry(term.as_str()).or_insert(next);
+            *count.entry(id).or_default() += 1.0;
+        }
+        counts.push(count);
+    }
+
+    let mut document_frequency = vec![0usize; vocabulary.len()];
+    for count in &counts {
+        for &id in count.keys() {
+            document_frequency[id] += 1;
+        }
+    }
+
+    let total = documents.len() as f32;
+    counts
+        .into_iter()
+        .map(|count| {
+            let mut vector: Vec<(usize, f32)> = count
+                .into_iter()
+                .filter(|&(id, _)| document_frequency[id] as f32 <= total * MAX_DOCUMENT_FREQUENCY)
+                .map(|(id, tf)| {
+                    let idf = ((1.0 + total) / (1.0 + document_frequency[id] as f32)).ln() + 1.0;
+                    (id, (1.0 + tf.ln()) * idf)
+                })
+                .collect();
+            let length = vector.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+            if length > 0.0 {
+                for (_, weight) in &mut vector {
+                    *weight /= length;
+                }
+            }
+            vector
+        })
+        .collect()
+}
+
+/// The closest cards to each of `members`, which are indices into `vectors`.
+fn neighbours(
+    members: &[usize],
+    vectors: &[Vec<(usize, f32)>],
+    rules: &[Rules],
+    similarities: &mut Vec<FunctionalSimilarity>,
+) {
+    let mut postings: HashMap<usize, Vec<(usize, f32)>> = HashMap::new();
+    for (position, &member) in members.iter().enumerate() {
+        for &(term, weight) in &vectors[member] {
+            postings.entry(term).or_default().push((position, weight));
+        }
+    }
+
+    let mut scores = vec![0f32; members.len()];
+    for (position, &member) in members.iter().enumerate() {
+        scores.fill(0.0);
+        for &(term, weight) in &vectors[member] {
+            for &(other, other_weight) in &postings[&term] {
+                scores[other] += weight * other_weight;
+            }
+        }
+
+        let mut closest: Vec<(usize, f32)> = scores
+            .iter()
+            .enumerate()
+            .filter(|&(other, &score)| other != position && score >= MIN_SCORE)
+            .map(|(other, &score)| (other, score.min(1.0)))
+            .collect();
+        closest.sort_by(|a, b| b.1.total_cmp(&a.1));
+        closest.truncate(NEIGHBOURS);
+
+        similarities.extend(
+            closest
+                .into_iter()
+                .map(|(other, score)| FunctionalSimilarity {
+                    oracle_id: rules[member].oracle_id,
+                    similar_oracle_id: rules[members[other]].oracle_id,
+                    score,
+                }),
+        );
+    }
+}
+
+fn similarities(rules: &[Rules]) -> Vec<FunctionalSimilarity> {
+    let documents: Vec<Vec<String>> = rules
+        .iter()
+        .map(|rules| terms(rules.name, rules.oracle_text))
+        .collect();
+    let vectors = weigh(&documents);
+
+    let mut groups: HashMap<(Vec<&str>, &str), Vec<usize>> = HashMap::new();
+    for (index, rules) in rules.iter().enumerate() {
+        let Some(card_type) = card_type(rules.type_line) else {
+            continue;
+        };
+        let mut colour_identity: Vec<&str> =
+            rules.colour_identity.iter().map(String::as_str).collect();
+        colour_identity.sort_unstable();
+        groups
+            .entry((colour_identity, card_type))
+            .or_default()
+            .push(index);
+    }
+
+    let mut similarities = Vec::new();
+    for members in groups.values() {
+        neighbours(members, &vectors, rules, &mut similarities);
+    }
+    similarities
+}
+
+/// The cards each card plays most like, going by its rules text, among
+/// cards of the same colour identity and type.
+#[must_use]
+pub fn functional_similarities(cards: &[CardInfo]) -> Vec<FunctionalSimilarity> {
+    let mut seen: HashSet<Uuid> = HashSet::new();
+    let rules: Vec<Rules> = cards
+        .iter()
+        .filter(|card| seen.insert(card.rule.id))
+        .filter_map(|card| {
+            Some(Rules {
+                oracle_id: card.rule.id,
+                name: &card.card.name,
+                colour_identity: &card.rule.colour_identity,
+                type_line: card.rule.type_line.as_deref()?,
+                oracle_text: card
+                    .rule
+                    .oracle_text
+                    .as_deref()
+                    .filter(|text| !text.is_empty())?,
+            })
+        })
+        .collect();
+    similarities(&rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CARDS: [(&str, &str, &str, &str); 7] = [
+        ("Divination", "U", "Sorcery", "Draw two cards."),
+        ("Counsel of the Soratami", "U", "Sorcery", "Draw two cards."),
+        (
+            "Lightning Bolt",
+            "R",
+            "Instant",
+            "Lightning Bolt deals 3 damage to any target.",
+        ),
+        (
+            "Shock",
+            "R",
+            "Instant",
+            "Shock deals 2 damage to any target.",
+        ),
+        (
+            "Giant Growth",
+            "G",
+            "Instant",
+            "Target creature gets +3/+3 until end of turn.",
+        ),
+        (
+            "Lava Spike",
+            "R",
+            "Sorcery",
+            "Lava Spike deals 3 damage to target player or planeswalker.",
+        ),
+        (
+            "Mind Rot",
+            "B",
+            "Sorcery",
+            "Target player discards two cards.",
+        ),
+    ];
+
+    fn score(similarities: &[FunctionalSimilarity], from: usize, to: usize) -> Option<f32> {
+        similarities
+            .iter()
+            .find(|similarity| {
+                similarity.oracle_id.as_u128() == from as u128
+                    && similarity.similar_oracle_id.as_u128() == to as u128
+            })
+            .map(|similarity| similarity.score)
+    }
+
+    fn run() -> Vec<FunctionalSimilarity> {
+        let colours: Vec<Vec<String>> = CARDS
+            .iter()
+            .map(|(_, colour, _, _)| vec![colour.to_string()])
+            .collect();
+        let rules: Vec<Rules> = CARDS
+            .iter()
+            .zip(&colours)
+            .enumerate()
+            .map(
+                |(index, ((name, _, type_line, oracle_text), colour_identity))| Rules {
+                    oracle_id: Uuid::from_u128(index as u128),
+                    name,
+                    colour_identity,
+                    type_line,
+                    oracle_text,
+                },
+            )
+            .collect();
+        similarities(&rules)
+    }
+
+    #[test]
+    fn test_same_text_is_most_similar() {
+        let similarities = run();
+        assert!(score(&similarities, 0, 1).is_some_and(|score| score > 0.99));
+        assert!(score(&similarities, 1, 0).is_some_and(|score| score > 0.99));
+    }
+
+    #[test]
+    fn test_own_name_is_ignored() {
+        let similarities = run();
+        assert!(score(&similarities, 2, 3).is_some_and(|score| score > 0.5));
+    }
+
+    #[test]
+    fn test_only_same_colour_and_type() {
+        let similarities = run();
+        // Same colour, different type.
+        assert_eq!(score(&similarities, 2, 5), None);
+        // Same type, different colour.
+        assert_eq!(score(&similarities, 2, 4), None);
+        assert_eq!(score(&similarities, 0, 6), None);
+    }
+
+    #[test]
+    fn test_card_types() {
+        assert_eq!(card_type("Artifact Creature — Golem"), Some("creature"));
+        assert_eq!(
+            card_type("Legendary Planeswalker — Jace"),
+            Some("planeswalker")
+        );
+        assert_eq!(card_type("Token Creature — Goblin"), None);
+        assert_eq!(card_type("Scheme"), None);
+    }
+
+    #[test]
+    fn test_terms() {
+        assert_eq!(
+            terms(
+                "Jace, the Mind Sculptor",
+                "Jace deals {T}: (Reminder.) draw"
+            ),
+            vec!["deals", "{t}", "draw", "deals {t}", "{t} draw"]
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        );
+    }
+}
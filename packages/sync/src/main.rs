@@ -1,9 +1,12 @@
 use crate::adapters::services::{
-    card_source_init, card_storage_init, emoji_store_init, image_store_init,
+    card_source_init, card_storage_init, emoji_store_init, image_store_init, rules_source_init,
 };
 use clap::{Parser, Subcommand};
 
-use crate::domain::{bulk, spoilers};
+use crate::domain::{bulk, localised, metrics, rules, spoilers};
+use crate::ports::emoji::EmojiStore;
+use crate::ports::image_store::ImageStore;
+use crate::ports::source::CardSource;
 #[cfg(feature = "local-dev")]
 use dotenv::dotenv;
 
@@ -21,6 +24,21 @@ struct Cli {
 enum Command {
     Spoilers,
     Bulk,
+    /// Load the Comprehensive Rules from `COMPREHENSIVE_RULES`.
+    Rules,
+    /// Load cards' non-English printed names from Scryfall.
+    Localised,
+}
+
+impl Command {
+    fn name(&self) -> &'static str {
+        match self {
+            Command::Spoilers => "spoilers",
+            Command::Bulk => "bulk",
+            Command::Rules => "rules",
+            Command::Localised => "localised",
+        }
+    }
 }
 
 #[tokio::main]
@@ -29,16 +47,35 @@ async fn main() {
     dotenv().ok();
 
     env_logger::init();
+    telemetry::serve();
 
     let cli = Cli::parse();
+    let job = cli.command.name();
+    let timer = metrics::DURATION.with_label_values(&[job]).start_timer();
 
-    let source = card_source_init();
     let storage = card_storage_init().await;
-    let image_store = image_store_init();
-    let emoji_store = emoji_store_init();
 
+    // The rules don't come from the card source, so don't need its config,
+    // and neither they nor localised names need the image and emoji stores'.
     match cli.command {
-        Command::Spoilers => spoilers::sync(source, storage, image_store, emoji_store).await,
-        Command::Bulk => bulk::sync(source, storage, image_store, emoji_store).await,
+        Command::Spoilers => {
+            let (source, image_store, emoji_store) = card_services_init();
+            spoilers::sync(source, storage, image_store, emoji_store).await;
+        }
+        Command::Bulk => {
+            let (source, image_store, emoji_store) = card_services_init();
+            bulk::sync(source, storage, image_store, emoji_store).await;
+        }
+        Command::Rules => rules::sync(rules_source_init(), storage).await,
+        Command::Localised => localised::sync(card_source_init(), storage).await,
     }
+
+    log::info!("Finished {job} sync in {:.1}s", timer.stop_and_record());
+    // The job exits now, likely before any scrape; the Pushgateway keeps
+    // the run's metrics until the next one.
+    telemetry::push(job).await;
+}
+
+fn card_services_init() -> (impl CardSource, impl ImageStore, impl EmojiStore) {
+    (card_source_init(), image_store_init(), emoji_store_init())
 }
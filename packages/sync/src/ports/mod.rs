@@ -1,3 +1,4 @@
 pub mod emoji;
 pub mod image_store;
+pub mod rules;
 pub mod source;
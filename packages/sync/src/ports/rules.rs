@@ -0,0 +1,7 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RulesSource {
+    /// The Comprehensive Rules as Wizards publishes them, in plain text.
+    async fn fetch_rules(&self) -> Option<String>;
+}
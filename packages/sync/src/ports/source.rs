@@ -1,14 +1,18 @@
 use crate::ports::emoji::{EmojiMetaData, SetEmoji, SymbolEmoji};
 use async_trait::async_trait;
-use cards_sdk::{CardInfo, Set};
+use cards_sdk::{CardInfo, LocalisedName, Set, SetInfo};
 
 #[async_trait]
 pub trait CardSource {
     async fn get_recent_sets(&self) -> Vec<Set>;
     async fn get_all_sets(&self) -> Vec<Set>;
+    /// Everything known about the sets fetched by either of the above.
+    async fn set_info(&self) -> Vec<SetInfo>;
 
     async fn fetch_cards_for_sets(&self, sets: &[Set]) -> Vec<CardInfo>;
     async fn fetch_all_cards(&self) -> Vec<CardInfo>;
+    /// The names cards are printed with in every language but English.
+    async fn fetch_localised_names(&self) -> Vec<LocalisedName>;
 
     async fn download_image(&self, url: &str) -> Option<Vec<u8>>;
 
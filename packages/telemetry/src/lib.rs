@@ -0,0 +1,302 @@
+//! Serves each binary's Prometheus metrics, and the health checks its
+//! probes ask for, over HTTP. Metrics are defined where they're recorded,
+//! against the `prometheus` default registry re-exported here. Binaries
+//! that exit before a scrape can reach them push to a Pushgateway instead.
+//! Also tells the long-running binaries when they've been asked to stop.
+
+use async_trait::async_trait;
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{CONTENT_TYPE, HOST};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode, Uri};
+use hyper_util::rt::TokioIo;
+use prometheus::{Encoder, TextEncoder};
+use std::convert::Infallible;
+use std::env;
+use std::error::Error;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::signal::unix::{SignalKind, signal};
+
+pub use prometheus;
+
+const DEFAULT_PORT: u16 = 9090;
+
+/// One thing a binary depends on, and whether it's working.
+pub struct Check {
+    name: &'static str,
+    healthy: bool,
+}
+
+impl Check {
+    #[must_use]
+    pub fn new(name: &'static str, healthy: bool) -> Self {
+        Self { name, healthy }
+    }
+}
+
+/// What a binary's probes check.
+#[async_trait]
+pub trait Health: Send + Sync {
+    /// Whether it's still doing its job at all. Any check failing gets the
+    /// pod restarted, so only check what a restart would fix.
+    async fn liveness(&self) -> Vec<Check>;
+    /// Whether it can do its job right now. Any check failing takes the pod
+    /// out of service until they all pass again.
+    async fn readiness(&self) -> Vec<Check>;
+}
+
+/// Everything registered so far, in Prometheus' text format.
+#[must_use]
+pub fn render() -> String {
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    if let Err(why) = encoder.encode(&prometheus::gather(), &mut buffer) {
+        log::warn!("Couldn't encode metrics: {why}");
+    }
+    String::from_utf8(buffer).unwrap_or_default()
+}
+
+fn response(status: StatusCode, content_type: &str, body: String) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    if let Ok(content_type) = content_type.parse() {
+        response.headers_mut().insert(CONTENT_TYPE, content_type);
+    }
+    response
+}
+
+/// `200` if every check passes and `503` if any fails, with a line for each.
+fn report(checks: &[Check]) -> Response<Full<Bytes>> {
+    let status = if checks.iter().all(|check| check.healthy) {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let mut body = String::new();
+    for check in checks {
+        let state = if check.healthy { "ok" } else { "failing" };
+        let _ = writeln!(body, "{}: {state}", check.name);
+    }
+    response(status, "text/plain; charset=utf-8", body)
+}
+
+async fn respond(path: &str, health: Option<&dyn Health>) -> Response<Full<Bytes>> {
+    match (path, health) {
+        ("/metrics", _) => response(StatusCode::OK, prometheus::TEXT_FORMAT, render()),
+        ("/livez", Some(health)) => report(&health.liveness().await),
+        ("/readyz", Some(health)) => report(&health.readiness().await),
+        _ => response(
+            StatusCode::NOT_FOUND,
+            "text/plain; charset=utf-8",
+            String::from("Not found"),
+        ),
+    }
+}
+
+async fn listen(address: SocketAddr, health: Option<Arc<dyn Health>>) {
+    let listener = match TcpListener::bind(address).await {
+        Ok(listener) => listener,
+        Err(why) => {
+            log::warn!("Couldn't serve metrics on {address}: {why}");
+            return;
+        }
+    };
+    log::info!("Serving metrics on {address}");
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(why) => {
+                log::warn!("Couldn't accept metrics connection: {why}");
+                continue;
+            }
+        };
+        let health = health.clone();
+        let service = service_fn(move |request: Request<Incoming>| {
+            let health = health.clone();
+            async move { Ok::<_, Infallible>(respond(request.uri().path(), health.as_deref()).await) }
+        });
+        tokio::spawn(async move {
+            if let Err(why) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                log::warn!("Error serving metrics: {why}");
+            }
+        });
+    }
+}
+
+fn address() -> SocketAddr {
+    let port = env::var("METRICS_PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(DEFAULT_PORT);
+    SocketAddr::from(([0, 0, 0, 0], port))
+}
+
+/// Serves `/metrics` in the background on `METRICS_PORT`, 9090 by default.
+/// A port that's taken is logged rather than fatal — the binary still does
+/// its job, it just can't be scraped.
+pub fn serve() {
+    tokio::spawn(listen(address(), None));
+}
+
+/// Like `serve`, and also answers liveness probes on `/livez` and readiness
+/// probes on `/readyz` from `health`.
+pub fn serve_with(health: impl Health + 'static) {
+    tokio::spawn(listen(address(), Some(Arc::new(health))));
+}
+
+/// Replaces the `job` group on the Pushgateway at `gateway` with everything
+/// registered so far.
+async fn push_to(gateway: &str, job: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let uri: Uri = format!("{}/metrics/job/{job}", gateway.trim_end_matches('/')).parse()?;
+    let host = uri.host().ok_or("no host in PUSHGATEWAY_URL")?;
+    let port = uri.port_u16().unwrap_or(80);
+    let stream = TcpStream::connect((host, port)).await?;
+    let (mut sender, connection) =
+        hyper::client::conn::http1::handshake(TokioIo::new(stream)).await?;
+    tokio::spawn(async move {
+        if let Err(why) = connection.await {
+            log::warn!("Error pushing metrics: {why}");
+        }
+    });
+
+    let request = Request::put(uri.path())
+        .header(HOST, format!("{host}:{port}"))
+        .header(CONTENT_TYPE, prometheus::TEXT_FORMAT)
+        .body(Full::new(Bytes::from(render())))?;
+    let status = sender.send_request(request).await?.status();
+    if !status.is_success() {
+        return Err(format!("Pushgateway answered {status}").into());
+    }
+    Ok(())
+}
+
+/// Pushes everything registered so far to the Pushgateway at
+/// `PUSHGATEWAY_URL`, replacing what the last run of `job` pushed. For
+/// binaries that exit before a scrape can reach them. Does nothing if the
+/// variable isn't set, and a failed push is logged rather than fatal.
+pub async fn push(job: &str) {
+    let Ok(gateway) = env::var("PUSHGATEWAY_URL") else {
+        return;
+    };
+    match push_to(&gateway, job).await {
+        Ok(()) => log::info!("Pushed {job} metrics to {gateway}"),
+        Err(why) => log::warn!("Couldn't push {job} metrics to {gateway}: {why}"),
+    }
+}
+
+/// Resolves once the process is asked to stop — SIGTERM from Kubernetes
+/// during a rollout, or Ctrl-C locally. Listens from the call rather than
+/// the first poll, so a signal that lands while the caller's busy isn't
+/// missed.
+///
+/// # Panics
+/// Panics if the signal handlers can't be installed.
+pub fn shutdown_signal() -> impl Future<Output = ()> + Send {
+    let mut terminate = signal(SignalKind::terminate()).expect("Couldn't listen for SIGTERM");
+    let mut interrupt = signal(SignalKind::interrupt()).expect("Couldn't listen for SIGINT");
+    async move {
+        tokio::select! {
+            _ = terminate.recv() => log::info!("Received SIGTERM, shutting down"),
+            _ = interrupt.recv() => log::info!("Received SIGINT, shutting down"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use prometheus::{IntCounter, register_int_counter};
+
+    struct Fixed {
+        live: bool,
+        ready: bool,
+    }
+
+    #[async_trait]
+    impl Health for Fixed {
+        async fn liveness(&self) -> Vec<Check> {
+            vec![Check::new("loop", self.live)]
+        }
+
+        async fn readiness(&self) -> Vec<Check> {
+            vec![
+                Check::new("loop", self.live),
+                Check::new("database", self.ready),
+            ]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_are_rendered() {
+        let counter: IntCounter =
+            register_int_counter!("telemetry_test_total", "A counter for the test").unwrap();
+        counter.inc();
+
+        let response = respond("/metrics", None).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(render().contains("telemetry_test_total 1"));
+    }
+
+    #[tokio::test]
+    async fn test_other_paths_are_not_found() {
+        assert_eq!(respond("/", None).await.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            respond("/readyz", None).await.status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_replaces_the_job_group() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gateway = format!("http://{}/", listener.local_addr().unwrap());
+        let received = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (sender, receiver) = tokio::sync::oneshot::channel();
+            let sender = std::sync::Mutex::new(Some(sender));
+            let service = service_fn(move |request: Request<Incoming>| {
+                if let Some(sender) = sender.lock().unwrap().take() {
+                    let _ =
+                        sender.send((request.method().clone(), request.uri().path().to_string()));
+                }
+                async { Ok::<_, Infallible>(Response::new(Full::new(Bytes::new()))) }
+            });
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+            receiver.await.unwrap()
+        });
+
+        push_to(&gateway, "bulk").await.unwrap();
+        let (method, path) = received.await.unwrap();
+        assert_eq!(method, hyper::Method::PUT);
+        assert_eq!(path, "/metrics/job/bulk");
+    }
+
+    #[tokio::test]
+    async fn test_probes_report_each_check() {
+        let health = Fixed {
+            live: true,
+            ready: false,
+        };
+        assert_eq!(
+            respond("/livez", Some(&health)).await.status(),
+            StatusCode::OK
+        );
+
+        let response = respond("/readyz", Some(&health)).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(&body[..], b"loop: ok\ndatabase: failing\n");
+    }
+}